@@ -2,7 +2,10 @@
 
 use chrono::{Duration, Local, NaiveTime};
 use rand::thread_rng;
-use sbrd_gen::builder::{GeneratorBuilder, ParentGeneratorBuilder, ValueBound, ValueStep};
+use sbrd_gen::builder::{
+    GeneratorBuilder, IdEncoding, Locale, ParentGeneratorBuilder, TimeZoneOption, ValueBound,
+    ValueStep, WeightedTimeZone,
+};
 use sbrd_gen::writer::{GeneratedValueWriterBase, PrettyJsonWriter};
 use sbrd_gen::SchemaBuilder;
 use std::io::stdout;
@@ -46,16 +49,46 @@ fn output_list() -> Vec<String> {
     vec![
         "duplicate-permutation-key".to_string(),
         "format-key".to_string(),
+        "regex-key".to_string(),
         "dist-normal-key".to_string(),
+        "dist-uniform-key".to_string(),
+        "dist-log-normal-key".to_string(),
+        "dist-exponential-key".to_string(),
+        "dist-poisson-key".to_string(),
+        "dist-binomial-key".to_string(),
+        "dist-geometric-key".to_string(),
+        "dist-gamma-key".to_string(),
+        "dist-beta-key".to_string(),
+        "dist-weibull-key".to_string(),
+        "dist-pareto-key".to_string(),
+        "dist-zipf-key".to_string(),
+        "dist-bernoulli-key".to_string(),
         "eval-int-key".to_string(),
         "eval-real-key".to_string(),
         "eval-bool-key".to_string(),
         "eval-string-key".to_string(),
+        "eval-date-time-key".to_string(),
+        "eval-date-key".to_string(),
+        "eval-time-key".to_string(),
+        "uuid-v4-key".to_string(),
+        "uuid-v7-key".to_string(),
+        "ulid-key".to_string(),
+        "snowflake-key".to_string(),
         "increment-id-key".to_string(),
+        "person-name-key".to_string(),
+        "company-name-key".to_string(),
+        "street-address-key".to_string(),
+        "city-key".to_string(),
+        "postal-code-key".to_string(),
+        "phone-number-key".to_string(),
+        "email-key".to_string(),
+        "url-key".to_string(),
+        "lorem-key".to_string(),
         "int-key".to_string(),
         "real-key".to_string(),
         "bool-key".to_string(),
         "date-time-key".to_string(),
+        "zoned-date-time-key".to_string(),
         "date-key".to_string(),
         "time-key".to_string(),
         "always-null-key".to_string(),
@@ -68,6 +101,8 @@ fn output_list() -> Vec<String> {
         "get-real-value-at-key".to_string(),
         "get-string-value-at-key".to_string(),
         "get-value-index-key".to_string(),
+        "object-key".to_string(),
+        "array-key".to_string(),
     ]
 }
 
@@ -97,21 +132,57 @@ fn builder_list() -> Vec<ParentGeneratorBuilder> {
         ).into_parent("duplicate-permutation-key"),
         GeneratorBuilder::new_format("Lorem ipsum: \"{duplicate-permutation-key}\"")
             .into_parent("format-key"),
-        GeneratorBuilder::new_dist_normal(50.0, 10.0).into_parent("dist-normal-key"),
+        GeneratorBuilder::new_regex("[A-Z]{3}-\\d{4}(-[a-z]+)?", Some(4)).into_parent("regex-key"),
+        GeneratorBuilder::new_dist_normal(50.0, 10.0, None).into_parent("dist-normal-key"),
+        GeneratorBuilder::new_dist_uniform(0.0, 100.0, None).into_parent("dist-uniform-key"),
+        GeneratorBuilder::new_dist_log_normal(0.0, 1.0, Some((0.0..=10.0).into())).into_parent("dist-log-normal-key"),
+        GeneratorBuilder::new_dist_exponential(0.5, None).into_parent("dist-exponential-key"),
+        GeneratorBuilder::new_dist_poisson(3.0, None).into_parent("dist-poisson-key"),
+        GeneratorBuilder::new_dist_binomial(10, 0.3, None).into_parent("dist-binomial-key"),
+        GeneratorBuilder::new_dist_geometric(0.2, Some((0..=10).into())).into_parent("dist-geometric-key"),
+        GeneratorBuilder::new_dist_gamma(2.0, 2.0, None).into_parent("dist-gamma-key"),
+        GeneratorBuilder::new_dist_beta(2.0, 5.0, None).into_parent("dist-beta-key"),
+        GeneratorBuilder::new_dist_weibull(1.0, 1.5, None).into_parent("dist-weibull-key"),
+        GeneratorBuilder::new_dist_pareto(1.0, 3.0, Some((1.0..=100.0).into())).into_parent("dist-pareto-key"),
+        GeneratorBuilder::new_dist_zipf(100, 1.1, None).into_parent("dist-zipf-key"),
+        GeneratorBuilder::new_dist_bernoulli(0.7, None).into_parent("dist-bernoulli-key"),
         GeneratorBuilder::new_eval_int("10 * 10").into_parent("eval-int-key"),
         GeneratorBuilder::new_eval_real("{dist-normal-key:5.2}").into_parent("eval-real-key"),
         GeneratorBuilder::new_eval_bool("{dist-normal-key} < 50").into_parent("eval-bool-key"),
         GeneratorBuilder::new_eval_string("\"string: {dist-normal-key:4.2}\"").into_parent("eval-string-key"),
+        GeneratorBuilder::new_eval_date_time("date_add(\"2022-01-01 00:00:00\", rand_int(1, 5), \"day\")").into_parent("eval-date-time-key"),
+        GeneratorBuilder::new_eval_date("date_add(\"2022-01-31\", rand_int(0, 11), \"month\")").into_parent("eval-date-key"),
+        GeneratorBuilder::new_eval_time("date_add(\"09:00:00\", rand_int(0, 480), \"minute\")").into_parent("eval-time-key"),
+        GeneratorBuilder::new_uuid_v4(None).into_parent("uuid-v4-key"),
+        GeneratorBuilder::new_uuid_v7(Some((now.sub(Duration::days(365)).naive_local() .. now.clone().naive_local()).into()), None).into_parent("uuid-v7-key"),
+        GeneratorBuilder::new_ulid(None, Some(IdEncoding::Base32)).into_parent("ulid-key"),
+        GeneratorBuilder::new_snowflake(None, None, Some(1), Some(IdEncoding::Integer)).into_parent("snowflake-key"),
         GeneratorBuilder::new_increment_id(Some(ValueStep::new(100, Some(10)))).into_parent("increment-id-key"),
-        GeneratorBuilder::new_int(Some(ValueBound::new(Some(1), Some((true, 100))))).into_parent("int-key"),
+        GeneratorBuilder::new_person_name(Some(Locale::Ja)).into_parent("person-name-key"),
+        GeneratorBuilder::new_company_name(Some(Locale::Ja)).into_parent("company-name-key"),
+        GeneratorBuilder::new_street_address(None).into_parent("street-address-key"),
+        GeneratorBuilder::new_city(None).into_parent("city-key"),
+        GeneratorBuilder::new_postal_code(None).into_parent("postal-code-key"),
+        GeneratorBuilder::new_phone_number(None).into_parent("phone-number-key"),
+        GeneratorBuilder::new_email(None, Some("{person-name-key}".to_string())).into_parent("email-key"),
+        GeneratorBuilder::new_url(None, Some("{company-name-key}".to_string())).into_parent("url-key"),
+        GeneratorBuilder::new_lorem(None, Some(ValueBound::new(Some(3), Some((true, 8))))).into_parent("lorem-key"),
+        GeneratorBuilder::new_int(Some(ValueBound::new(Some(1), Some((true, 100))))).unique().into_parent("int-key"),
         GeneratorBuilder::new_real(Some((0.0 ..= 1.0).into())).into_parent("real-key"),
         GeneratorBuilder::new_bool().into_parent("bool-key"),
         GeneratorBuilder::new_date_time(Some((now.sub(Duration::days(1)).naive_local() .. now.clone().naive_local()).into()),
                                         Some("%H:%M %Y/%m/%d".to_string())).nullable().into_parent("date-time-key"),
+        GeneratorBuilder::new_date_time(Some((now.sub(Duration::days(1)).naive_local() .. now.clone().naive_local()).into()),
+                                        Some("%Y-%m-%dT%H:%M:%S%:z".to_string()))
+            .timezone(TimeZoneOption::new_weighted(vec![
+                WeightedTimeZone::new("Asia/Tokyo").weight(3),
+                WeightedTimeZone::new("America/New_York"),
+                WeightedTimeZone::new("+05:30"),
+            ])).into_parent("zoned-date-time-key"),
         GeneratorBuilder::new_date(Some((now.sub(Duration::days(365)).date().naive_local() .. now.clone().date().naive_local()).into()),
                                         Some("%Y/%m/%d".to_string())).nullable().into_parent("date-key"),
         GeneratorBuilder::new_time(Some((NaiveTime::parse_from_str("00:00", "%H:%M").unwrap() ..= now.clone().time()).into()),
-                                        Some("%H:%M".to_string())).nullable().into_parent("time-key"),
+                                        Some("%H:%M".to_string())).nullable_with_probability(0.5).into_parent("time-key"),
         GeneratorBuilder::new_always_null().into_parent("always-null-key"),
         GeneratorBuilder::new_case_when(vec![
             GeneratorBuilder::new_int(None).into_child().condition("{int-key} < 0"),
@@ -186,5 +257,14 @@ fn builder_list() -> Vec<ParentGeneratorBuilder> {
             Some(vec![0.into(),10.into(),20.into(),30.into(),40.into(),50.into(),60.into(),70.into(),80.into(),90.into()]),
            Some(dummy_num_filepath.clone())
         ).into_parent("get-value-index-key"),
+        GeneratorBuilder::new_object(vec![
+            GeneratorBuilder::new_increment_id(None).into_child().key("id"),
+            GeneratorBuilder::new_format("name-{id}").into_child().key("name"),
+            GeneratorBuilder::new_real(Some((0.0 ..= 100.0).into())).nullable().into_child().key("score"),
+        ]).into_parent("object-key"),
+        GeneratorBuilder::new_array(Some((1 ..= 3).into()), vec![
+            GeneratorBuilder::new_int(None).into_child().weight(3),
+            GeneratorBuilder::new_bool().into_child(),
+        ]).into_parent("array-key"),
     ]
 }
@@ -0,0 +1,54 @@
+//! Example for tables from yaml
+
+use rand::thread_rng;
+use sbrd_gen::file::set_schema_file_path;
+use sbrd_gen::parser::SchemaParser;
+use sbrd_gen::parser::YamlParser;
+use sbrd_gen::writer::{CsvWriter, GeneratedValueWriterBase, PrettyJsonWriter};
+use std::fs::File;
+use std::io::stdout;
+use std::path::PathBuf;
+
+fn main() {
+    let schema_dir = get_schema_dir();
+    let schema_file_path = schema_dir.join("tables.yaml");
+
+    // set load current filepath
+    set_schema_file_path(schema_file_path.clone());
+
+    let file = File::open(schema_file_path.as_path()).unwrap();
+
+    let schema_builder = YamlParser::parse_tables_from_reader(file).unwrap();
+    let schema = schema_builder.build().unwrap();
+
+    let mut rng = thread_rng();
+    let tables = schema.generate(&mut rng).unwrap();
+    println!("Debug: {:?}\n", tables);
+
+    println!("---------------------\n");
+
+    let mut writer = PrettyJsonWriter::from_writer(stdout());
+    writer.write_tables(true, &tables).unwrap();
+    writer.flush().unwrap();
+
+    println!("\n---------------------\n");
+
+    let mut writer = CsvWriter::from_writer(stdout());
+    writer.write_tables(true, &tables).unwrap();
+    writer.flush().unwrap();
+}
+
+fn get_schema_dir() -> PathBuf {
+    let schema_dir = std::env::current_exe().unwrap();
+    schema_dir
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("examples")
+        .join("schema")
+}
@@ -44,9 +44,10 @@ impl<T> ValueBound<T> {
 
     /// Create value boundary from start to end.
     /// If end is `Some((true, _))`, then include end.
+    /// If end is `None`, then `include_end` keeps the default because it has no effect.
     pub fn new(start: Option<T>, end: Option<(bool, T)>) -> Self {
         let (_include_end, _end): (bool, Option<T>) = match end {
-            None => (false, None),
+            None => (default_include_end(), None),
             Some((_include_end, _end)) => (_include_end, Some(_end)),
         };
 
@@ -0,0 +1,34 @@
+//! Module for encoding
+
+use serde::{Deserialize, Serialize};
+
+/// Encoding option of the generated identifier
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum IdEncoding {
+    /// Lowercase hexadecimal digits separated by hyphens such as "0188e3b1-5c4e-7a4f-9c3d-2b1a0f9e8d7c"
+    Hyphenated,
+    /// Lowercase hexadecimal digits without hyphens such as "0188e3b15c4e7a4f9c3d2b1a0f9e8d7c"
+    Simple,
+    /// Uppercase of [`IdEncoding::Hyphenated`], or of [`IdEncoding::Simple`] for the identifier which is not 128 bits
+    ///
+    /// [`IdEncoding::Hyphenated`]: ./enum.IdEncoding.html#variant.Hyphenated
+    /// [`IdEncoding::Simple`]: ./enum.IdEncoding.html#variant.Simple
+    Uppercase,
+    /// Crockford's Base32 such as "01H3HV2Q2EBPVZ5N3Y8V1K0QZM"
+    Base32,
+    /// Integer
+    Integer,
+}
+
+impl std::fmt::Display for IdEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdEncoding::Hyphenated => write!(f, "hyphenated"),
+            IdEncoding::Simple => write!(f, "simple"),
+            IdEncoding::Uppercase => write!(f, "uppercase"),
+            IdEncoding::Base32 => write!(f, "base32"),
+            IdEncoding::Integer => write!(f, "integer"),
+        }
+    }
+}
@@ -3,25 +3,39 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
-use crate::builder::{ValueBound, ValueStep};
+use crate::builder::{
+    IdEncoding, Locale, Nullable, TimeZoneOption, ValueBound, ValueReference, ValueStep,
+};
 use crate::error::BuildError;
-use crate::generator::build_string::{DuplicatePermutationGenerator, FormatGenerator};
-use crate::generator::distribution::NormalGenerator;
+use crate::generator::build_string::{
+    DuplicatePermutationGenerator, FormatGenerator, RegexGenerator,
+};
+use crate::generator::distribution::{
+    BernoulliGenerator, BetaGenerator, BinomialGenerator, ExponentialGenerator, GammaGenerator,
+    GeometricGenerator, LogNormalGenerator, NormalGenerator, ParetoGenerator, PoissonGenerator,
+    UniformGenerator, WeibullGenerator, ZipfGenerator,
+};
 use crate::generator::evaluate::EvalGenerator;
+use crate::generator::identifier::{SnowflakeGenerator, UlidGenerator, UuidGenerator};
 use crate::generator::incremental::IncrementIdGenerator;
+use crate::generator::locale::{
+    CityGenerator, CompanyNameGenerator, EmailGenerator, LoremGenerator, PersonNameGenerator,
+    PhoneNumberGenerator, PostalCodeGenerator, StreetAddressGenerator, UrlGenerator,
+};
 use crate::generator::primitive::{
     AlwaysNullGenerator, BoolGenerator, DateGenerator, DateTimeGenerator, IntGenerator,
     RealGenerator, TimeGenerator,
 };
 use crate::generator::random_children::{CaseWhenGenerator, RandomChildGenerator};
 use crate::generator::random_values::{
-    GetValueAtGenerator, GetValueIndexGenerator, SelectGenerator,
+    GetValueAtGenerator, GetValueIndexGenerator, ReferenceGenerator, SelectGenerator,
 };
+use crate::generator::structure::{ArrayGenerator, ObjectGenerator};
 use crate::generator::{GeneratorBase, Randomizer};
 use crate::generator_type::GeneratorType;
 use crate::value::{
     DataValue, DataValueMap, SbrdBool, SbrdDate, SbrdDateTime, SbrdInt, SbrdReal, SbrdString,
-    SbrdTime, DATE_DEFAULT_FORMAT, DATE_TIME_DEFAULT_FORMAT, TIME_DEFAULT_FORMAT,
+    SbrdTime, ValueMap, DATE_DEFAULT_FORMAT, DATE_TIME_DEFAULT_FORMAT, TIME_DEFAULT_FORMAT,
 };
 
 /// Generator Builder used in [`SchemeBuilder`] as Generator Builder.
@@ -65,6 +79,10 @@ pub type Weight = u8;
 /// [`SchemeBuilder`]: ../schema/struct.SchemaBuilder.html
 #[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
 pub struct ChildGeneratorBuilder {
+    /// Child Generator's `key` option
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) key: Option<String>,
+
     /// Child Generator's `condition` option
     #[serde(rename = "case", skip_serializing_if = "Option::is_none")]
     pub(crate) condition: Option<String>,
@@ -84,12 +102,22 @@ impl ChildGeneratorBuilder {
     /// [`GeneratorBuilder`]: ./struct.GeneratorBuilder.html
     fn new(builder: GeneratorBuilder) -> ChildGeneratorBuilder {
         Self {
+            key: None,
             condition: None,
             weight: None,
             builder,
         }
     }
 
+    /// Set `key` condition
+    pub fn key<S>(mut self, key: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.key = Some(key.into());
+        self
+    }
+
     /// Set `condition` condition
     pub fn condition<S>(mut self, condition: S) -> Self
     where
@@ -106,14 +134,6 @@ impl ChildGeneratorBuilder {
     }
 }
 
-fn is_required(use_nullable: &bool) -> bool {
-    use_nullable != &false
-}
-
-fn as_required() -> bool {
-    false
-}
-
 /// Base Generator Builder
 #[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
 pub struct GeneratorBuilder {
@@ -125,9 +145,16 @@ pub struct GeneratorBuilder {
 
     /// Generator's `nullable` status
     ///
-    /// This is a nullable flag for the generator.
-    #[serde(skip_serializing_if = "is_required", default = "as_required")]
-    pub(crate) nullable: bool,
+    /// This is a nullable flag or a probability of generating null for the generator.
+    #[serde(skip_serializing_if = "Nullable::is_required", default)]
+    pub(crate) nullable: Nullable,
+
+    /// Generator's `unique` status
+    ///
+    /// This is a flag that the generated value must be unique in the values generated at the key.
+    /// Only available for the generator at the top level of the schema.
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub(crate) unique: bool,
 
     /// Generator's `format` option
     ///
@@ -147,6 +174,18 @@ pub struct GeneratorBuilder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) script: Option<String>,
 
+    /// Generator's `pattern` option
+    ///
+    /// This is a regular expression which the generated value matches.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) pattern: Option<String>,
+
+    /// Generator's `max_repeat` option
+    ///
+    /// This is a maximum count of the repetition for the unbounded quantifier in the `pattern`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) max_repeat: Option<u32>,
+
     /// Generator's `separator` option
     ///
     /// This separator use as glue to join the generated values.
@@ -189,11 +228,42 @@ pub struct GeneratorBuilder {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) filepath: Option<PathBuf>,
 
+    /// Generator's `weighted` status
+    ///
+    /// This is a flag that each line in the file at `filepath` is the value and the weight separated by the last tab.
+    /// Only available for the generator picking out the value with the weight.
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub(crate) weighted: bool,
+
     /// Generator's `parameters` option
     ///
     /// This is a parameter data set for a Distribution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) parameters: Option<DataValueMap<String>>,
+
+    /// Generator's `reference` option
+    ///
+    /// This is a reference to the values in an other table.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) reference: Option<ValueReference>,
+
+    /// Generator's `locale` option
+    ///
+    /// This is a locale of the bundled dataset for the generated value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) locale: Option<Locale>,
+
+    /// Generator's `timezone` option
+    ///
+    /// This is a time zone or the time zones with the weights for the generated date-time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) timezone: Option<TimeZoneOption>,
+
+    /// Generator's `encoding` option
+    ///
+    /// This is an encoding of the generated identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) encoding: Option<IdEncoding>,
 }
 
 /// Helper for build generator.
@@ -210,25 +280,75 @@ macro_rules! build_generator {
 impl GeneratorBuilder {
     /// Build generator as the type
     pub fn build<R: Randomizer + ?Sized>(self) -> Result<Box<dyn GeneratorBase<R>>, BuildError> {
+        if !self.nullable.is_valid() {
+            return Err(BuildError::InvalidNullProbability(self.nullable));
+        }
+
+        let is_required_object =
+            self.generator_type == GeneratorType::Object && self.nullable.is_required();
+        if !is_required_object
+            && self
+                .children
+                .iter()
+                .flatten()
+                .any(|child| child.builder.has_increment_id())
+        {
+            return Err(BuildError::IncrementIdInChildren(self.generator_type));
+        }
+
         match self.generator_type {
             // build string
             GeneratorType::DuplicatePermutation => {
                 build_generator!(self, R, DuplicatePermutationGenerator<R>)
             }
             GeneratorType::Format => build_generator!(self, R, FormatGenerator),
+            GeneratorType::Regex => build_generator!(self, R, RegexGenerator),
 
             // distribution
             GeneratorType::DistNormal => build_generator!(self, R, NormalGenerator),
+            GeneratorType::DistUniform => build_generator!(self, R, UniformGenerator),
+            GeneratorType::DistLogNormal => build_generator!(self, R, LogNormalGenerator),
+            GeneratorType::DistExponential => build_generator!(self, R, ExponentialGenerator),
+            GeneratorType::DistPoisson => build_generator!(self, R, PoissonGenerator),
+            GeneratorType::DistBinomial => build_generator!(self, R, BinomialGenerator),
+            GeneratorType::DistGeometric => build_generator!(self, R, GeometricGenerator),
+            GeneratorType::DistGamma => build_generator!(self, R, GammaGenerator),
+            GeneratorType::DistBeta => build_generator!(self, R, BetaGenerator),
+            GeneratorType::DistWeibull => build_generator!(self, R, WeibullGenerator),
+            GeneratorType::DistPareto => build_generator!(self, R, ParetoGenerator),
+            GeneratorType::DistZipf => build_generator!(self, R, ZipfGenerator),
+            GeneratorType::DistBernoulli => build_generator!(self, R, BernoulliGenerator),
 
             // evaluate
             GeneratorType::EvalInt => build_generator!(self, R, EvalGenerator<SbrdInt>),
             GeneratorType::EvalReal => build_generator!(self, R, EvalGenerator<SbrdReal>),
             GeneratorType::EvalBool => build_generator!(self, R, EvalGenerator<SbrdBool>),
             GeneratorType::EvalString => build_generator!(self, R, EvalGenerator<SbrdString>),
+            GeneratorType::EvalDateTime => build_generator!(self, R, EvalGenerator<SbrdDateTime>),
+            GeneratorType::EvalDate => build_generator!(self, R, EvalGenerator<SbrdDate>),
+            GeneratorType::EvalTime => build_generator!(self, R, EvalGenerator<SbrdTime>),
+
+            // identifier
+            GeneratorType::UuidV4 | GeneratorType::UuidV7 => {
+                build_generator!(self, R, UuidGenerator)
+            }
+            GeneratorType::Ulid => build_generator!(self, R, UlidGenerator),
+            GeneratorType::Snowflake => build_generator!(self, R, SnowflakeGenerator),
 
             // incremental
             GeneratorType::IncrementId => build_generator!(self, R, IncrementIdGenerator),
 
+            // locale
+            GeneratorType::PersonName => build_generator!(self, R, PersonNameGenerator),
+            GeneratorType::CompanyName => build_generator!(self, R, CompanyNameGenerator),
+            GeneratorType::StreetAddress => build_generator!(self, R, StreetAddressGenerator),
+            GeneratorType::City => build_generator!(self, R, CityGenerator),
+            GeneratorType::PostalCode => build_generator!(self, R, PostalCodeGenerator),
+            GeneratorType::Email => build_generator!(self, R, EmailGenerator),
+            GeneratorType::PhoneNumber => build_generator!(self, R, PhoneNumberGenerator),
+            GeneratorType::Url => build_generator!(self, R, UrlGenerator),
+            GeneratorType::Lorem => build_generator!(self, R, LoremGenerator),
+
             // primitive
             GeneratorType::Int => build_generator!(self, R, IntGenerator),
             GeneratorType::Real => build_generator!(self, R, RealGenerator),
@@ -256,6 +376,11 @@ impl GeneratorBuilder {
             GeneratorType::GetValueIndex => {
                 build_generator!(self, R, GetValueIndexGenerator)
             }
+            GeneratorType::Reference => build_generator!(self, R, ReferenceGenerator),
+
+            // structure
+            GeneratorType::Object => build_generator!(self, R, ObjectGenerator<R>),
+            GeneratorType::Array => build_generator!(self, R, ArrayGenerator<R>),
         }
     }
 
@@ -273,6 +398,71 @@ impl GeneratorBuilder {
     }
 }
 
+//
+// reference to other tables
+//
+impl GeneratorBuilder {
+    /// Get names of the tables referenced by this builder and the children
+    pub(crate) fn get_referenced_tables(&self) -> Vec<&str> {
+        let mut tables = Vec::new();
+        if let Some(reference) = &self.reference {
+            tables.push(reference.get_table());
+        }
+
+        if let Some(children) = &self.children {
+            for child in children.iter() {
+                tables.extend(child.builder.get_referenced_tables());
+            }
+        }
+
+        tables
+    }
+
+    /// Check that this builder or the children has the cardinality of the reference.
+    /// The value generated with the cardinality depends on the order of generation.
+    pub(crate) fn has_reference_cardinality(&self) -> bool {
+        let has_cardinality = self
+            .reference
+            .as_ref()
+            .is_some_and(|reference| reference.get_cardinality().is_some());
+
+        has_cardinality
+            || self
+                .children
+                .iter()
+                .flatten()
+                .any(|child| child.builder.has_reference_cardinality())
+    }
+
+    /// Check that this builder or the children is the increment-id generator
+    fn has_increment_id(&self) -> bool {
+        self.generator_type == GeneratorType::IncrementId
+            || self
+                .children
+                .iter()
+                .flatten()
+                .any(|child| child.builder.has_increment_id())
+    }
+
+    /// Replace `values` option with the records of the referenced table for this builder and the children
+    pub(crate) fn set_referenced_records(
+        &mut self,
+        records_map: &ValueMap<String, Vec<DataValue>>,
+    ) {
+        if let Some(reference) = &self.reference {
+            if let Some(records) = records_map.get(reference.get_table()) {
+                self.values = Some(records.clone());
+            }
+        }
+
+        if let Some(children) = &mut self.children {
+            for child in children.iter_mut() {
+                child.builder.set_referenced_records(records_map);
+            }
+        }
+    }
+}
+
 //
 // constructor functions following:
 //
@@ -281,9 +471,12 @@ impl GeneratorBuilder {
     fn new(generator_type: GeneratorType) -> Self {
         Self {
             generator_type,
-            nullable: as_required(),
+            nullable: Nullable::default(),
+            unique: false,
             format: None,
             script: None,
+            pattern: None,
+            max_repeat: None,
             separator: None,
             range: None,
             increment: None,
@@ -291,7 +484,12 @@ impl GeneratorBuilder {
             chars: None,
             values: None,
             filepath: None,
+            weighted: false,
             parameters: None,
+            reference: None,
+            locale: None,
+            timezone: None,
+            encoding: None,
         }
     }
 
@@ -344,18 +542,242 @@ impl GeneratorBuilder {
         Self::new(GeneratorType::Format).format(format)
     }
 
+    /// Create builder for [`RegexGenerator`] with the maximum count of the repetition for the unbounded quantifier
+    ///
+    /// [`RegexGenerator`]: ../generator/build_string/regex_generator/struct.RegexGenerator.html
+    pub fn new_regex<S>(pattern: S, max_repeat: Option<u32>) -> Self
+    where
+        S: Into<String>,
+    {
+        let mut this = Self::new(GeneratorType::Regex).pattern(pattern);
+        if let Some(max_repeat) = max_repeat {
+            this = this.max_repeat(max_repeat);
+        }
+
+        this
+    }
+
     //
     // distribution
     //
 
-    /// Create builder for [`NormalGenerator`]
+    /// Create builder for [`NormalGenerator`] with the range to clamp the generated value
     ///
     /// [`NormalGenerator`]: ../generator/distribution/normal_generator/struct.NormalGenerator.html
-    pub fn new_dist_normal(mean: SbrdReal, std_dev: SbrdReal) -> Self {
+    pub fn new_dist_normal(
+        mean: SbrdReal,
+        std_dev: SbrdReal,
+        range: Option<ValueBound<SbrdReal>>,
+    ) -> Self {
         let mut parameters = DataValueMap::new();
         parameters.insert(NormalGenerator::MEAN.to_string(), mean.into());
         parameters.insert(NormalGenerator::STD_DEV.to_string(), std_dev.into());
-        Self::new(GeneratorType::DistNormal).parameters(parameters)
+        let mut this = Self::new(GeneratorType::DistNormal).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
+    /// Create builder for [`UniformGenerator`] with the range to clamp the generated value
+    ///
+    /// [`UniformGenerator`]: ../generator/distribution/uniform_generator/struct.UniformGenerator.html
+    pub fn new_dist_uniform(
+        low: SbrdReal,
+        high: SbrdReal,
+        range: Option<ValueBound<SbrdReal>>,
+    ) -> Self {
+        let mut parameters = DataValueMap::new();
+        parameters.insert(UniformGenerator::LOW.to_string(), low.into());
+        parameters.insert(UniformGenerator::HIGH.to_string(), high.into());
+        let mut this = Self::new(GeneratorType::DistUniform).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
+    /// Create builder for [`LogNormalGenerator`] with the range to clamp the generated value
+    ///
+    /// [`LogNormalGenerator`]: ../generator/distribution/log_normal_generator/struct.LogNormalGenerator.html
+    pub fn new_dist_log_normal(
+        mean: SbrdReal,
+        std_dev: SbrdReal,
+        range: Option<ValueBound<SbrdReal>>,
+    ) -> Self {
+        let mut parameters = DataValueMap::new();
+        parameters.insert(LogNormalGenerator::MEAN.to_string(), mean.into());
+        parameters.insert(LogNormalGenerator::STD_DEV.to_string(), std_dev.into());
+        let mut this = Self::new(GeneratorType::DistLogNormal).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
+    /// Create builder for [`ExponentialGenerator`] with the range to clamp the generated value
+    ///
+    /// [`ExponentialGenerator`]: ../generator/distribution/exponential_generator/struct.ExponentialGenerator.html
+    pub fn new_dist_exponential(lambda: SbrdReal, range: Option<ValueBound<SbrdReal>>) -> Self {
+        let mut parameters = DataValueMap::new();
+        parameters.insert(ExponentialGenerator::LAMBDA.to_string(), lambda.into());
+        let mut this = Self::new(GeneratorType::DistExponential).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
+    /// Create builder for [`PoissonGenerator`] with the range to clamp the generated value
+    ///
+    /// [`PoissonGenerator`]: ../generator/distribution/poisson_generator/struct.PoissonGenerator.html
+    pub fn new_dist_poisson(lambda: SbrdReal, range: Option<ValueBound<SbrdInt>>) -> Self {
+        let mut parameters = DataValueMap::new();
+        parameters.insert(PoissonGenerator::LAMBDA.to_string(), lambda.into());
+        let mut this = Self::new(GeneratorType::DistPoisson).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
+    /// Create builder for [`BinomialGenerator`] with the range to clamp the generated value
+    ///
+    /// [`BinomialGenerator`]: ../generator/distribution/binomial_generator/struct.BinomialGenerator.html
+    pub fn new_dist_binomial(n: SbrdInt, p: SbrdReal, range: Option<ValueBound<SbrdInt>>) -> Self {
+        let mut parameters = DataValueMap::new();
+        parameters.insert(BinomialGenerator::N.to_string(), n.into());
+        parameters.insert(BinomialGenerator::P.to_string(), p.into());
+        let mut this = Self::new(GeneratorType::DistBinomial).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
+    /// Create builder for [`GeometricGenerator`] with the range to clamp the generated value
+    ///
+    /// [`GeometricGenerator`]: ../generator/distribution/geometric_generator/struct.GeometricGenerator.html
+    pub fn new_dist_geometric(p: SbrdReal, range: Option<ValueBound<SbrdInt>>) -> Self {
+        let mut parameters = DataValueMap::new();
+        parameters.insert(GeometricGenerator::P.to_string(), p.into());
+        let mut this = Self::new(GeneratorType::DistGeometric).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
+    /// Create builder for [`GammaGenerator`] with the range to clamp the generated value
+    ///
+    /// [`GammaGenerator`]: ../generator/distribution/gamma_generator/struct.GammaGenerator.html
+    pub fn new_dist_gamma(
+        shape: SbrdReal,
+        scale: SbrdReal,
+        range: Option<ValueBound<SbrdReal>>,
+    ) -> Self {
+        let mut parameters = DataValueMap::new();
+        parameters.insert(GammaGenerator::SHAPE.to_string(), shape.into());
+        parameters.insert(GammaGenerator::SCALE.to_string(), scale.into());
+        let mut this = Self::new(GeneratorType::DistGamma).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
+    /// Create builder for [`BetaGenerator`] with the range to clamp the generated value
+    ///
+    /// [`BetaGenerator`]: ../generator/distribution/beta_generator/struct.BetaGenerator.html
+    pub fn new_dist_beta(
+        alpha: SbrdReal,
+        beta: SbrdReal,
+        range: Option<ValueBound<SbrdReal>>,
+    ) -> Self {
+        let mut parameters = DataValueMap::new();
+        parameters.insert(BetaGenerator::ALPHA.to_string(), alpha.into());
+        parameters.insert(BetaGenerator::BETA.to_string(), beta.into());
+        let mut this = Self::new(GeneratorType::DistBeta).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
+    /// Create builder for [`WeibullGenerator`] with the range to clamp the generated value
+    ///
+    /// [`WeibullGenerator`]: ../generator/distribution/weibull_generator/struct.WeibullGenerator.html
+    pub fn new_dist_weibull(
+        scale: SbrdReal,
+        shape: SbrdReal,
+        range: Option<ValueBound<SbrdReal>>,
+    ) -> Self {
+        let mut parameters = DataValueMap::new();
+        parameters.insert(WeibullGenerator::SCALE.to_string(), scale.into());
+        parameters.insert(WeibullGenerator::SHAPE.to_string(), shape.into());
+        let mut this = Self::new(GeneratorType::DistWeibull).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
+    /// Create builder for [`ParetoGenerator`] with the range to clamp the generated value
+    ///
+    /// [`ParetoGenerator`]: ../generator/distribution/pareto_generator/struct.ParetoGenerator.html
+    pub fn new_dist_pareto(
+        scale: SbrdReal,
+        shape: SbrdReal,
+        range: Option<ValueBound<SbrdReal>>,
+    ) -> Self {
+        let mut parameters = DataValueMap::new();
+        parameters.insert(ParetoGenerator::SCALE.to_string(), scale.into());
+        parameters.insert(ParetoGenerator::SHAPE.to_string(), shape.into());
+        let mut this = Self::new(GeneratorType::DistPareto).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
+    /// Create builder for [`ZipfGenerator`] with the range to clamp the generated value
+    ///
+    /// [`ZipfGenerator`]: ../generator/distribution/zipf_generator/struct.ZipfGenerator.html
+    pub fn new_dist_zipf(n: SbrdInt, s: SbrdReal, range: Option<ValueBound<SbrdInt>>) -> Self {
+        let mut parameters = DataValueMap::new();
+        parameters.insert(ZipfGenerator::N.to_string(), n.into());
+        parameters.insert(ZipfGenerator::S.to_string(), s.into());
+        let mut this = Self::new(GeneratorType::DistZipf).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
+    /// Create builder for [`BernoulliGenerator`] with the range to clamp the generated value
+    ///
+    /// [`BernoulliGenerator`]: ../generator/distribution/bernoulli_generator/struct.BernoulliGenerator.html
+    pub fn new_dist_bernoulli(p: SbrdReal, range: Option<ValueBound<SbrdInt>>) -> Self {
+        let mut parameters = DataValueMap::new();
+        parameters.insert(BernoulliGenerator::P.to_string(), p.into());
+        let mut this = Self::new(GeneratorType::DistBernoulli).parameters(parameters);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
     }
 
     //
@@ -406,6 +828,119 @@ impl GeneratorBuilder {
         Self::new(GeneratorType::EvalString).script(script)
     }
 
+    /// Create builder for [`EvalGenerator`] with type [`SbrdDateTime`]
+    ///
+    /// [`EvalGenerator`]: ../generator/evaluate/eval_generator/struct.EvalGenerator.html
+    /// [`SbrdDateTime`]: ../value/type.SbrdDateTime.html
+    pub fn new_eval_date_time<S>(script: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(GeneratorType::EvalDateTime).script(script)
+    }
+
+    /// Create builder for [`EvalGenerator`] with type [`SbrdDate`]
+    ///
+    /// [`EvalGenerator`]: ../generator/evaluate/eval_generator/struct.EvalGenerator.html
+    /// [`SbrdDate`]: ../value/type.SbrdDate.html
+    pub fn new_eval_date<S>(script: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(GeneratorType::EvalDate).script(script)
+    }
+
+    /// Create builder for [`EvalGenerator`] with type [`SbrdTime`]
+    ///
+    /// [`EvalGenerator`]: ../generator/evaluate/eval_generator/struct.EvalGenerator.html
+    /// [`SbrdTime`]: ../value/type.SbrdTime.html
+    pub fn new_eval_time<S>(script: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::new(GeneratorType::EvalTime).script(script)
+    }
+
+    //
+    // identifier
+    //
+
+    /// Create builder for [`UuidGenerator`] as UUID version 4
+    ///
+    /// [`UuidGenerator`]: ../generator/identifier/uuid_generator/struct.UuidGenerator.html
+    pub fn new_uuid_v4(encoding: Option<IdEncoding>) -> Self {
+        let mut this = Self::new(GeneratorType::UuidV4);
+        if let Some(encoding) = encoding {
+            this = this.encoding(encoding);
+        }
+
+        this
+    }
+
+    /// Create builder for [`UuidGenerator`] as UUID version 7 with the range of the timestamp
+    ///
+    /// [`UuidGenerator`]: ../generator/identifier/uuid_generator/struct.UuidGenerator.html
+    pub fn new_uuid_v7(
+        range: Option<ValueBound<SbrdDateTime>>,
+        encoding: Option<IdEncoding>,
+    ) -> Self {
+        Self::new(GeneratorType::UuidV7).id_options(range, encoding)
+    }
+
+    /// Create builder for [`UlidGenerator`] with the range of the timestamp
+    ///
+    /// [`UlidGenerator`]: ../generator/identifier/ulid_generator/struct.UlidGenerator.html
+    pub fn new_ulid(range: Option<ValueBound<SbrdDateTime>>, encoding: Option<IdEncoding>) -> Self {
+        Self::new(GeneratorType::Ulid).id_options(range, encoding)
+    }
+
+    /// Create builder for [`SnowflakeGenerator`] with the range of the timestamp.
+    /// `epoch` is the milliseconds since the Unix epoch, and `machine_id` is from 0 to 1023.
+    ///
+    /// [`SnowflakeGenerator`]: ../generator/identifier/snowflake_generator/struct.SnowflakeGenerator.html
+    pub fn new_snowflake(
+        range: Option<ValueBound<SbrdDateTime>>,
+        epoch: Option<SbrdInt>,
+        machine_id: Option<SbrdInt>,
+        encoding: Option<IdEncoding>,
+    ) -> Self {
+        let mut parameters = DataValueMap::new();
+        if let Some(epoch) = epoch {
+            parameters.insert(SnowflakeGenerator::EPOCH.to_string(), epoch.into());
+        }
+        if let Some(machine_id) = machine_id {
+            parameters.insert(
+                SnowflakeGenerator::MACHINE_ID.to_string(),
+                machine_id.into(),
+            );
+        }
+
+        let mut this = Self::new(GeneratorType::Snowflake).id_options(range, encoding);
+        if !parameters.is_empty() {
+            this = this.parameters(parameters);
+        }
+
+        this
+    }
+
+    /// Set the range of the timestamp and `encoding` option for the identifier
+    fn id_options(
+        mut self,
+        range: Option<ValueBound<SbrdDateTime>>,
+        encoding: Option<IdEncoding>,
+    ) -> Self {
+        if let Some(range) = range {
+            self = self.range(
+                range.convert_with(|v| v.format(DATE_TIME_DEFAULT_FORMAT).to_string().into()),
+            );
+        }
+        if let Some(encoding) = encoding {
+            self = self.encoding(encoding);
+        }
+
+        self
+    }
+
     //
     // incremental
     //
@@ -423,6 +958,129 @@ impl GeneratorBuilder {
         this
     }
 
+    //
+    // locale
+    //
+
+    /// Create builder for [`PersonNameGenerator`]
+    ///
+    /// [`PersonNameGenerator`]: ../generator/locale/person_name_generator/struct.PersonNameGenerator.html
+    pub fn new_person_name(locale: Option<Locale>) -> Self {
+        let mut this = Self::new(GeneratorType::PersonName);
+        if let Some(locale) = locale {
+            this = this.locale(locale);
+        }
+
+        this
+    }
+
+    /// Create builder for [`CompanyNameGenerator`]
+    ///
+    /// [`CompanyNameGenerator`]: ../generator/locale/company_name_generator/struct.CompanyNameGenerator.html
+    pub fn new_company_name(locale: Option<Locale>) -> Self {
+        let mut this = Self::new(GeneratorType::CompanyName);
+        if let Some(locale) = locale {
+            this = this.locale(locale);
+        }
+
+        this
+    }
+
+    /// Create builder for [`StreetAddressGenerator`]
+    ///
+    /// [`StreetAddressGenerator`]: ../generator/locale/street_address_generator/struct.StreetAddressGenerator.html
+    pub fn new_street_address(locale: Option<Locale>) -> Self {
+        let mut this = Self::new(GeneratorType::StreetAddress);
+        if let Some(locale) = locale {
+            this = this.locale(locale);
+        }
+
+        this
+    }
+
+    /// Create builder for [`CityGenerator`]
+    ///
+    /// [`CityGenerator`]: ../generator/locale/city_generator/struct.CityGenerator.html
+    pub fn new_city(locale: Option<Locale>) -> Self {
+        let mut this = Self::new(GeneratorType::City);
+        if let Some(locale) = locale {
+            this = this.locale(locale);
+        }
+
+        this
+    }
+
+    /// Create builder for [`PostalCodeGenerator`]
+    ///
+    /// [`PostalCodeGenerator`]: ../generator/locale/postal_code_generator/struct.PostalCodeGenerator.html
+    pub fn new_postal_code(locale: Option<Locale>) -> Self {
+        let mut this = Self::new(GeneratorType::PostalCode);
+        if let Some(locale) = locale {
+            this = this.locale(locale);
+        }
+
+        this
+    }
+
+    /// Create builder for [`EmailGenerator`].
+    /// If `format` is specified, the local part is derived from the evaluated format.
+    ///
+    /// [`EmailGenerator`]: ../generator/locale/email_generator/struct.EmailGenerator.html
+    pub fn new_email(locale: Option<Locale>, format: Option<String>) -> Self {
+        let mut this = Self::new(GeneratorType::Email);
+        if let Some(locale) = locale {
+            this = this.locale(locale);
+        }
+        if let Some(format) = format {
+            this = this.format(format);
+        }
+
+        this
+    }
+
+    /// Create builder for [`PhoneNumberGenerator`]
+    ///
+    /// [`PhoneNumberGenerator`]: ../generator/locale/phone_number_generator/struct.PhoneNumberGenerator.html
+    pub fn new_phone_number(locale: Option<Locale>) -> Self {
+        let mut this = Self::new(GeneratorType::PhoneNumber);
+        if let Some(locale) = locale {
+            this = this.locale(locale);
+        }
+
+        this
+    }
+
+    /// Create builder for [`UrlGenerator`].
+    /// If `format` is specified, the subdomain is derived from the evaluated format.
+    ///
+    /// [`UrlGenerator`]: ../generator/locale/url_generator/struct.UrlGenerator.html
+    pub fn new_url(locale: Option<Locale>, format: Option<String>) -> Self {
+        let mut this = Self::new(GeneratorType::Url);
+        if let Some(locale) = locale {
+            this = this.locale(locale);
+        }
+        if let Some(format) = format {
+            this = this.format(format);
+        }
+
+        this
+    }
+
+    /// Create builder for [`LoremGenerator`] with the range of the count of the words
+    ///
+    /// [`LoremGenerator`]: ../generator/locale/lorem_generator/struct.LoremGenerator.html
+    pub fn new_lorem(locale: Option<Locale>, range: Option<ValueBound<SbrdInt>>) -> Self {
+        let mut this = Self::new(GeneratorType::Lorem);
+        if let Some(locale) = locale {
+            this = this.locale(locale);
+        }
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
+
     //
     // primitive
     //
@@ -737,6 +1395,45 @@ impl GeneratorBuilder {
 
         this
     }
+
+    /// Create builder for [`ReferenceGenerator`]
+    ///
+    /// [`ReferenceGenerator`]: ../generator/random_values/reference_generator/struct.ReferenceGenerator.html
+    pub fn new_reference(reference: ValueReference, values: Option<Vec<DataValue>>) -> Self {
+        let mut this = Self::new(GeneratorType::Reference).reference(reference);
+
+        if let Some(values) = values {
+            this = this.values(values);
+        }
+
+        this
+    }
+
+    //
+    // structure
+    //
+
+    /// Create builder for [`ObjectGenerator`] as generator with generate from keyed children
+    ///
+    /// [`ObjectGenerator`]: ../generator/structure/object_generator/struct.ObjectGenerator.html
+    pub fn new_object(children: Vec<ChildGeneratorBuilder>) -> Self {
+        Self::new(GeneratorType::Object).children(children)
+    }
+
+    /// Create builder for [`ArrayGenerator`] as generator with generate from children
+    ///
+    /// [`ArrayGenerator`]: ../generator/structure/array_generator/struct.ArrayGenerator.html
+    pub fn new_array(
+        range: Option<ValueBound<SbrdInt>>,
+        children: Vec<ChildGeneratorBuilder>,
+    ) -> Self {
+        let mut this = Self::new(GeneratorType::Array).children(children);
+        if let Some(range) = range {
+            this = this.range(range.convert_into());
+        }
+
+        this
+    }
 }
 
 //
@@ -745,7 +1442,41 @@ impl GeneratorBuilder {
 impl GeneratorBuilder {
     /// Set `nullable` status to change to nullable
     pub fn nullable(mut self) -> Self {
-        self.nullable = true;
+        self.nullable = Nullable::Flag(true);
+        self
+    }
+
+    /// Set `nullable` status to change to nullable with the probability of generating null
+    pub fn nullable_with_probability(mut self, probability: f64) -> Self {
+        self.nullable = Nullable::Probability(probability);
+        self
+    }
+
+    /// Set `unique` status to change to generate only the unique value
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Set `weighted` status to change to read the weight separated by the last tab in each line in the file at `filepath`
+    pub fn weighted(mut self) -> Self {
+        self.weighted = true;
+        self
+    }
+
+    /// Set `range` option whose bounds are the scripts such as "{key}" evaluated with the context while generating.
+    ///
+    /// Available for int, real, date-time, date, time and duplicate-permutation.
+    pub fn scripted_range(mut self, range: ValueBound<String>) -> Self {
+        self.range = Some(range.convert_into());
+        self
+    }
+
+    /// Set `timezone` option for the generated date-time.
+    ///
+    /// Available for date-time.
+    pub fn timezone(mut self, timezone: TimeZoneOption) -> Self {
+        self.timezone = Some(timezone);
         self
     }
 
@@ -780,6 +1511,21 @@ impl GeneratorBuilder {
         self
     }
 
+    /// Set `pattern` option
+    fn pattern<S>(mut self, pattern: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.pattern = Some(pattern.into());
+        self
+    }
+
+    /// Set `max_repeat` option
+    fn max_repeat(mut self, max_repeat: u32) -> Self {
+        self.max_repeat = Some(max_repeat);
+        self
+    }
+
     /// Set `range` option
     fn range(mut self, range: ValueBound<DataValue>) -> Self {
         self.range = Some(range);
@@ -827,4 +1573,22 @@ impl GeneratorBuilder {
         self.parameters = Some(parameters);
         self
     }
+
+    /// Set `reference` option
+    fn reference(mut self, reference: ValueReference) -> Self {
+        self.reference = Some(reference);
+        self
+    }
+
+    /// Set `encoding` option
+    fn encoding(mut self, encoding: IdEncoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Set `locale` option
+    fn locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
 }
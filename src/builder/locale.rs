@@ -0,0 +1,25 @@
+//! Module for locale
+
+use serde::{Deserialize, Serialize};
+
+/// Locale option
+///
+/// Specify the locale of the bundled dataset used by the generator.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum Locale {
+    /// Locale for English
+    #[default]
+    En,
+    /// Locale for Japanese
+    Ja,
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Locale::En => write!(f, "en"),
+            Locale::Ja => write!(f, "ja"),
+        }
+    }
+}
@@ -2,9 +2,19 @@
 //! Module for builder and it's fields
 
 pub use bound::*;
+pub use encoding::*;
 pub use generator_builder::*;
+pub use locale::*;
+pub use nullable::*;
+pub use reference::*;
 pub use step::*;
+pub use timezone::*;
 
 mod bound;
+mod encoding;
 mod generator_builder;
+mod locale;
+mod nullable;
+mod reference;
 mod step;
+mod timezone;
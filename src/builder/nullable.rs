@@ -0,0 +1,74 @@
+//! Module for nullable
+
+use serde::{Deserialize, Serialize};
+
+/// Nullable option
+///
+/// Specify as a flag or as a probability of generating null.
+/// The flag `true` means the probability [`Nullable::DEFAULT_PROBABILITY`].
+///
+/// [`Nullable::DEFAULT_PROBABILITY`]: #associatedconstant.DEFAULT_PROBABILITY
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone, Copy)]
+#[serde(untagged)]
+pub enum Nullable {
+    /// Nullable flag
+    Flag(bool),
+    /// Probability of generating null
+    Probability(f64),
+}
+
+impl Default for Nullable {
+    fn default() -> Self {
+        Self::Flag(false)
+    }
+}
+
+impl From<bool> for Nullable {
+    fn from(flag: bool) -> Self {
+        Self::Flag(flag)
+    }
+}
+
+impl From<f64> for Nullable {
+    fn from(probability: f64) -> Self {
+        Self::Probability(probability)
+    }
+}
+
+impl std::fmt::Display for Nullable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Nullable::Flag(flag) => write!(f, "{}", flag),
+            Nullable::Probability(probability) => write!(f, "{}", probability),
+        }
+    }
+}
+
+impl Nullable {
+    /// Probability of generating null when specified as `true`
+    pub const DEFAULT_PROBABILITY: f64 = 0.1;
+
+    /// Get probability of generating null
+    pub fn get_probability(&self) -> f64 {
+        match self {
+            Nullable::Flag(true) => Self::DEFAULT_PROBABILITY,
+            Nullable::Flag(false) => 0.0,
+            Nullable::Probability(probability) => *probability,
+        }
+    }
+
+    /// Can generate null flag
+    pub fn is_nullable(&self) -> bool {
+        self.get_probability() > 0.0
+    }
+
+    /// Cannot generate null flag
+    pub fn is_required(&self) -> bool {
+        !self.is_nullable()
+    }
+
+    /// Check the probability is within `0.0` and `1.0`
+    pub fn is_valid(&self) -> bool {
+        (0.0..=1.0).contains(&self.get_probability())
+    }
+}
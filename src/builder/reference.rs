@@ -0,0 +1,71 @@
+//! Module for reference
+
+use crate::builder::ValueBound;
+use crate::value::SbrdInt;
+use serde::{Deserialize, Serialize};
+
+/// Reference option to the values in an other table
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct ValueReference {
+    /// Name of the referenced table
+    table: String,
+    /// Key of the referenced value in the table
+    key: String,
+    /// Key of the weight for picking out the referenced value in the table
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight_key: Option<String>,
+    /// Range of count of referencing to a same record in the table
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cardinality: Option<ValueBound<SbrdInt>>,
+}
+
+impl ValueReference {
+    /// Create ValueReference
+    pub fn new<S1, S2>(table: S1, key: S2) -> Self
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        Self {
+            table: table.into(),
+            key: key.into(),
+            weight_key: None,
+            cardinality: None,
+        }
+    }
+
+    /// Set key of the weight
+    pub fn weight_key<S>(mut self, weight_key: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.weight_key = Some(weight_key.into());
+        self
+    }
+
+    /// Set range of count of referencing to a same record
+    pub fn cardinality(mut self, cardinality: ValueBound<SbrdInt>) -> Self {
+        self.cardinality = Some(cardinality);
+        self
+    }
+
+    /// Get name of the referenced table
+    pub fn get_table(&self) -> &str {
+        &self.table
+    }
+
+    /// Get key of the referenced value
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get key of the weight
+    pub fn get_weight_key(&self) -> &Option<String> {
+        &self.weight_key
+    }
+
+    /// Get range of count of referencing to a same record
+    pub fn get_cardinality(&self) -> &Option<ValueBound<SbrdInt>> {
+        &self.cardinality
+    }
+}
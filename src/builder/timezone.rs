@@ -0,0 +1,74 @@
+//! Module for time zone
+
+use crate::builder::Weight;
+use serde::{Deserialize, Serialize};
+
+/// Time zone option
+///
+/// Specify the time zone as a fixed offset such as "+09:00", "-05:30" and "Z",
+/// or as a name in the IANA time zone database such as "Asia/Tokyo".
+/// The list of the time zones with the weights is also available to pick out a time zone for each generated value.
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+#[serde(untagged)]
+pub enum TimeZoneOption {
+    /// A time zone
+    Zone(String),
+    /// Time zones picked out with the weights
+    Weighted(Vec<WeightedTimeZone>),
+}
+
+impl TimeZoneOption {
+    /// Create TimeZoneOption from a time zone
+    pub fn new_zone<S>(zone: S) -> Self
+    where
+        S: Into<String>,
+    {
+        TimeZoneOption::Zone(zone.into())
+    }
+
+    /// Create TimeZoneOption from the time zones with the weights
+    pub fn new_weighted(zones: Vec<WeightedTimeZone>) -> Self {
+        TimeZoneOption::Weighted(zones)
+    }
+}
+
+/// Time zone with the weight in [`TimeZoneOption`]
+///
+/// [`TimeZoneOption`]: ./enum.TimeZoneOption.html
+#[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Clone)]
+pub struct WeightedTimeZone {
+    /// Time zone
+    zone: String,
+    /// Weight for picking out the time zone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight: Option<Weight>,
+}
+
+impl WeightedTimeZone {
+    /// Create WeightedTimeZone
+    pub fn new<S>(zone: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            zone: zone.into(),
+            weight: None,
+        }
+    }
+
+    /// Set weight for picking out the time zone
+    pub fn weight(mut self, weight: Weight) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Get time zone
+    pub fn get_zone(&self) -> &str {
+        &self.zone
+    }
+
+    /// Get weight for picking out the time zone. The default weight is 1.
+    pub fn get_weight(&self) -> Weight {
+        self.weight.unwrap_or(1)
+    }
+}
@@ -0,0 +1,507 @@
+#![deny(missing_debug_implementations)]
+//! Module for the static check of the schema before generating
+//!
+//! The check parses the `format`, `script` and `case` options and the scripts in the `range` option
+//! without generating values, and reports the problems found in the schema as [`CheckFinding`].
+//!
+//! [`CheckFinding`]: ./struct.CheckFinding.html
+
+use crate::builder::{GeneratorBuilder, ParentGeneratorBuilder, ValueBound};
+use crate::error::BuildError;
+use crate::schema::sort_by_dependency;
+use crate::value::DataValue;
+use crate::GeneratorType;
+use evalexpr::{build_operator_tree, EvalexprError, EvalexprResult, Node, Operator};
+use human_string_filler::StrExt;
+use std::collections::HashSet;
+
+/// Level of the finding by the check
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub enum CheckLevel {
+    /// The schema can generate values, but it may not be intended
+    Warning,
+    /// The schema fails to build or to generate values
+    Error,
+}
+
+impl std::fmt::Display for CheckLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckLevel::Warning => write!(f, "warning"),
+            CheckLevel::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Kind of the finding by the check
+#[derive(Debug, PartialEq, Clone)]
+pub enum CheckKind {
+    /// Fail parse the script or the format.
+    ///
+    /// # Arguments
+    /// * 0: Name of the option
+    /// * 1: Script or format
+    /// * 2: Error message
+    InvalidScript(String, String, String),
+
+    /// The key referenced in the script or the format is not generated in the schema.
+    ///
+    /// # Arguments
+    /// * 0: Referenced key
+    /// * 1: Name of the option
+    UnknownKey(String, String),
+
+    /// The key referenced in the script or the format is not generated before the generator.
+    ///
+    /// # Arguments
+    /// * 0: Referenced key
+    /// * 1: Name of the option
+    KeyNotGeneratedYet(String, String),
+
+    /// The generators reference each other by the scripts and the formats.
+    ///
+    /// # Arguments
+    /// * 0: Keys of the generators in the cycle from the key back to the same key
+    CyclicKeyReference(Vec<String>),
+
+    /// The child generator of `case-when` is never used because the default case precedes it.
+    ///
+    /// # Arguments
+    /// * 0: Index of the child generator
+    UnreachableCase(usize),
+
+    /// The generator is neither specified in `keys` nor referenced by other generators.
+    UnusedGenerator,
+
+    /// `include_end` is specified to the range without `end`.
+    IncludeEndWithoutEnd,
+
+    /// The range is empty because `end` is same as `start` and is not included.
+    ///
+    /// # Arguments
+    /// * 0: Range bound
+    EmptyRangeExcludingEnd(ValueBound<DataValue>),
+}
+
+impl CheckKind {
+    /// Get the level of the finding
+    pub fn get_level(&self) -> CheckLevel {
+        match self {
+            CheckKind::InvalidScript(_, _, _)
+            | CheckKind::UnknownKey(_, _)
+            | CheckKind::KeyNotGeneratedYet(_, _)
+            | CheckKind::CyclicKeyReference(_)
+            | CheckKind::EmptyRangeExcludingEnd(_) => CheckLevel::Error,
+            CheckKind::UnreachableCase(_)
+            | CheckKind::UnusedGenerator
+            | CheckKind::IncludeEndWithoutEnd => CheckLevel::Warning,
+        }
+    }
+}
+
+impl std::fmt::Display for CheckKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckKind::InvalidScript(option, script, e) => {
+                write!(f, "Fail parse {} \"{}\" with error: {}", option, script, e)
+            }
+            CheckKind::UnknownKey(key, option) => {
+                write!(f, "Not Exist Key \"{}\" referenced in {}", key, option)
+            }
+            CheckKind::KeyNotGeneratedYet(key, option) => write!(
+                f,
+                "Key \"{}\" referenced in {} is not generated before this generator",
+                key, option
+            ),
+            CheckKind::CyclicKeyReference(keys) => {
+                write!(f, "Cyclic reference among the keys: {}", keys.join(" -> "))
+            }
+            CheckKind::UnreachableCase(index) => write!(
+                f,
+                "Child at index {} is unreachable because the default case precedes it",
+                index
+            ),
+            CheckKind::UnusedGenerator => {
+                write!(f, "Generator is neither specified in keys nor referenced")
+            }
+            CheckKind::IncludeEndWithoutEnd => {
+                write!(f, "include_end is specified to the range without end")
+            }
+            CheckKind::EmptyRangeExcludingEnd(range) => {
+                write!(f, "Empty Range: {} because the end is not included", range)
+            }
+        }
+    }
+}
+
+/// Finding by the check with the key of the generator which it belongs to
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckFinding {
+    table: Option<String>,
+    key: String,
+    kind: CheckKind,
+}
+
+impl CheckFinding {
+    /// Create finding for the generator at the key
+    fn new(key: &str, kind: CheckKind) -> CheckFinding {
+        CheckFinding {
+            table: None,
+            key: key.to_string(),
+            kind,
+        }
+    }
+
+    /// Set the name of the table which the generator belongs to
+    pub(crate) fn in_table(mut self, table: &str) -> CheckFinding {
+        self.table = Some(table.to_string());
+        self
+    }
+
+    /// Get the level of the finding
+    pub fn get_level(&self) -> CheckLevel {
+        self.kind.get_level()
+    }
+
+    /// Get the name of the table which the generator belongs to
+    pub fn get_table(&self) -> Option<&str> {
+        self.table.as_deref()
+    }
+
+    /// Get the key of the generator.
+    /// The key of the child generator is joined with the parent's key by ".".
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the kind of the finding
+    pub fn get_kind(&self) -> &CheckKind {
+        &self.kind
+    }
+
+    /// Check the finding is an error
+    pub fn is_error(&self) -> bool {
+        self.get_level() == CheckLevel::Error
+    }
+}
+
+impl std::fmt::Display for CheckFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.table {
+            None => write!(f, "{}: [{}] {}", self.get_level(), self.key, self.kind),
+            Some(table) => write!(
+                f,
+                "{}: [{}.{}] {}",
+                self.get_level(),
+                table,
+                self.key,
+                self.kind
+            ),
+        }
+    }
+}
+
+/// Check the generators in the schema
+pub(crate) fn check_schema(
+    keys: &[String],
+    builders: &[ParentGeneratorBuilder],
+    unique_keys: &[Vec<String>],
+) -> Vec<CheckFinding> {
+    let mut checker = Checker {
+        defined: HashSet::new(),
+        referenced: HashSet::new(),
+        findings: Vec::new(),
+    };
+    for parent_builder in builders.iter() {
+        checker.defined.insert(parent_builder.key.clone());
+        checker.collect_defined(&parent_builder.builder);
+    }
+
+    // the generators are sorted so that the referenced generator precedes, except the cyclic references
+    for parent_builder in builders.iter() {
+        let visible: Vec<&str> = builders
+            .iter()
+            .map(|other| other.key.as_str())
+            .filter(|other_key| other_key != &parent_builder.key)
+            .collect();
+        checker.check_builder(&parent_builder.key, &parent_builder.builder, &visible);
+    }
+
+    if let Err(BuildError::CyclicKeyReference(cycle)) = sort_by_dependency(builders.to_vec()) {
+        let key = cycle[0].clone();
+        checker.findings.push(CheckFinding::new(
+            &key,
+            CheckKind::CyclicKeyReference(cycle),
+        ));
+    }
+
+    for parent_builder in builders.iter() {
+        let key = &parent_builder.key;
+        if !keys.contains(key)
+            && !checker.referenced.contains(key)
+            && !unique_keys.iter().any(|keys| keys.contains(key))
+        {
+            checker
+                .findings
+                .push(CheckFinding::new(key, CheckKind::UnusedGenerator));
+        }
+    }
+
+    checker.findings
+}
+
+/// Walker of the generators collecting the findings
+struct Checker {
+    /// All keys generated in the schema including the keys of the children
+    defined: HashSet<String>,
+    /// Keys referenced by the scripts and the formats
+    referenced: HashSet<String>,
+    findings: Vec<CheckFinding>,
+}
+
+impl Checker {
+    /// Collect the keys of the children generated in the object
+    fn collect_defined(&mut self, builder: &GeneratorBuilder) {
+        if let Some(children) = &builder.children {
+            for child in children.iter() {
+                if let Some(key) = &child.key {
+                    self.defined.insert(key.clone());
+                }
+                self.collect_defined(&child.builder);
+            }
+        }
+    }
+
+    /// Check the generator at the key with the keys generated before it
+    fn check_builder(&mut self, key: &str, builder: &GeneratorBuilder, visible: &[&str]) {
+        if let Some(format) = &builder.format {
+            self.check_script(key, "format", format, false, visible);
+        }
+        if let Some(script) = &builder.script {
+            self.check_script(key, "script", script, true, visible);
+        }
+        if let Some(range) = &builder.range {
+            self.check_range(key, range, visible);
+        }
+
+        let children = match &builder.children {
+            None => return,
+            Some(children) => children,
+        };
+        let mut child_visible = visible.to_vec();
+        let mut has_default_case = false;
+        for (index, child) in children.iter().enumerate() {
+            if builder.generator_type == GeneratorType::CaseWhen {
+                if has_default_case {
+                    self.findings
+                        .push(CheckFinding::new(key, CheckKind::UnreachableCase(index)));
+                }
+                has_default_case |= child.condition.is_none();
+            }
+            if let Some(condition) = &child.condition {
+                self.check_script(key, "case", condition, true, &child_visible);
+            }
+
+            match &child.key {
+                Some(child_key) if builder.generator_type == GeneratorType::Object => {
+                    let child_path = format!("{}.{}", key, child_key);
+                    self.check_builder(&child_path, &child.builder, &child_visible);
+                    child_visible.push(child_key);
+                }
+                _ => self.check_builder(key, &child.builder, &child_visible),
+            }
+        }
+    }
+
+    /// Check the scripts in the bounds and the use of `include_end` in the range
+    fn check_range(&mut self, key: &str, range: &ValueBound<DataValue>, visible: &[&str]) {
+        for bound in [range.get_start(), range.get_end()].into_iter().flatten() {
+            if let DataValue::String(script) = bound {
+                if script.contains('{') {
+                    self.check_script(key, "range", script, false, visible);
+                }
+            }
+        }
+
+        match (range.get_start(), range.get_end()) {
+            (_, None) if !range.is_include_end() => self
+                .findings
+                .push(CheckFinding::new(key, CheckKind::IncludeEndWithoutEnd)),
+            (Some(start), Some(end)) if !range.is_include_end() && start == end => {
+                self.findings.push(CheckFinding::new(
+                    key,
+                    CheckKind::EmptyRangeExcludingEnd(range.clone()),
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    /// Check the keys referenced in the script or the format.
+    /// If `is_expression` is true, also check the syntax of the script replaced the keys with a dummy value.
+    fn check_script(
+        &mut self,
+        key: &str,
+        option: &str,
+        script: &str,
+        is_expression: bool,
+        visible: &[&str],
+    ) {
+        let (replaced, referenced_keys) = match fill_replace_keys(script, &self.defined) {
+            Ok(filled) => filled,
+            Err(e) => {
+                self.findings.push(CheckFinding::new(
+                    key,
+                    CheckKind::InvalidScript(option.to_string(), script.to_string(), e),
+                ));
+                return;
+            }
+        };
+
+        for referenced_key in referenced_keys.into_iter() {
+            if visible.contains(&referenced_key.as_str()) {
+                self.referenced.insert(referenced_key);
+                continue;
+            }
+
+            let kind = if self.defined.contains(&referenced_key) {
+                CheckKind::KeyNotGeneratedYet(referenced_key.clone(), option.to_string())
+            } else {
+                CheckKind::UnknownKey(referenced_key.clone(), option.to_string())
+            };
+            self.findings.push(CheckFinding::new(key, kind));
+            self.referenced.insert(referenced_key);
+        }
+
+        if is_expression {
+            if let Err(e) = build_operator_tree(&replaced).and_then(|tree| check_arguments(&tree)) {
+                self.findings.push(CheckFinding::new(
+                    key,
+                    CheckKind::InvalidScript(option.to_string(), script.to_string(), e.to_string()),
+                ));
+            }
+        }
+    }
+}
+
+/// Get the keys of the generators referenced by the scripts and the formats in the builder and the children.
+/// The keys of the children generated in the object before the referencing child are not included.
+pub(crate) fn collect_referenced_keys(
+    builder: &GeneratorBuilder,
+    defined: &HashSet<String>,
+) -> Vec<String> {
+    let mut referenced_keys = Vec::new();
+    collect_referenced_keys_into(builder, defined, &[], &mut referenced_keys);
+
+    referenced_keys
+}
+
+/// Collect the referenced keys which are not the local keys into the list
+fn collect_referenced_keys_into(
+    builder: &GeneratorBuilder,
+    defined: &HashSet<String>,
+    local_keys: &[&str],
+    referenced_keys: &mut Vec<String>,
+) {
+    let mut scripts: Vec<&str> = Vec::new();
+    scripts.extend(builder.format.as_deref());
+    scripts.extend(builder.script.as_deref());
+    if let Some(range) = &builder.range {
+        for bound in [range.get_start(), range.get_end()].into_iter().flatten() {
+            if let DataValue::String(script) = bound {
+                if script.contains('{') {
+                    scripts.push(script);
+                }
+            }
+        }
+    }
+    if let Some(children) = &builder.children {
+        scripts.extend(
+            children
+                .iter()
+                .filter_map(|child| child.condition.as_deref()),
+        );
+    }
+
+    for script in scripts.into_iter() {
+        // the script which fails to parse is reported while generating
+        if let Ok((_, keys)) = fill_replace_keys(script, defined) {
+            for key in keys.into_iter() {
+                if !local_keys.contains(&key.as_str()) && !referenced_keys.contains(&key) {
+                    referenced_keys.push(key);
+                }
+            }
+        }
+    }
+
+    if let Some(children) = &builder.children {
+        let mut child_local_keys = local_keys.to_vec();
+        for child in children.iter() {
+            collect_referenced_keys_into(
+                &child.builder,
+                defined,
+                &child_local_keys,
+                referenced_keys,
+            );
+            if let (Some(child_key), GeneratorType::Object) = (&child.key, builder.generator_type) {
+                child_local_keys.push(child_key);
+            }
+        }
+    }
+}
+
+/// Replace the replace-key-syntax in the script with a dummy value, and get the replaced script and the referenced keys
+fn fill_replace_keys(
+    script: &str,
+    defined: &HashSet<String>,
+) -> Result<(String, Vec<String>), String> {
+    let mut referenced_keys: Vec<String> = Vec::new();
+    let mut replaced = String::new();
+    script
+        .fill_into::<_, _, String>(&mut replaced, |output: &mut String, replace_key: &str| {
+            let referenced_key = resolve_key(replace_key, defined);
+            if !referenced_keys.iter().any(|k| k == referenced_key) {
+                referenced_keys.push(referenced_key.to_string());
+            }
+            output.push('0');
+            Ok(())
+        })
+        .map_err(|e| e.to_string())?;
+
+    Ok((replaced, referenced_keys))
+}
+
+/// Get the key in the replace-key-syntax without the format option as the evaluator does
+fn resolve_key<'a>(replace_key: &'a str, defined: &HashSet<String>) -> &'a str {
+    if defined.contains(replace_key) {
+        return replace_key;
+    }
+
+    match replace_key.rfind(':') {
+        None => replace_key,
+        Some(split_index) => &replace_key[0..split_index],
+    }
+}
+
+/// Check the count of the arguments of the operators in the tree,
+/// because the parser accepts an operator without the operands such as "1 >" and it fails only while evaluating.
+fn check_arguments(node: &Node) -> EvalexprResult<()> {
+    let expected = match node.operator() {
+        Operator::Not | Operator::Neg => Some(1),
+        Operator::RootNode
+        | Operator::Tuple
+        | Operator::Chain
+        | Operator::Const { .. }
+        | Operator::VariableIdentifier { .. }
+        | Operator::FunctionIdentifier { .. } => None,
+        _ => Some(2),
+    };
+    if let Some(expected) = expected {
+        let actual = node.children().len();
+        if actual != expected {
+            return Err(EvalexprError::WrongOperatorArgumentAmount { expected, actual });
+        }
+    }
+
+    node.children().iter().try_for_each(check_arguments)
+}
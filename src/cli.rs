@@ -2,17 +2,21 @@
 #![allow(deprecated)]
 //! Application for Sbrd Generator.
 
-use clap::{AppSettings, ArgEnum, Parser};
-use rand::prelude::ThreadRng;
-use rand::thread_rng;
+use clap::{AppSettings, ArgEnum, Args, Parser, Subcommand};
+use sbrd_gen::check::CheckFinding;
 use sbrd_gen::error::{BuildError, SchemaResult};
 use sbrd_gen::file::set_schema_file_path;
-use sbrd_gen::generator::Randomizer;
-use sbrd_gen::parser::{JsonParser, SchemaParser, YamlParser};
+use sbrd_gen::generator::{gen_randomizer_seed, RandomizerSeed, SeededRandomizer};
+use sbrd_gen::infer::{SampleFormat, SchemaInferrer, DEFAULT_SELECT_LIMIT};
+use sbrd_gen::parser::{
+    set_openapi_component, JsonParser, OpenApiParser, SchemaParser, SqlParser, YamlParser,
+};
+use sbrd_gen::value::DateTimeRepresentation;
 use sbrd_gen::writer::{
-    CsvWriter, GeneratedValueWriterBase, PrettyJsonWriter, TsvWriter, YamlWriter,
+    CsvWriter, GeneratedValueWriterBase, PrettyJsonWriter, SqlDialect, SqlWriter, TsvWriter,
+    YamlWriter, DEFAULT_SQL_BATCH_SIZE, DEFAULT_SQL_TABLE_NAME,
 };
-use sbrd_gen::{Schema, SchemaBuilder};
+use sbrd_gen::{GeneratedTable, GeneratedValuesIter, Schema, SchemaBuilder, TablesSchemaBuilder};
 use std::fs::File;
 use std::io;
 use std::io::{stdout, BufWriter, Stdout};
@@ -27,6 +31,10 @@ pub enum ParserType {
     Yaml,
     /// parse from json
     Json,
+    /// parse from `CREATE TABLE` statements of sql
+    Sql,
+    /// parse from the component schemas of OpenAPI 3
+    Openapi,
 }
 
 /// Type of Output for this generator
@@ -41,6 +49,43 @@ pub enum OutputType {
     Csv,
     /// write as tsv
     Tsv,
+    /// write as sql
+    Sql,
+}
+
+impl OutputType {
+    /// Extension of the file written as the type
+    fn get_extension(&self) -> &'static str {
+        match self {
+            OutputType::Yaml => "yaml",
+            OutputType::Json => "json",
+            OutputType::Csv => "csv",
+            OutputType::Tsv => "tsv",
+            OutputType::Sql => "sql",
+        }
+    }
+}
+
+/// Dialect of SQL for output as sql
+#[derive(ArgEnum, Debug, Eq, PartialEq, Copy, Clone)]
+#[clap(rename_all = "kebab-case")]
+pub enum SqlDialectType {
+    /// write for PostgreSQL
+    Postgresql,
+    /// write for MySQL
+    Mysql,
+    /// write for SQLite
+    Sqlite,
+}
+
+impl From<SqlDialectType> for SqlDialect {
+    fn from(dialect_type: SqlDialectType) -> Self {
+        match dialect_type {
+            SqlDialectType::Postgresql => SqlDialect::PostgreSql,
+            SqlDialectType::Mysql => SqlDialect::MySql,
+            SqlDialectType::Sqlite => SqlDialect::Sqlite,
+        }
+    }
 }
 
 /// Application for Sbrd Generator
@@ -52,25 +97,97 @@ pub enum OutputType {
 )]
 #[clap(global_setting(AppSettings::DeriveDisplayOrder))]
 #[clap(global_setting(AppSettings::NextLineHelp))]
+#[clap(setting(AppSettings::SubcommandsNegateReqs))]
+#[clap(setting(AppSettings::ArgsNegateSubcommands))]
 pub struct SbrdGenApp {
+    /// Command instead of generating values
+    #[clap(subcommand)]
+    command: Option<SbrdGenCommand>,
+
     /// Schema for generate value
     ///
     /// You must specify `keys` and `builders` as sequence in the schema.
     /// To learn more about the schema, see: https://github.com/ogata-k/sbrd-gen/blob/v0.1.x/README.md#about-schema
-    schema_file_path: PathBuf,
+    #[clap(required = true)]
+    schema_file_path: Option<PathBuf>,
 
     /// Type of Parser for schema
     #[clap(short = 'p', long = "parser", arg_enum, default_value_t = ParserType::Yaml)]
     parser_type: ParserType,
 
+    /// Name of the component schema to parse when parsed as openapi
+    ///
+    /// If not specified, the only object component is parsed.
+    /// This is not used with `--tables`, because all object components are parsed as the tables.
+    #[clap(long = "component")]
+    component: Option<String>,
+
     /// Type of Output for this generator
     #[clap(short = 't', long = "type", arg_enum, default_value_t = OutputType::Json)]
     output_type: OutputType,
 
+    /// Name of the table to insert the generated values when output as sql
+    ///
+    /// This is not used with `--tables`, because the table's name is used instead.
+    #[clap(long = "table", default_value = DEFAULT_SQL_TABLE_NAME)]
+    table: String,
+
+    /// Dialect of SQL when output as sql
+    #[clap(long = "dialect", arg_enum, default_value_t = SqlDialectType::Postgresql)]
+    dialect: SqlDialectType,
+
+    /// Count of the values sets inserted in an INSERT statement when output as sql
+    ///
+    /// If 0 is specified, all values sets are inserted in an INSERT statement.
+    #[clap(long = "batch", default_value_t = DEFAULT_SQL_BATCH_SIZE)]
+    batch_size: usize,
+
+    /// Representation of the generated date-time, date and time values
+    ///
+    /// Specify "format" for the `format` option of each generator, "iso8601" for ISO-8601,
+    /// "epoch-seconds" or "epoch-millis" for the integer since the Unix epoch,
+    /// otherwise the value is formatted with the specified format.
+    #[clap(long = "date-format", default_value = "format")]
+    date_format: DateTimeRepresentation,
+
+    /// Flag for parse the schema as a schema consisting of tables
+    ///
+    /// You must specify `tables` as sequence in the schema, and `name` and `count` for each table.
+    /// The count of generate values for each table is `count` of the table.
+    #[clap(long = "tables")]
+    tables: bool,
+
+    /// Directory for write a file for each table
+    ///
+    /// Each file is named as the table's name with the extension of the output type.
+    /// If not specified, all tables are written to standard output as sections.
+    #[clap(long = "output-dir", requires = "tables")]
+    output_dir: Option<PathBuf>,
+
     /// Count of generate values
     #[clap(short = 'n', long = "num", default_value = "10")]
     count: u64,
 
+    /// Count of threads for generate values in parallel
+    ///
+    /// Each values set is generated with the randomizer for its index,
+    /// so the same seed always generates the same output with or without this option.
+    /// The values sets are generated and written in bounded chunks in order.
+    /// If 0 is specified, use the available parallelism.
+    #[clap(short = 'j', long = "jobs", conflicts_with = "tables")]
+    jobs: Option<usize>,
+
+    /// Seed for generate values
+    ///
+    /// The same schema, seed and count always generate the same output.
+    /// If not specified, the seed is picked out at random.
+    #[clap(long = "seed")]
+    seed: Option<RandomizerSeed>,
+
+    /// Flag for print the seed used to generate values to standard error
+    #[clap(long = "print-seed")]
+    print_seed: bool,
+
     /// Flag for generate without key's header
     #[clap(long = "no-header")]
     no_header: bool,
@@ -80,23 +197,335 @@ pub struct SbrdGenApp {
     dry_run: bool,
 }
 
-impl SbrdGenApp {
-    /// run app
-    pub fn run(self) -> ! {
-        // set load current filepath
-        set_schema_file_path(self.schema_file_path.as_path());
+/// Command of Sbrd Generator
+#[derive(Subcommand, Debug, PartialEq, Eq, Clone)]
+pub enum SbrdGenCommand {
+    /// Check the schema without generating values
+    ///
+    /// Parse every script and format in the schema, and report the problems as warnings or errors
+    /// with the key of the generator. If no error is found, also build the schema and report the error while building.
+    /// Exit with an error status if an error is found.
+    Check(CheckCommand),
+
+    /// Export JSON Schema (draft 2020-12) describing the generated values
+    ///
+    /// Each values set is described as an object consisting of the specified keys.
+    /// With the tables, the values set of each table is defined under `$defs` with the table's name.
+    ExportJsonSchema(ExportJsonSchemaCommand),
+
+    /// Infer a schema from the sample data
+    ///
+    /// Read the sample data and print the schema as yaml.
+    /// Each column is inferred as the generator from the type, the range, the ratio of the null values,
+    /// the count of the distinct values and whether the integers are strictly increasing.
+    Infer(InferCommand),
+}
+
+/// Command for check the schema
+#[derive(Args, Debug, PartialEq, Eq, Clone)]
+pub struct CheckCommand {
+    /// Schema for check
+    schema_file_path: PathBuf,
+
+    /// Type of Parser for schema
+    #[clap(short = 'p', long = "parser", arg_enum, default_value_t = ParserType::Yaml)]
+    parser_type: ParserType,
+
+    /// Name of the component schema to parse when parsed as openapi
+    ///
+    /// If not specified, the only object component is parsed.
+    /// This is not used with `--tables`, because all object components are parsed as the tables.
+    #[clap(long = "component")]
+    component: Option<String>,
+
+    /// Flag for parse the schema as a schema consisting of tables
+    #[clap(long = "tables")]
+    tables: bool,
+
+    /// Flag for exit with an error status if a warning is found
+    #[clap(long = "deny-warnings")]
+    deny_warnings: bool,
+}
+
+impl CheckCommand {
+    /// run command
+    fn run(self) -> ! {
+        let file = open_schema_file(&self.schema_file_path, &self.component);
+
+        let (findings, build_result): (Vec<CheckFinding>, SchemaResult<()>) = if self.tables {
+            let schema_builder: TablesSchemaBuilder = match self.parser_type {
+                ParserType::Yaml => YamlParser::parse_tables_from_reader(file),
+                ParserType::Json => JsonParser::parse_tables_from_reader(file),
+                ParserType::Sql => SqlParser::parse_tables_from_reader(file),
+                ParserType::Openapi => OpenApiParser::parse_tables_from_reader(file),
+            }
+            .unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                exit(exitcode::DATAERR);
+            });
+
+            let findings = schema_builder.check();
+            let build_result = if findings.iter().any(|f| f.is_error()) {
+                Ok(())
+            } else {
+                schema_builder.build::<SeededRandomizer>().map(|_| ())
+            };
+
+            (findings, build_result)
+        } else {
+            let schema_builder: SchemaBuilder = match self.parser_type {
+                ParserType::Yaml => YamlParser::parse_from_reader(file),
+                ParserType::Json => JsonParser::parse_from_reader(file),
+                ParserType::Sql => SqlParser::parse_from_reader(file),
+                ParserType::Openapi => OpenApiParser::parse_from_reader(file),
+            }
+            .unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                exit(exitcode::DATAERR);
+            });
+
+            let findings = schema_builder.check();
+            let build_result = if findings.iter().any(|f| f.is_error()) {
+                Ok(())
+            } else {
+                schema_builder.build::<SeededRandomizer>().map(|_| ())
+            };
+
+            (findings, build_result)
+        };
+
+        for finding in findings.iter() {
+            println!("{}", finding);
+        }
+        let mut error_count = findings.iter().filter(|f| f.is_error()).count();
+        let warning_count = findings.len() - error_count;
+        if let Err(e) = build_result {
+            println!("error: {}", e);
+            error_count += 1;
+        }
+
+        if error_count == 0 && warning_count == 0 {
+            println!("Check Succeed");
+            exit(exitcode::OK);
+        }
+
+        println!("{} error(s), {} warning(s)", error_count, warning_count);
+        if error_count > 0 || self.deny_warnings {
+            exit(exitcode::DATAERR);
+        }
+
+        exit(exitcode::OK)
+    }
+}
+
+/// Command for export JSON Schema describing the generated values
+#[derive(Args, Debug, PartialEq, Eq, Clone)]
+pub struct ExportJsonSchemaCommand {
+    /// Schema for export
+    schema_file_path: PathBuf,
+
+    /// Type of Parser for schema
+    #[clap(short = 'p', long = "parser", arg_enum, default_value_t = ParserType::Yaml)]
+    parser_type: ParserType,
+
+    /// Name of the component schema to parse when parsed as openapi
+    ///
+    /// If not specified, the only object component is parsed.
+    /// This is not used with `--tables`, because all object components are parsed as the tables.
+    #[clap(long = "component")]
+    component: Option<String>,
+
+    /// Flag for parse the schema as a schema consisting of tables
+    #[clap(long = "tables")]
+    tables: bool,
+
+    /// Representation of the generated date-time, date and time values
+    ///
+    /// Specify the same representation as generating values.
+    #[clap(long = "date-format", default_value = "format")]
+    date_format: DateTimeRepresentation,
+}
+
+impl ExportJsonSchemaCommand {
+    /// run command
+    fn run(self) -> ! {
+        let file = open_schema_file(&self.schema_file_path, &self.component);
+
+        let json_schema = if self.tables {
+            let schema_builder: TablesSchemaBuilder = match self.parser_type {
+                ParserType::Yaml => YamlParser::parse_tables_from_reader(file),
+                ParserType::Json => JsonParser::parse_tables_from_reader(file),
+                ParserType::Sql => SqlParser::parse_tables_from_reader(file),
+                ParserType::Openapi => OpenApiParser::parse_tables_from_reader(file),
+            }
+            .unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                exit(exitcode::DATAERR);
+            });
+
+            schema_builder
+                .clone()
+                .build::<SeededRandomizer>()
+                .and_then(|_| schema_builder.to_json_schema(&self.date_format))
+        } else {
+            let schema_builder: SchemaBuilder = match self.parser_type {
+                ParserType::Yaml => YamlParser::parse_from_reader(file),
+                ParserType::Json => JsonParser::parse_from_reader(file),
+                ParserType::Sql => SqlParser::parse_from_reader(file),
+                ParserType::Openapi => OpenApiParser::parse_from_reader(file),
+            }
+            .unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                exit(exitcode::DATAERR);
+            });
+
+            schema_builder
+                .build::<SeededRandomizer>()
+                .and_then(|schema| schema.to_json_schema(&self.date_format))
+        }
+        .unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            exit(exitcode::DATAERR);
+        });
+
+        match serde_json::to_string_pretty(&json_schema) {
+            Ok(json_schema) => println!("{}", json_schema),
+            Err(e) => {
+                eprintln!("{}", e);
+                exit(exitcode::SOFTWARE);
+            }
+        }
 
-        let file = File::open(self.schema_file_path.as_path()).unwrap_or_else(|e| {
+        exit(exitcode::OK)
+    }
+}
+
+/// Type of the sample data for infer a schema
+#[derive(ArgEnum, Debug, Eq, PartialEq, Copy, Clone)]
+#[clap(rename_all = "kebab-case")]
+pub enum SampleType {
+    /// read as csv with the header
+    Csv,
+    /// read as tsv with the header
+    Tsv,
+    /// read as a json object per line
+    JsonLines,
+}
+
+impl From<SampleType> for SampleFormat {
+    fn from(sample_type: SampleType) -> Self {
+        match sample_type {
+            SampleType::Csv => SampleFormat::Csv,
+            SampleType::Tsv => SampleFormat::Tsv,
+            SampleType::JsonLines => SampleFormat::JsonLines,
+        }
+    }
+}
+
+/// Command for infer a schema from the sample data
+#[derive(Args, Debug, PartialEq, Eq, Clone)]
+pub struct InferCommand {
+    /// Sample data for infer
+    sample_file_path: PathBuf,
+
+    /// Type of the sample data
+    ///
+    /// If not specified, the type is decided from the extension of the sample file:
+    /// "tsv" for tsv, "jsonl" and "ndjson" for json-lines, and csv for the others.
+    #[clap(short = 't', long = "type", arg_enum)]
+    sample_type: Option<SampleType>,
+
+    /// Limit of the count of the distinct values for the column inferred as select-* generator
+    #[clap(long = "select-limit", default_value_t = DEFAULT_SELECT_LIMIT)]
+    select_limit: usize,
+}
+
+impl InferCommand {
+    /// run command
+    fn run(self) -> ! {
+        let sample_type = self.sample_type.unwrap_or_else(|| {
+            match self
+                .sample_file_path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .map(|extension| extension.to_ascii_lowercase())
+                .as_deref()
+            {
+                Some("tsv") => SampleType::Tsv,
+                Some("jsonl") | Some("ndjson") => SampleType::JsonLines,
+                _ => SampleType::Csv,
+            }
+        });
+
+        let file = File::open(&self.sample_file_path).unwrap_or_else(|e| {
             eprintln!(
                 "{}",
-                BuildError::FileError(e, self.schema_file_path.clone())
+                BuildError::FileError(e, self.sample_file_path.clone())
             );
             exit(exitcode::IOERR);
         });
 
+        let schema_builder = SchemaInferrer::new(sample_type.into())
+            .select_limit(self.select_limit)
+            .infer_from_reader(file)
+            .unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                exit(exitcode::DATAERR);
+            });
+
+        match serde_yaml::to_string(&schema_builder) {
+            Ok(schema) => print!("{}", schema),
+            Err(e) => {
+                eprintln!("{}", e);
+                exit(exitcode::SOFTWARE);
+            }
+        }
+
+        exit(exitcode::OK)
+    }
+}
+
+/// helper to open the schema file after setting the filepath for the relative paths in the schema
+/// and the component to parse when parsed as openapi
+fn open_schema_file(schema_file_path: &std::path::Path, component: &Option<String>) -> File {
+    // set load current filepath
+    set_schema_file_path(schema_file_path);
+    if let Some(component) = component {
+        set_openapi_component(component);
+    }
+
+    File::open(schema_file_path).unwrap_or_else(|e| {
+        eprintln!(
+            "{}",
+            BuildError::FileError(e, schema_file_path.to_path_buf())
+        );
+        exit(exitcode::IOERR);
+    })
+}
+
+impl SbrdGenApp {
+    /// run app
+    pub fn run(self) -> ! {
+        let schema_file_path = match self.command {
+            Some(SbrdGenCommand::Check(check_command)) => check_command.run(),
+            Some(SbrdGenCommand::ExportJsonSchema(export_command)) => export_command.run(),
+            Some(SbrdGenCommand::Infer(infer_command)) => infer_command.run(),
+            None => self
+                .schema_file_path
+                .clone()
+                .expect("Schema file path is required without command"),
+        };
+        let file = open_schema_file(&schema_file_path, &self.component);
+
+        if self.tables {
+            self.run_tables(file);
+        }
+
         let schema_builder: SchemaBuilder = match self.parser_type {
             ParserType::Yaml => YamlParser::parse_from_reader(file),
             ParserType::Json => JsonParser::parse_from_reader(file),
+            ParserType::Sql => SqlParser::parse_from_reader(file),
+            ParserType::Openapi => OpenApiParser::parse_from_reader(file),
         }
         .unwrap_or_else(|e| {
             eprintln!("{}", e);
@@ -113,27 +542,107 @@ impl SbrdGenApp {
             exit(exitcode::OK);
         }
 
-        type Rng = ThreadRng;
-        let mut rng = thread_rng();
+        let seed = self.seed.unwrap_or_else(gen_randomizer_seed);
+        if self.print_seed {
+            eprintln!("seed: {}", seed);
+        }
+
+        // generate each values set with the randomizer for the index so that the jobs does not change the values sets
+        let values_sets = schema.generate_iter(seed, self.count, self.jobs.unwrap_or(1));
 
         type Output = BufWriter<Stdout>;
         let output = BufWriter::new(stdout());
         let output_result: SchemaResult<()> = match self.output_type {
-            OutputType::Yaml => {
-                self.write_all_data::<Output, YamlWriter<Output>, Rng>(output, &schema, &mut rng)
-            }
+            OutputType::Yaml => self.write_all_data::<Output, YamlWriter<Output>>(
+                YamlWriter::from_writer(output).date_time_representation(self.date_format.clone()),
+                &schema,
+                values_sets,
+            ),
             OutputType::Json => {
                 // use human readable json writer
-                self.write_all_data::<Output, PrettyJsonWriter<Output>, Rng>(
-                    output, &schema, &mut rng,
+                self.write_all_data::<Output, PrettyJsonWriter<Output>>(
+                    PrettyJsonWriter::from_writer(output)
+                        .date_time_representation(self.date_format.clone()),
+                    &schema,
+                    values_sets,
                 )
             }
-            OutputType::Csv => {
-                self.write_all_data::<Output, CsvWriter<Output>, Rng>(output, &schema, &mut rng)
-            }
-            OutputType::Tsv => {
-                self.write_all_data::<Output, TsvWriter<Output>, Rng>(output, &schema, &mut rng)
+            OutputType::Csv => self.write_all_data::<Output, CsvWriter<Output>>(
+                CsvWriter::from_writer(output).date_time_representation(self.date_format.clone()),
+                &schema,
+                values_sets,
+            ),
+            OutputType::Tsv => self.write_all_data::<Output, TsvWriter<Output>>(
+                TsvWriter::from_writer(output).date_time_representation(self.date_format.clone()),
+                &schema,
+                values_sets,
+            ),
+            OutputType::Sql => self.write_all_data::<Output, SqlWriter<Output>>(
+                self.build_sql_writer(output).table(self.table.as_str()),
+                &schema,
+                values_sets,
+            ),
+        };
+
+        output_result.unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            exit(exitcode::SOFTWARE);
+        });
+
+        exit(exitcode::OK)
+    }
+
+    /// run app for the schema consisting of tables
+    fn run_tables(self, file: File) -> ! {
+        let schema_builder: TablesSchemaBuilder = match self.parser_type {
+            ParserType::Yaml => YamlParser::parse_tables_from_reader(file),
+            ParserType::Json => JsonParser::parse_tables_from_reader(file),
+            ParserType::Sql => SqlParser::parse_tables_from_reader(file),
+            ParserType::Openapi => OpenApiParser::parse_tables_from_reader(file),
+        }
+        .unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            exit(exitcode::IOERR);
+        });
+
+        let schema = schema_builder.build().unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            exit(exitcode::IOERR);
+        });
+
+        if self.dry_run {
+            println!("Parse Succeed");
+            exit(exitcode::OK);
+        }
+
+        let seed = self.seed.unwrap_or_else(gen_randomizer_seed);
+        if self.print_seed {
+            eprintln!("seed: {}", seed);
+        }
+
+        let tables = schema.generate_from_seed(seed).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            exit(exitcode::SOFTWARE);
+        });
+
+        let output_result: SchemaResult<()> = match &self.output_dir {
+            None => {
+                let output = BufWriter::new(stdout());
+                self.write_tables_data(output, &tables)
             }
+            Some(output_dir) => tables.iter().try_for_each(|table| {
+                let filepath = output_dir.join(format!(
+                    "{}.{}",
+                    table.get_name(),
+                    self.output_type.get_extension()
+                ));
+                let file = File::create(filepath.as_path()).unwrap_or_else(|e| {
+                    eprintln!("{}", BuildError::FileError(e, filepath.clone()));
+                    exit(exitcode::IOERR);
+                });
+
+                self.write_table_data(BufWriter::new(file), table)
+            }),
         };
 
         output_result.unwrap_or_else(|e| {
@@ -144,21 +653,85 @@ impl SbrdGenApp {
         exit(exitcode::OK)
     }
 
-    /// helper to write all generated value's data
-    fn write_all_data<O, Writer, R>(
+    /// helper to write the generated table's data
+    fn write_table_data<O: io::Write>(
+        &self,
+        output: O,
+        table: &GeneratedTable,
+    ) -> SchemaResult<()> {
+        let use_key_header = !self.no_header;
+        match self.output_type {
+            OutputType::Yaml => YamlWriter::from_writer(output)
+                .date_time_representation(self.date_format.clone())
+                .write_table(use_key_header, table),
+            OutputType::Json => {
+                // use human readable json writer
+                PrettyJsonWriter::from_writer(output)
+                    .date_time_representation(self.date_format.clone())
+                    .write_table(use_key_header, table)
+            }
+            OutputType::Csv => CsvWriter::from_writer(output)
+                .date_time_representation(self.date_format.clone())
+                .write_table(use_key_header, table),
+            OutputType::Tsv => TsvWriter::from_writer(output)
+                .date_time_representation(self.date_format.clone())
+                .write_table(use_key_header, table),
+            OutputType::Sql => self
+                .build_sql_writer(output)
+                .write_table(use_key_header, table),
+        }
+    }
+
+    /// helper to write all generated table's data
+    fn write_tables_data<O: io::Write>(
         &self,
         output: O,
-        schema: &Schema<R>,
-        rng: &mut R,
+        tables: &[GeneratedTable],
+    ) -> SchemaResult<()> {
+        let use_key_header = !self.no_header;
+        match self.output_type {
+            OutputType::Yaml => YamlWriter::from_writer(output)
+                .date_time_representation(self.date_format.clone())
+                .write_tables(use_key_header, tables),
+            OutputType::Json => {
+                // use human readable json writer
+                PrettyJsonWriter::from_writer(output)
+                    .date_time_representation(self.date_format.clone())
+                    .write_tables(use_key_header, tables)
+            }
+            OutputType::Csv => CsvWriter::from_writer(output)
+                .date_time_representation(self.date_format.clone())
+                .write_tables(use_key_header, tables),
+            OutputType::Tsv => TsvWriter::from_writer(output)
+                .date_time_representation(self.date_format.clone())
+                .write_tables(use_key_header, tables),
+            OutputType::Sql => self
+                .build_sql_writer(output)
+                .write_tables(use_key_header, tables),
+        }
+    }
+
+    /// helper to build writer as sql with the options
+    fn build_sql_writer<O: io::Write>(&self, output: O) -> SqlWriter<O> {
+        SqlWriter::from_writer(output)
+            .batch_size(self.batch_size)
+            .dialect(self.dialect.into())
+            .date_time_representation(self.date_format.clone())
+    }
+
+    /// helper to write all generated value's data
+    fn write_all_data<O, Writer>(
+        &self,
+        mut writer: Writer,
+        schema: &Schema<SeededRandomizer>,
+        values_sets: GeneratedValuesIter<'_>,
     ) -> SchemaResult<()>
     where
         O: io::Write,
         Writer: GeneratedValueWriterBase<O>,
-        R: Randomizer + ?Sized,
     {
-        let mut writer = Writer::from_writer(output);
         writer
-            .write_with_generate(!self.no_header, schema, rng, self.count)
+            .write_values_sets(!self.no_header, schema.get_keys(), values_sets)
             .map_err(|e| match writer.flush() {
                 Ok(()) => e,
                 Err(flush_error) => flush_error,
@@ -1,11 +1,10 @@
 #![deny(missing_debug_implementations)]
 //! Module for errors used in this crate
 
-use crate::builder::ValueBound;
+use crate::builder::{Nullable, ValueBound};
 use crate::eval::EvalError;
 use crate::value::{DataValue, DataValueMap};
 use crate::GeneratorType;
-use std::borrow::Borrow;
 
 /// A Error for a Schema
 #[derive(Debug)]
@@ -40,7 +39,7 @@ impl SchemaError {
 
     /// Get error's information
     pub fn get_error_info(&self) -> &dyn std::error::Error {
-        self.info.0.borrow()
+        self.info.0.as_ref()
     }
 }
 
@@ -59,7 +58,7 @@ pub enum SchemaErrorKind {
 
 /// Error information
 #[derive(Debug)]
-struct SchemaErrorInfo(Box<dyn std::error::Error>);
+struct SchemaErrorInfo(Box<dyn std::error::Error + Send + Sync>);
 
 impl std::fmt::Display for SchemaErrorInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -76,7 +75,7 @@ impl From<BuildError> for SchemaError {
 /// Trait for convert to [`SchemaError`] from other error
 ///
 /// [`SchemaError`]: ./struct.SchemaError.html
-pub trait IntoSbrdError: 'static + std::error::Error + Sized {
+pub trait IntoSbrdError: 'static + std::error::Error + Send + Sync + Sized {
     /// Converter function to [`SchemaError`] from other error
     ///
     /// [`SchemaError`]: ./struct.SchemaError.html
@@ -88,7 +87,7 @@ pub trait IntoSbrdError: 'static + std::error::Error + Sized {
     }
 }
 
-impl<E> IntoSbrdError for E where E: 'static + std::error::Error + Sized {}
+impl<E> IntoSbrdError for E where E: 'static + std::error::Error + Send + Sync + Sized {}
 
 /// Alias of [`Result`] type
 ///
@@ -143,6 +142,13 @@ pub enum BuildError {
     /// * 2: Parse error message
     FailParseValue(String, String, String),
 
+    /// Specified `pattern` in the Schema is not valid or not supported regular expression.
+    ///
+    /// # Arguments
+    /// * 0: Specified `pattern`
+    /// * 1: Error message
+    InvalidPattern(String, String),
+
     /// Specified `range` in the Schema is empty range.
     ///
     /// # Arguments
@@ -177,6 +183,58 @@ pub enum BuildError {
     /// * 0: Name of the distribution
     /// * 1: Error information
     FailBuildDistribution(String, String),
+
+    /// Specified probability at the key `nullable` in the Schema is not within 0.0 and 1.0
+    ///
+    /// # Arguments
+    /// * 0: Specified `nullable`
+    InvalidNullProbability(Nullable),
+
+    /// Specified `name` of a table in the Schema already exist.
+    ///
+    /// # Arguments
+    /// * 0: Specified `name`
+    AlreadyExistTable(String),
+
+    /// Specified `table` as a referenced table does not exist in the Schema.
+    ///
+    /// # Arguments
+    /// * 0: Specified `table`
+    /// * 1: Names of tables
+    NotExistTable(String, Vec<String>),
+
+    /// The tables in the Schema reference each other.
+    ///
+    /// # Arguments
+    /// * 0: Names of the tables which cannot be ordered
+    CyclicTableReference(Vec<String>),
+
+    /// The generators in the Schema reference each other by the scripts and the formats.
+    ///
+    /// # Arguments
+    /// * 0: Keys of the generators in the cycle from a key back to the same key
+    CyclicKeyReference(Vec<String>),
+
+    /// Specified `$ref` in the Schema does not reference an existing value.
+    ///
+    /// # Arguments
+    /// * 0: Specified `$ref`
+    NotExistReference(String),
+
+    /// Specified `$ref` in the Schema references itself through the other references.
+    ///
+    /// # Arguments
+    /// * 0: References in the cycle from a reference back to the same reference
+    CyclicSchemaReference(Vec<String>),
+
+    /// Specified keys at the key `unique_keys` in the Schema is empty.
+    EmptyUniqueKeys,
+
+    /// Increment-id generator is specified as a child generator except of a not nullable object generator, where the id at the index of the values set is not determined.
+    ///
+    /// # Arguments
+    /// * 0: Type of the parent generator
+    IncrementIdInChildren(GeneratorType),
 }
 
 impl std::fmt::Display for BuildError {
@@ -195,6 +253,9 @@ impl std::fmt::Display for BuildError {
             BuildError::FailParseValue(s, t, e) => {
                 write!(f, "Fail Parse {} as {} with error: {}", s, t, e)
             }
+            BuildError::InvalidPattern(pattern, e) => {
+                write!(f, "Invalid pattern \"{}\" with error: {}", pattern, e)
+            }
             BuildError::RangeEmpty(range) => write!(f, "Empty Range: {}", range),
             BuildError::EmptySelectableChildren => write!(f, "Selectable children is empty"),
             BuildError::EmptySelectValues => write!(f, "Selectable values is empty"),
@@ -212,6 +273,31 @@ impl std::fmt::Display for BuildError {
             BuildError::FailBuildDistribution(dn, e) => {
                 write!(f, "Fail build {} distribution with error: {}", dn, e)
             }
+            BuildError::InvalidNullProbability(n) => {
+                write!(f, "Invalid probability of null: {}", n)
+            }
+            BuildError::AlreadyExistTable(t) => write!(f, "Already Exist Table: {}", t),
+            BuildError::NotExistTable(t, tables) => {
+                write!(f, "Not Exist Table \"{}\" in {:?}", t, tables)
+            }
+            BuildError::CyclicTableReference(tables) => {
+                write!(f, "Cyclic reference among the tables: {:?}", tables)
+            }
+            BuildError::CyclicKeyReference(keys) => {
+                write!(f, "Cyclic reference among the keys: {}", keys.join(" -> "))
+            }
+            BuildError::NotExistReference(reference) => {
+                write!(f, "Not Exist Reference: {}", reference)
+            }
+            BuildError::CyclicSchemaReference(references) => write!(
+                f,
+                "Cyclic reference among the definitions: {}",
+                references.join(" -> ")
+            ),
+            BuildError::EmptyUniqueKeys => write!(f, "Unique keys is empty"),
+            BuildError::IncrementIdInChildren(t) => {
+                write!(f, "Increment-id is not available as a child of {}", t)
+            }
         }
     }
 }
@@ -241,6 +327,19 @@ pub enum GenerateError {
     /// * 0: Key
     /// * 1: Generated values
     NotExistGeneratedKey(String, DataValueMap<String>),
+
+    /// Fail generate the unique values at the keys within the count of retrying
+    ///
+    /// # Arguments
+    /// * 0: Keys which must be unique
+    /// * 1: Count of retrying
+    NotUniqueValues(Vec<String>, u64),
+
+    /// Range evaluated with the context while generating is empty
+    ///
+    /// # Arguments
+    /// * 0: Evaluated range
+    RangeEmpty(ValueBound<DataValue>),
 }
 
 impl std::fmt::Display for GenerateError {
@@ -259,8 +358,50 @@ impl std::fmt::Display for GenerateError {
             GenerateError::NotExistGeneratedKey(key, values) => {
                 write!(f, "Not exist key \"{}\" in {:?}", key, values)
             }
+            GenerateError::NotUniqueValues(keys, retry_count) => write!(
+                f,
+                "Fail generate unique values at the keys {:?} within {} retries",
+                keys, retry_count
+            ),
+            GenerateError::RangeEmpty(range) => write!(f, "Empty Range: {}", range),
         }
     }
 }
 
 impl std::error::Error for GenerateError {}
+
+/// Error for writer
+#[derive(Debug, PartialEq)]
+pub enum OutputError {
+    /// The generated value is not supported by the output format
+    ///
+    /// # Arguments
+    /// * 0: Name of the output format
+    /// * 1: Key of the value
+    /// * 2: Generated value
+    NotSupportedValue(String, String, DataValue),
+
+    /// Fail convert the generated value into the representation of the date-time, date and time
+    ///
+    /// # Arguments
+    /// * 0: Generated value
+    /// * 1: Error message
+    FailRepresentValue(DataValue, String),
+}
+
+impl std::fmt::Display for OutputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputError::NotSupportedValue(output, key, value) => write!(
+                f,
+                "Not support to write the value {} at the key \"{}\" as {}",
+                value, key, output
+            ),
+            OutputError::FailRepresentValue(value, e) => {
+                write!(f, "Fail represent the value {} with error: {}", value, e)
+            }
+        }
+    }
+}
+
+impl std::error::Error for OutputError {}
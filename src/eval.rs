@@ -1,23 +1,67 @@
 #![deny(missing_debug_implementations)]
 //! Module for evaluator for `script` and `format`
 
-use crate::value::{DataValueMap, SbrdBool, SbrdInt, SbrdReal, SbrdString};
+use crate::generator::Randomizer;
+use crate::value::{
+    DataValue, DataValueMap, SbrdBool, SbrdDate, SbrdDateTime, SbrdInt, SbrdReal, SbrdString,
+    SbrdTime, DATE_DEFAULT_FORMAT, DATE_TIME_DEFAULT_FORMAT, TIME_DEFAULT_FORMAT,
+};
+use chrono::{Datelike, Duration};
 use evalexpr::{
     eval_boolean_with_context, eval_int_with_context, eval_number_with_context,
-    eval_string_with_context, EvalexprError, HashMapContext,
+    eval_string_with_context, Context, EvalexprError, EvalexprResult, HashMapContext, Value,
 };
 use human_string_filler::StrExt;
+use rand::{Rng, RngCore};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt::Write;
+use std::sync::LazyLock;
 
 /// Evaluator for `script` and `format`.
 /// Script and format is processed by replacing a replace-key-syntax for the key with value based on each entry `(key, value)` of context.
 /// Replace-key-syntax is "{key}" and "{key:\<format-option\>}". It specified by Rust format syntax with the key as name position. But not support index position, variable, padding with character and [`Pointer`] format (`{:p}`).
 /// [`Debug`] format is not supported in release build.
+/// The date-time, date and time are replaced with their format in the format, and with the default format in the script.
 /// If you want to know, you will see [`Rust-format syntax`] and [`DataValue::format`].
 ///
 /// All values, variables and functions are available as described in the [`evalexpr`] except the regex functions.
 /// If you'll know syntax and available them more, you can see [`this document`].
 ///
+/// In addition, the following functions are available. A function with the same name as the builtin function overrides it.
+///
+/// | Function | Description |
+/// |---|---|
+/// | `rand_int(a, b)` | Random integer between `a` and `b`, both inclusive |
+/// | `rand_real()`, `rand_real(a, b)` | Random real number between 0.0 (or `a`) inclusive and 1.0 (or `b`) exclusive |
+/// | `choice(v1, v2, ...)` | One of the values picked out at random. A tuple as the only argument is treated as the values |
+/// | `upper(s)`, `lower(s)` | The string converted to uppercase or lowercase |
+/// | `substr(s, start)`, `substr(s, start, len)` | The substring from the `start`-th character with `len` characters at most |
+/// | `len(s)` | The count of the characters in the string or the count of the values in the tuple |
+/// | `pad_left(s, width)`, `pad_left(s, width, c)` | The string padded on the left with the character `c` (default is a space) to `width` characters |
+/// | `replace(s, from, to)` | The string with all `from` replaced by `to` |
+/// | `round(x)`, `round(x, n)` | The number rounded to `n` (default is 0) decimal places |
+/// | `clamp(x, min, max)` | The number restricted between `min` and `max` |
+/// | `abs(x)` | The absolute value of the number |
+/// | `min(x1, x2, ...)`, `max(x1, x2, ...)` | The minimum or maximum of the numbers. These are the builtin functions of the [`evalexpr`] |
+/// | `get(tuple, i)` | The value at the index `i` in the tuple |
+/// | `date_add(d, n, unit)` | The date, date-time or time moved by `n` units |
+/// | `date_diff(d1, d2, unit)` | The count of the whole units from `d2` to `d1` |
+/// | `weekday(d)` | The day of the week of the date or date-time from 1 (Monday) to 7 (Sunday) |
+/// | `to_timestamp(d)` | The Unix timestamp in seconds of the date or date-time |
+/// | `from_timestamp(n)` | The date-time at the Unix timestamp in seconds |
+/// | `format_date(d, format)` | The date, date-time or time formatted with the format |
+/// | `parse_date(s, format)` | The date, date-time or time parsed from the string with the format |
+///
+/// The random functions are available only if the evaluator has the randomizer by [`Evaluator::with_randomizer`].
+///
+/// The date functions take and return a date, a date-time and a time as a string in the formats [`DATE_DEFAULT_FORMAT`], [`DATE_TIME_DEFAULT_FORMAT`] and [`TIME_DEFAULT_FORMAT`], that are the default formats of the date, date-time and time generators.
+/// The `unit` is one of `"year"`, `"month"`, `"week"`, `"day"`, `"hour"`, `"minute"` and `"second"`, and the plural form is also available.
+/// Moving by months and years keeps the day of the month if possible, otherwise uses the last day of the month.
+/// A date can not move by the units less than a day, and a time can not move by the units more than an hour.
+/// The `format` is the same syntax as the `format` option of the date, date-time and time generators.
+///
 /// # Examples
 /// ```
 /// fn main(){
@@ -40,6 +84,59 @@ use std::fmt::Write;
 ///     assert_eq!(Ok(" aiueoあいうえお ".to_string()), evaluator.format_script("{Key:String:^12}"));
 ///     assert_eq!(Ok("true    ".to_string()), evaluator.format_script("{Key Bool::<8}"));
 ///     assert_eq!(Ok("null".to_string()), evaluator.format_script("{key Null :<10}"));
+///
+///     assert_eq!(Ok("AIUEO".to_string()), evaluator.eval_string("upper(substr(\"{Key:String}\", 0, 5))"));
+///     assert_eq!(Ok("0012".to_string()), evaluator.eval_string("pad_left(str::from({Key-Int}), 4, \"0\")"));
+///     assert_eq!(Ok(12.35), evaluator.eval_real("round({キー Real}, 2)"));
+///     assert_eq!(Ok(10), evaluator.eval_int("clamp({Key-Int}, 0, 10)"));
+///     assert_eq!(Ok(3), evaluator.eval_int("min({Key-Int}, 3, 5)"));
+///     assert_eq!(Ok(2), evaluator.eval_int("get((1, 2, 3), 1)"));
+/// }
+/// ```
+///
+/// The random functions draw from the randomizer.
+/// ```
+/// fn main(){
+///     use sbrd_gen::eval::Evaluator;
+///     use sbrd_gen::generator::build_seeded_randomizer;
+///     use sbrd_gen::value::DataValueMap;
+///     use rand::Rng;
+///
+///     let value_context = DataValueMap::new();
+///     let mut rng = build_seeded_randomizer(42);
+///     let evaluator = Evaluator::new(&value_context).with_randomizer(&mut rng);
+///
+///     let dice = evaluator.eval_int("rand_int(1, 6)").unwrap();
+///     assert!((1..=6).contains(&dice));
+///     let picked = evaluator.eval_string("choice(\"a\", \"b\", \"c\")").unwrap();
+///     assert!(["a", "b", "c"].contains(&picked.as_str()));
+///     assert!(Evaluator::new(&value_context).eval_int("rand_int(1, 6)").is_err());
+///
+///     // The randomizer is not advanced without the random functions.
+///     let mut rng = build_seeded_randomizer(42);
+///     assert_eq!(Ok(3), Evaluator::new(&value_context).with_randomizer(&mut rng).eval_int("1 + 2"));
+///     assert_eq!(build_seeded_randomizer(42).gen::<u64>(), rng.gen::<u64>());
+/// }
+/// ```
+///
+/// The date functions derive a date-time from another date-time.
+/// ```
+/// fn main(){
+///     use sbrd_gen::eval::Evaluator;
+///     use sbrd_gen::value::{DataValue, DataValueMap};
+///
+///     let mut value_context = DataValueMap::new();
+///     value_context.insert("ordered_at", DataValue::String("2022-01-30 12:00:00".to_string()));
+///     let evaluator = Evaluator::new(&value_context);
+///
+///     assert_eq!(Ok("2022-02-02 12:00:00".to_string()), evaluator.eval_string("date_add(\"{ordered_at}\", 3, \"day\")"));
+///     assert_eq!(Ok("2022-02-28".to_string()), evaluator.eval_string("date_add(\"2022-01-30\", 1, \"month\")"));
+///     assert_eq!(Ok(-36), evaluator.eval_int("date_diff(\"2022-01-29\", \"{ordered_at}\", \"hours\")"));
+///     assert_eq!(Ok(7), evaluator.eval_int("weekday(\"{ordered_at}\")"));
+///     assert_eq!(Ok(1643544000), evaluator.eval_int("to_timestamp(\"{ordered_at}\")"));
+///     assert_eq!(Ok("2022-01-30 12:00:00".to_string()), evaluator.eval_string("from_timestamp(1643544000)"));
+///     assert_eq!(Ok("2022/01/30".to_string()), evaluator.eval_string("format_date(\"{ordered_at}\", \"%Y/%m/%d\")"));
+///     assert_eq!(Ok("12:34:00".to_string()), evaluator.eval_string("parse_date(\"12h34m\", \"%Hh%Mm\")"));
 /// }
 /// ```
 ///
@@ -49,9 +146,22 @@ use std::fmt::Write;
 /// [`DataValue::format`]: ../value/enum.DataValue.html#method.format
 /// [`evalexpr`]: https://crates.io/crates/evalexpr/7.0.1
 /// [`this document`]: https://docs.rs/evalexpr/7.0.1/evalexpr/index.html#features
-#[derive(Debug, PartialEq, Clone)]
+/// [`Evaluator::with_randomizer`]: ./struct.Evaluator.html#method.with_randomizer
+/// [`DATE_DEFAULT_FORMAT`]: ../value/constant.DATE_DEFAULT_FORMAT.html
+/// [`DATE_TIME_DEFAULT_FORMAT`]: ../value/constant.DATE_TIME_DEFAULT_FORMAT.html
+/// [`TIME_DEFAULT_FORMAT`]: ../value/constant.TIME_DEFAULT_FORMAT.html
 pub struct Evaluator<'a> {
     value_context: &'a DataValueMap<&'a str>,
+    rng: Option<RefCell<Box<dyn RngCore + 'a>>>,
+}
+
+impl std::fmt::Debug for Evaluator<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Evaluator")
+            .field("value_context", &self.value_context)
+            .field("has_randomizer", &self.rng.is_some())
+            .finish()
+    }
 }
 
 /// Context for evaluator
@@ -87,51 +197,55 @@ pub type EvalResult<T> = Result<T, EvalError>;
 impl<'a> Evaluator<'a> {
     /// Create from script and a value context
     pub fn new(value_context: &'a DataValueMap<&str>) -> Self {
-        Self { value_context }
+        Self {
+            value_context,
+            rng: None,
+        }
     }
 
-    /// Create context when use evaluate
-    fn create_eval_context() -> EvalResult<EvalContext> {
-        #[allow(unused_mut)]
-        let mut context = EvalContext::new();
-
-        // @todo If you need impl custom function, do impl. e.g. function for get value at the index
-        /*
-        context
-            .set_function(
-                "get".to_string(),
-                Function::new(move |argument| {
-                    let arg_tuple = argument.as_fixed_len_tuple(2)?;
-                    let (values, index) = (arg_tuple[0].as_tuple()?, arg_tuple[1].as_int()?);
-
-                    if index < 0 {
-                        return Err(EvalexprError::CustomMessage(
-                            "Invalid index in a script.".to_string(),
-                        ));
-                    }
-                    match values.get(index as usize) {
-                        None => Err(EvalexprError::CustomMessage(format!(
-                            "Not found value in {} at tuple index {}",
-                            argument, index
-                        ))),
-                        Some(value) => Ok(value.clone()),
-                    }
-                }),
-            )
-            .map_err(EvalError::FailEval)?;
-         */
+    /// Enable the random functions drawing from the randomizer.
+    ///
+    /// The random functions draw from the specified randomizer directly, so the evaluated value is reproducible if the specified randomizer is seeded.
+    /// The randomizer is not advanced by a script without the random functions.
+    pub fn with_randomizer<R: Randomizer + ?Sized>(mut self, rng: &'a mut R) -> Self {
+        self.rng = Some(RefCell::new(Box::new(rng)));
+        self
+    }
 
-        Ok(context)
+    /// Create context when use evaluate
+    fn create_eval_context(&self) -> FunctionContext<'_, 'a> {
+        FunctionContext {
+            rng: self.rng.as_ref(),
+        }
     }
 
-    /// Apply value-context to the script
-    fn apply_value_context(&self, script: &str) -> EvalResult<String> {
+    /// Apply value-context to the script.
+    /// If use the value's format, the date-time, date and time are formatted with their format, otherwise with the default format.
+    pub(crate) fn apply_value_context(
+        &self,
+        script: &str,
+        use_value_format: bool,
+    ) -> EvalResult<String> {
+        let interpolated = |v: &'a DataValue| -> Cow<'a, DataValue> {
+            match v {
+                DataValue::DateTime(..)
+                | DataValue::ZonedDateTime(..)
+                | DataValue::Date(..)
+                | DataValue::Time(..)
+                    if use_value_format =>
+                {
+                    Cow::Owned(DataValue::String(v.to_permutation_string()))
+                }
+                _ => Cow::Borrowed(v),
+            }
+        };
+
         let mut result = String::new();
         script
             .fill_into::<_, _, String>(&mut result, |output: &mut String, key: &str| {
                 match self.value_context.get(key) {
                     Some(v) => {
-                        let formatted = v
+                        let formatted = interpolated(v)
                             .format("{}")
                             .ok_or_else(|| format!("Fail apply key \"{}\".", key))?;
 
@@ -144,7 +258,7 @@ impl<'a> Evaluator<'a> {
                         let _key = &key[0..split_index];
                         match self.value_context.get(_key) {
                             Some(v) => {
-                                let formatted = v
+                                let formatted = interpolated(v)
                                     .format(&format!("{{{}}}", &key[split_index..key.len()]))
                                     .ok_or_else(|| format!("Fail apply key \"{}\".", _key))?;
 
@@ -166,46 +280,659 @@ impl<'a> Evaluator<'a> {
     }
 
     /// Get format applied value-context to the script.
+    /// The date-time, date and time are formatted with their format.
     ///
     /// If you want to know syntax, you will see [`Evaluator`]'s document.
     ///
     /// [`Evaluator`]: ./struct.Evaluator.html
     pub fn format_script(&self, script: &str) -> EvalResult<String> {
-        self.apply_value_context(script)
+        self.apply_value_context(script, true)
     }
 
     /// Evaluate the script applied the context, as [`SbrdInt`]
     ///
     /// [`SbrdInt`]: ../value/type.SbrdInt.html
     pub fn eval_int(&self, script: &str) -> EvalResult<SbrdInt> {
-        eval_int_with_context(&self.format_script(script)?, &Self::create_eval_context()?)
-            .map(|v| v as SbrdInt)
-            .map_err(EvalError::FailEval)
+        eval_int_with_context(
+            &self.apply_value_context(script, false)?,
+            &self.create_eval_context(),
+        )
+        .map(|v| v as SbrdInt)
+        .map_err(EvalError::FailEval)
     }
 
     /// Evaluate the script applied the context, as [`SbrdReal`]
     ///
     /// [`SbrdReal`]: ../value/type.SbrdReal.html
     pub fn eval_real(&self, script: &str) -> EvalResult<SbrdReal> {
-        eval_number_with_context(&self.format_script(script)?, &Self::create_eval_context()?)
-            .map(|v| v as SbrdReal)
-            .map_err(EvalError::FailEval)
+        eval_number_with_context(
+            &self.apply_value_context(script, false)?,
+            &self.create_eval_context(),
+        )
+        .map(|v| v as SbrdReal)
+        .map_err(EvalError::FailEval)
     }
 
     /// Evaluate the script applied the context, as [`SbrdBool`]
     ///
     /// [`SbrdBool`]: ../value/type.SbrdBool.html
     pub fn eval_bool(&self, script: &str) -> EvalResult<SbrdBool> {
-        eval_boolean_with_context(&self.format_script(script)?, &Self::create_eval_context()?)
-            .map(|v| v as SbrdBool)
-            .map_err(EvalError::FailEval)
+        eval_boolean_with_context(
+            &self.apply_value_context(script, false)?,
+            &self.create_eval_context(),
+        )
+        .map(|v| v as SbrdBool)
+        .map_err(EvalError::FailEval)
     }
 
     /// Evaluate the script applied the context, as [`SbrdString`]
     ///
     /// [`SbrdString`]: ../value/type.SbrdString.html
     pub fn eval_string(&self, script: &str) -> EvalResult<SbrdString> {
-        eval_string_with_context(&self.format_script(script)?, &Self::create_eval_context()?)
-            .map_err(EvalError::FailEval)
+        eval_string_with_context(
+            &self.apply_value_context(script, false)?,
+            &self.create_eval_context(),
+        )
+        .map_err(EvalError::FailEval)
+    }
+
+    /// Evaluate the script applied the context, as [`SbrdDateTime`] parsed with [`DATE_TIME_DEFAULT_FORMAT`]
+    ///
+    /// [`SbrdDateTime`]: ../value/type.SbrdDateTime.html
+    /// [`DATE_TIME_DEFAULT_FORMAT`]: ../value/constant.DATE_TIME_DEFAULT_FORMAT.html
+    pub fn eval_date_time(&self, script: &str) -> EvalResult<SbrdDateTime> {
+        let evaluated = self.eval_string(script)?;
+        SbrdDateTime::parse_from_str(&evaluated, DATE_TIME_DEFAULT_FORMAT)
+            .map_err(|e| EvalError::FailEval(parse_date_error(&evaluated, e)))
+    }
+
+    /// Evaluate the script applied the context, as [`SbrdDate`] parsed with [`DATE_DEFAULT_FORMAT`]
+    ///
+    /// [`SbrdDate`]: ../value/type.SbrdDate.html
+    /// [`DATE_DEFAULT_FORMAT`]: ../value/constant.DATE_DEFAULT_FORMAT.html
+    pub fn eval_date(&self, script: &str) -> EvalResult<SbrdDate> {
+        let evaluated = self.eval_string(script)?;
+        SbrdDate::parse_from_str(&evaluated, DATE_DEFAULT_FORMAT)
+            .map_err(|e| EvalError::FailEval(parse_date_error(&evaluated, e)))
+    }
+
+    /// Evaluate the script applied the context, as [`SbrdTime`] parsed with [`TIME_DEFAULT_FORMAT`]
+    ///
+    /// [`SbrdTime`]: ../value/type.SbrdTime.html
+    /// [`TIME_DEFAULT_FORMAT`]: ../value/constant.TIME_DEFAULT_FORMAT.html
+    pub fn eval_time(&self, script: &str) -> EvalResult<SbrdTime> {
+        let evaluated = self.eval_string(script)?;
+        SbrdTime::parse_from_str(&evaluated, TIME_DEFAULT_FORMAT)
+            .map_err(|e| EvalError::FailEval(parse_date_error(&evaluated, e)))
+    }
+}
+
+/// Context calling the functions of the evaluator
+struct FunctionContext<'e, 'a> {
+    rng: Option<&'e RefCell<Box<dyn RngCore + 'a>>>,
+}
+
+impl Context for FunctionContext<'_, '_> {
+    fn get_value(&self, _identifier: &str) -> Option<&Value> {
+        None
+    }
+
+    fn call_function(&self, identifier: &str, argument: &Value) -> EvalexprResult<Value> {
+        match FUNCTIONS.get(identifier) {
+            None => Err(EvalexprError::FunctionIdentifierNotFound(
+                identifier.to_string(),
+            )),
+            Some(EvalFunction::Pure(function)) => function(argument),
+            Some(EvalFunction::Random(function)) => {
+                let rng = self.rng.ok_or_else(|| {
+                    custom_error(
+                        "Random functions are not available without a randomizer".to_string(),
+                    )
+                })?;
+                let mut rng = rng
+                    .try_borrow_mut()
+                    .map_err(|e| custom_error(format!("Fail borrow the randomizer: {}", e)))?;
+                function(rng.as_mut(), argument)
+            }
+        }
+    }
+}
+
+/// Function for the evaluator
+#[derive(Clone, Copy)]
+enum EvalFunction {
+    /// Function without the randomizer
+    Pure(fn(&Value) -> EvalexprResult<Value>),
+    /// Function drawing from the randomizer
+    Random(fn(&mut dyn RngCore, &Value) -> EvalexprResult<Value>),
+}
+
+/// The functions registered to the evaluator, built once
+static FUNCTIONS: LazyLock<HashMap<&'static str, EvalFunction>> =
+    LazyLock::new(|| functions().into_iter().collect());
+
+/// Create the functions registered to the evaluator
+fn functions() -> Vec<(&'static str, EvalFunction)> {
+    vec![
+        // random
+        (
+            "rand_int",
+            EvalFunction::Random(|rng, argument| {
+                let args = argument.as_fixed_len_tuple(2)?;
+                let (start, end) = (args[0].as_int()?, args[1].as_int()?);
+                if start > end {
+                    return Err(custom_error(format!(
+                        "rand_int expects the start {} is not greater than the end {}",
+                        start, end
+                    )));
+                }
+
+                Ok(Value::Int(rng.gen_range(start..=end)))
+            }),
+        ),
+        (
+            "rand_real",
+            EvalFunction::Random(|rng, argument| {
+                let (start, end) = match to_arguments(argument).as_slice() {
+                    [] => (0.0, 1.0),
+                    [start, end] => (start.as_number()?, end.as_number()?),
+                    args => return Err(wrong_argument_amount(2, args.len())),
+                };
+                if start >= end || !(end - start).is_finite() {
+                    return Err(custom_error(format!(
+                        "rand_real expects the finite range from the start {} to the end {}",
+                        start, end
+                    )));
+                }
+
+                Ok(Value::Float(rng.gen_range(start..end)))
+            }),
+        ),
+        (
+            "choice",
+            EvalFunction::Random(|rng, argument| {
+                let args = to_arguments(argument);
+                if args.is_empty() {
+                    return Err(custom_error(
+                        "choice expects one or more values".to_string(),
+                    ));
+                }
+
+                Ok(args[rng.gen_range(0..args.len())].clone())
+            }),
+        ),
+        // string
+        (
+            "upper",
+            EvalFunction::Pure(|argument| Ok(Value::String(argument.as_string()?.to_uppercase()))),
+        ),
+        (
+            "lower",
+            EvalFunction::Pure(|argument| Ok(Value::String(argument.as_string()?.to_lowercase()))),
+        ),
+        (
+            "substr",
+            EvalFunction::Pure(|argument| {
+                let (s, start, len) = match to_arguments(argument).as_slice() {
+                    [s, start] => (s.as_string()?, start.as_int()?, None),
+                    [s, start, len] => (s.as_string()?, start.as_int()?, Some(len.as_int()?)),
+                    args => return Err(wrong_argument_amount(3, args.len())),
+                };
+                let start = to_index(start)?;
+                let chars = s.chars().skip(start);
+                let substring: String = match len {
+                    None => chars.collect(),
+                    Some(len) => chars.take(to_index(len)?).collect(),
+                };
+
+                Ok(Value::String(substring))
+            }),
+        ),
+        (
+            "len",
+            EvalFunction::Pure(|argument| match argument {
+                Value::String(s) => Ok(Value::Int(s.chars().count() as i64)),
+                Value::Tuple(values) => Ok(Value::Int(values.len() as i64)),
+                _ => Err(EvalexprError::expected_string(argument.clone())),
+            }),
+        ),
+        (
+            "pad_left",
+            EvalFunction::Pure(|argument| {
+                let (s, width, pad) = match to_arguments(argument).as_slice() {
+                    [s, width] => (s.as_string()?, width.as_int()?, ' '),
+                    [s, width, pad] => {
+                        let pad = pad.as_string()?;
+                        let mut chars = pad.chars();
+                        match (chars.next(), chars.next()) {
+                            (Some(c), None) => (s.as_string()?, width.as_int()?, c),
+                            _ => {
+                                return Err(custom_error(format!(
+                                    "pad_left expects a character for padding, actual \"{}\"",
+                                    pad
+                                )))
+                            }
+                        }
+                    }
+                    args => return Err(wrong_argument_amount(3, args.len())),
+                };
+                let padding = to_index(width)?.saturating_sub(s.chars().count());
+
+                Ok(Value::String(
+                    std::iter::repeat_n(pad, padding).chain(s.chars()).collect(),
+                ))
+            }),
+        ),
+        (
+            "replace",
+            EvalFunction::Pure(|argument| {
+                let args = argument.as_fixed_len_tuple(3)?;
+                let (s, from, to) = (
+                    args[0].as_string()?,
+                    args[1].as_string()?,
+                    args[2].as_string()?,
+                );
+
+                Ok(Value::String(s.replace(&from, &to)))
+            }),
+        ),
+        // math
+        (
+            "round",
+            EvalFunction::Pure(|argument| {
+                let (x, digits) = match to_arguments(argument).as_slice() {
+                    [x] => (x.as_number()?, 0),
+                    [x, digits] => (x.as_number()?, digits.as_int()?),
+                    args => return Err(wrong_argument_amount(2, args.len())),
+                };
+                let scale = 10f64.powi(digits.clamp(i32::MIN as i64, i32::MAX as i64) as i32);
+
+                Ok(Value::Float((x * scale).round() / scale))
+            }),
+        ),
+        (
+            "clamp",
+            EvalFunction::Pure(|argument| {
+                let args = argument.as_fixed_len_tuple(3)?;
+                match (&args[0], &args[1], &args[2]) {
+                    (Value::Int(x), Value::Int(min), Value::Int(max)) => {
+                        if min > max {
+                            return Err(custom_error(format!(
+                                "clamp expects the min {} is not greater than the max {}",
+                                min, max
+                            )));
+                        }
+
+                        Ok(Value::Int(*x.clamp(min, max)))
+                    }
+                    (x, min, max) => {
+                        let (x, min, max) = (x.as_number()?, min.as_number()?, max.as_number()?);
+                        if min > max || min.is_nan() || max.is_nan() {
+                            return Err(custom_error(format!(
+                                "clamp expects the min {} is not greater than the max {}",
+                                min, max
+                            )));
+                        }
+
+                        Ok(Value::Float(x.clamp(min, max)))
+                    }
+                }
+            }),
+        ),
+        (
+            "abs",
+            EvalFunction::Pure(|argument| match argument {
+                Value::Int(x) => x
+                    .checked_abs()
+                    .map(Value::Int)
+                    .ok_or_else(|| custom_error(format!("abs overflows for {}", x))),
+                _ => Ok(Value::Float(argument.as_number()?.abs())),
+            }),
+        ),
+        // tuple
+        (
+            "get",
+            EvalFunction::Pure(|argument| {
+                let args = argument.as_fixed_len_tuple(2)?;
+                let (values, index) = (args[0].as_tuple()?, to_index(args[1].as_int()?)?);
+
+                values.get(index).cloned().ok_or_else(|| {
+                    custom_error(format!(
+                        "Not found value in {} at tuple index {}",
+                        argument, index
+                    ))
+                })
+            }),
+        ),
+        // date
+        (
+            "date_add",
+            EvalFunction::Pure(|argument| {
+                let args = argument.as_fixed_len_tuple(3)?;
+                let value = DateValue::parse(&args[0].as_string()?)?;
+                let amount = args[1].as_int()?;
+                let unit = DateUnit::parse(&args[2].as_string()?)?;
+
+                Ok(Value::String(value.add(amount, unit)?.to_string()))
+            }),
+        ),
+        (
+            "date_diff",
+            EvalFunction::Pure(|argument| {
+                let args = argument.as_fixed_len_tuple(3)?;
+                let lhs = DateValue::parse(&args[0].as_string()?)?;
+                let rhs = DateValue::parse(&args[1].as_string()?)?;
+                let unit = DateUnit::parse(&args[2].as_string()?)?;
+
+                Ok(Value::Int(lhs.diff(&rhs, unit)?))
+            }),
+        ),
+        (
+            "weekday",
+            EvalFunction::Pure(|argument| {
+                let value = DateValue::parse(&argument.as_string()?)?;
+                let weekday = match value {
+                    DateValue::DateTime(v) => v.weekday(),
+                    DateValue::Date(v) => v.weekday(),
+                    DateValue::Time(_) => {
+                        return Err(custom_error(format!(
+                            "weekday expects a date or a date-time, actual time {}",
+                            value
+                        )))
+                    }
+                };
+
+                Ok(Value::Int(weekday.number_from_monday() as i64))
+            }),
+        ),
+        (
+            "to_timestamp",
+            EvalFunction::Pure(|argument| {
+                let value = DateValue::parse(&argument.as_string()?)?;
+                match value.to_date_time() {
+                    Some(v) => Ok(Value::Int(v.timestamp())),
+                    None => Err(custom_error(format!(
+                        "to_timestamp expects a date or a date-time, actual time {}",
+                        value
+                    ))),
+                }
+            }),
+        ),
+        (
+            "from_timestamp",
+            EvalFunction::Pure(|argument| {
+                let timestamp = argument.as_int()?;
+                SbrdDateTime::from_timestamp_opt(timestamp, 0)
+                    .map(|v| Value::String(DateValue::DateTime(v).to_string()))
+                    .ok_or_else(|| custom_error(format!("Timestamp {} is out of range", timestamp)))
+            }),
+        ),
+        (
+            "format_date",
+            EvalFunction::Pure(|argument| {
+                let args = argument.as_fixed_len_tuple(2)?;
+                let value = DateValue::parse(&args[0].as_string()?)?;
+                let format = args[1].as_string()?;
+
+                let mut formatted = String::new();
+                let result = match value {
+                    DateValue::DateTime(v) => write!(formatted, "{}", v.format(&format)),
+                    DateValue::Date(v) => write!(formatted, "{}", v.format(&format)),
+                    DateValue::Time(v) => write!(formatted, "{}", v.format(&format)),
+                };
+                result.map_err(|_| {
+                    custom_error(format!("Fail format {} with format \"{}\"", value, format))
+                })?;
+
+                Ok(Value::String(formatted))
+            }),
+        ),
+        (
+            "parse_date",
+            EvalFunction::Pure(|argument| {
+                let args = argument.as_fixed_len_tuple(2)?;
+                let (s, format) = (args[0].as_string()?, args[1].as_string()?);
+                let value = SbrdDateTime::parse_from_str(&s, &format)
+                    .map(DateValue::DateTime)
+                    .or_else(|_| SbrdDate::parse_from_str(&s, &format).map(DateValue::Date))
+                    .or_else(|_| SbrdTime::parse_from_str(&s, &format).map(DateValue::Time))
+                    .map_err(|e| {
+                        custom_error(format!(
+                            "Fail parse \"{}\" with format \"{}\": {}",
+                            s, format, e
+                        ))
+                    })?;
+
+                Ok(Value::String(value.to_string()))
+            }),
+        ),
+    ]
+}
+
+/// Value for the date functions
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum DateValue {
+    DateTime(SbrdDateTime),
+    Date(SbrdDate),
+    Time(SbrdTime),
+}
+
+impl std::fmt::Display for DateValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DateValue::DateTime(v) => write!(f, "{}", v.format(DATE_TIME_DEFAULT_FORMAT)),
+            DateValue::Date(v) => write!(f, "{}", v.format(DATE_DEFAULT_FORMAT)),
+            DateValue::Time(v) => write!(f, "{}", v.format(TIME_DEFAULT_FORMAT)),
+        }
+    }
+}
+
+impl DateValue {
+    /// Parse the string with the default formats
+    fn parse(s: &str) -> EvalexprResult<Self> {
+        SbrdDateTime::parse_from_str(s, DATE_TIME_DEFAULT_FORMAT)
+            .map(DateValue::DateTime)
+            .or_else(|_| SbrdDate::parse_from_str(s, DATE_DEFAULT_FORMAT).map(DateValue::Date))
+            .or_else(|_| SbrdTime::parse_from_str(s, TIME_DEFAULT_FORMAT).map(DateValue::Time))
+            .map_err(|e| parse_date_error(s, e))
+    }
+
+    /// Convert to the date-time. A date is converted to the date-time at midnight.
+    fn to_date_time(self) -> Option<SbrdDateTime> {
+        match self {
+            DateValue::DateTime(v) => Some(v),
+            DateValue::Date(v) => Some(v.and_hms(0, 0, 0)),
+            DateValue::Time(_) => None,
+        }
+    }
+
+    /// Move by the amount of the unit
+    fn add(self, amount: i64, unit: DateUnit) -> EvalexprResult<Self> {
+        let overflow = || {
+            custom_error(format!(
+                "{} moved by {} {:?} is out of range",
+                self, amount, unit
+            ))
+        };
+
+        if let Some(months) = unit.months() {
+            let months = amount.checked_mul(months).ok_or_else(overflow)?;
+            return match self {
+                DateValue::DateTime(v) => add_months(v.date(), months)
+                    .map(|date| DateValue::DateTime(date.and_time(v.time()))),
+                DateValue::Date(v) => add_months(v, months).map(DateValue::Date),
+                DateValue::Time(_) => None,
+            }
+            .ok_or_else(|| match self {
+                DateValue::Time(_) => unit_error(&self, unit),
+                _ => overflow(),
+            });
+        }
+
+        let seconds = amount
+            .checked_mul(unit.seconds())
+            .filter(|seconds| seconds.checked_abs().is_some_and(|s| s <= i64::MAX / 1000))
+            .ok_or_else(overflow)?;
+        let duration = Duration::seconds(seconds);
+        match self {
+            DateValue::DateTime(v) => v
+                .checked_add_signed(duration)
+                .map(DateValue::DateTime)
+                .ok_or_else(overflow),
+            DateValue::Date(v) => {
+                if unit < DateUnit::Day {
+                    return Err(unit_error(&self, unit));
+                }
+                v.checked_add_signed(duration)
+                    .map(DateValue::Date)
+                    .ok_or_else(overflow)
+            }
+            DateValue::Time(v) => {
+                if unit > DateUnit::Hour {
+                    return Err(unit_error(&self, unit));
+                }
+                Ok(DateValue::Time(v.overflowing_add_signed(duration).0))
+            }
+        }
     }
+
+    /// Count the whole units from the other to self
+    fn diff(&self, other: &Self, unit: DateUnit) -> EvalexprResult<i64> {
+        let (lhs, rhs) = match (self, other) {
+            (DateValue::Time(lhs), DateValue::Time(rhs)) => {
+                if unit > DateUnit::Hour {
+                    return Err(unit_error(self, unit));
+                }
+                return Ok(lhs.signed_duration_since(*rhs).num_seconds() / unit.seconds());
+            }
+            _ => match (self.to_date_time(), other.to_date_time()) {
+                (Some(lhs), Some(rhs)) => (lhs, rhs),
+                _ => {
+                    return Err(custom_error(format!(
+                        "Can not count the units from {} to {}",
+                        other, self
+                    )))
+                }
+            },
+        };
+
+        match unit.months() {
+            None => Ok(lhs.signed_duration_since(rhs).num_seconds() / unit.seconds()),
+            Some(unit_months) => {
+                let mut months = (lhs.year() as i64 * 12 + lhs.month0() as i64)
+                    - (rhs.year() as i64 * 12 + rhs.month0() as i64);
+                let moved =
+                    |months| add_months(rhs.date(), months).map(|date| date.and_time(rhs.time()));
+                if months > 0 && moved(months).is_some_and(|moved| moved > lhs) {
+                    months -= 1;
+                } else if months < 0 && moved(months).is_some_and(|moved| moved < lhs) {
+                    months += 1;
+                }
+
+                Ok(months / unit_months)
+            }
+        }
+    }
+}
+
+/// Unit for the date functions
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+enum DateUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl DateUnit {
+    /// Parse the name of the unit in the singular or plural form
+    fn parse(s: &str) -> EvalexprResult<Self> {
+        match s.strip_suffix('s').unwrap_or(s) {
+            "second" => Ok(DateUnit::Second),
+            "minute" => Ok(DateUnit::Minute),
+            "hour" => Ok(DateUnit::Hour),
+            "day" => Ok(DateUnit::Day),
+            "week" => Ok(DateUnit::Week),
+            "month" => Ok(DateUnit::Month),
+            "year" => Ok(DateUnit::Year),
+            _ => Err(custom_error(format!("Unknown unit \"{}\"", s))),
+        }
+    }
+
+    /// The seconds of the unit. The unit with months is not available.
+    fn seconds(self) -> i64 {
+        match self {
+            DateUnit::Second => 1,
+            DateUnit::Minute => 60,
+            DateUnit::Hour => 60 * 60,
+            DateUnit::Day => 24 * 60 * 60,
+            DateUnit::Week => 7 * 24 * 60 * 60,
+            DateUnit::Month | DateUnit::Year => unreachable!("month and year are not seconds"),
+        }
+    }
+
+    /// The months of the unit if the unit is month or year
+    fn months(self) -> Option<i64> {
+        match self {
+            DateUnit::Month => Some(1),
+            DateUnit::Year => Some(12),
+            _ => None,
+        }
+    }
+}
+
+/// Move the date by the months. If the day does not exist in the month, then use the last day of the month.
+fn add_months(date: SbrdDate, months: i64) -> Option<SbrdDate> {
+    let total_months = (date.year() as i64 * 12 + date.month0() as i64).checked_add(months)?;
+    let year = i32::try_from(total_months.div_euclid(12)).ok()?;
+    let month = total_months.rem_euclid(12) as u32 + 1;
+
+    (1..=date.day())
+        .rev()
+        .find_map(|day| SbrdDate::from_ymd_opt(year, month, day))
+}
+
+/// Create the error for the unit which is not available for the value
+fn unit_error(value: &DateValue, unit: DateUnit) -> EvalexprError {
+    custom_error(format!("Can not use unit {:?} for {}", unit, value))
+}
+
+/// Create the error for the string which is not parsed as a date, a date-time or a time
+fn parse_date_error(s: &str, e: chrono::ParseError) -> EvalexprError {
+    custom_error(format!(
+        "Fail parse \"{}\" as a date, a date-time or a time: {}",
+        s, e
+    ))
+}
+
+/// Convert the argument to the list of the arguments
+fn to_arguments(argument: &Value) -> Vec<Value> {
+    match argument {
+        Value::Empty => Vec::new(),
+        Value::Tuple(values) => values.clone(),
+        _ => vec![argument.clone()],
+    }
+}
+
+/// Convert the integer to the index
+fn to_index(i: i64) -> EvalexprResult<usize> {
+    usize::try_from(i).map_err(|_| {
+        custom_error(format!(
+            "Expected a not negative integer, actual {} is negative.",
+            i
+        ))
+    })
+}
+
+/// Create the error with the message
+fn custom_error(message: String) -> EvalexprError {
+    EvalexprError::CustomMessage(message)
+}
+
+/// Create the error for the wrong count of the arguments
+fn wrong_argument_amount(expected: usize, actual: usize) -> EvalexprError {
+    EvalexprError::WrongFunctionArgumentAmount { expected, actual }
 }
@@ -18,14 +18,28 @@ where
     env::set_var(SCHEMA_FILE_PATH_KEY, path);
 }
 
-/// The file path specified in the schema is interpreted starting from the file path of the stored schema if it exists, otherwise it is interpreted starting from the path of the command when the command was executed.
-pub(crate) fn open_sbrd_file(filepath: &Path) -> io::Result<File> {
-    match env::var(SCHEMA_FILE_PATH_KEY) {
-        Ok(schema_filepath) => {
-            let mut _filepath = PathBuf::from(schema_filepath);
+/// Get the file path of the stored schema if it exists
+pub(crate) fn get_schema_file_path() -> Option<PathBuf> {
+    env::var(SCHEMA_FILE_PATH_KEY).ok().map(PathBuf::from)
+}
+
+/// Interpret the file path specified in the file at the base path starting from the base path if it exists,
+/// otherwise starting from the path of the command when the command was executed.
+pub(crate) fn resolve_file_path(filepath: &Path, base_filepath: Option<&Path>) -> PathBuf {
+    match base_filepath {
+        Some(base_filepath) => {
+            let mut _filepath = base_filepath.to_path_buf();
             _filepath.set_file_name(filepath);
-            File::open(_filepath)
+            _filepath
         }
-        Err(_) => File::open(filepath),
+        None => filepath.to_path_buf(),
     }
 }
+
+/// The file path specified in the schema is interpreted starting from the file path of the stored schema if it exists, otherwise it is interpreted starting from the path of the command when the command was executed.
+pub(crate) fn open_sbrd_file(filepath: &Path) -> io::Result<File> {
+    File::open(resolve_file_path(
+        filepath,
+        get_schema_file_path().as_deref(),
+    ))
+}
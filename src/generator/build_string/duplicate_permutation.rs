@@ -1,21 +1,25 @@
-use crate::builder::{GeneratorBuilder, ValueBound};
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
 use crate::error::{BuildError, GenerateError};
-use crate::generator::{GeneratorBase, Randomizer, ValueChildGeneratorBase, ValueOrChild};
+use crate::generator::scriptable_bound::ScriptableBound;
+use crate::generator::{GeneratorBase, Randomizer, ValueChildGeneratorBase, WeightedValueOrChild};
 use crate::value::{DataValue, DataValueMap, SbrdInt};
 use crate::GeneratorType;
+use either::Either;
 
 /// The generator with permuting [`DataValue::String`] joined by separator
 ///
+/// The bound of the range of the count can be a script such as "{key}" evaluated while generating.
+///
 /// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
 pub struct DuplicatePermutationGenerator<R: Randomizer + ?Sized> {
-    nullable: bool,
-    count_range: ValueBound<SbrdInt>,
+    nullable: Nullable,
+    count_range: ValueBound<ScriptableBound<SbrdInt>>,
     separator: String,
-    selectable_values: Vec<ValueOrChild<R>>,
+    selectable_values: Vec<WeightedValueOrChild<R>>,
 }
 
 impl<R: Randomizer + ?Sized> ValueChildGeneratorBase<R> for DuplicatePermutationGenerator<R> {
-    fn get_selectable(&self) -> &[ValueOrChild<R>] {
+    fn get_selectable(&self) -> &[WeightedValueOrChild<R>] {
         &self.selectable_values
     }
 }
@@ -34,6 +38,7 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for DuplicatePermutationGenerator<
             chars,
             values,
             filepath,
+            weighted,
             ..
         } = builder;
 
@@ -42,31 +47,29 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for DuplicatePermutationGenerator<
         }
 
         let count_range = match range {
-            None => Self::default_range(),
-            Some(r) => r
-                .try_convert_with(|s| {
-                    s.to_parse_string().parse::<SbrdInt>().map_err(|e| {
-                        BuildError::FailParseValue(
-                            s.to_parse_string(),
-                            "Int".to_string(),
-                            e.to_string(),
-                        )
-                    })
-                })?
-                .without_no_bound_from_other((0..).into()),
+            None => ScriptableBound::fixed_range(Self::default_range()),
+            Some(r) => ScriptableBound::parse_range(r, |s| {
+                s.to_parse_string().parse::<SbrdInt>().map_err(|e| {
+                    BuildError::FailParseValue(
+                        s.to_parse_string(),
+                        "Int".to_string(),
+                        e.to_string(),
+                    )
+                })
+            })?
+            .without_no_bound_from_other(ScriptableBound::fixed_range((0..).into())),
         };
-        if let Some(s) = count_range.get_start() {
+        if let Some(ScriptableBound::Value(s)) = count_range.get_start() {
             if s < &0 {
                 return Err(BuildError::InvalidValue(count_range.to_string()));
             }
         }
-        if count_range.is_empty() {
-            return Err(BuildError::RangeEmpty(count_range.convert_into()));
-        }
+        ScriptableBound::check_range(&count_range)?;
 
         let _separator = separator.unwrap_or_else(|| "".to_string());
 
-        let selectable_values = Self::build_selectable(children, chars, values, filepath)?;
+        let selectable_values =
+            Self::build_selectable(children, chars, values, filepath, weighted)?;
 
         Ok(Self {
             nullable,
@@ -76,21 +79,64 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for DuplicatePermutationGenerator<
         })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
         &self,
         rng: &mut R,
         context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        self.generate_permutation(rng, context, None)
+    }
+
+    fn generate_without_null_at(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+        index: u64,
+    ) -> Result<DataValue, GenerateError> {
+        self.generate_permutation(rng, context, Some(index))
+    }
+
+    fn reset(&self) {
+        for (_, value_or_child) in self.selectable_values.iter() {
+            if let Either::Right(child) = value_or_child {
+                child.reset();
+            }
+        }
+    }
+}
+
+impl<R: Randomizer + ?Sized> DuplicatePermutationGenerator<R> {
+    /// Generate the permutation. If the index is specified, generate as the value in the values set at the index.
+    fn generate_permutation(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+        index: Option<u64>,
     ) -> Result<DataValue, GenerateError> {
         let mut result: String = String::new();
         let mut is_first = true;
-        let count = rng.gen_range(self.count_range);
+        let count_range = ScriptableBound::resolve_range(
+            &self.count_range,
+            rng,
+            context,
+            |evaluator, script| evaluator.eval_int(script),
+        )?;
+        if let Some(s) = count_range.get_start() {
+            if s < &0 {
+                return Err(GenerateError::FailGenerate(format!(
+                    "Invalid count range {}",
+                    count_range
+                )));
+            }
+        }
+        let count = rng.gen_range(count_range);
         for _ in 0..count {
             let value_string = self
-                .generate_from_values_or_children(rng, context)?
+                .generate_from_values_or_children(rng, context, index)?
                 .to_permutation_string();
 
             if is_first {
@@ -104,9 +150,7 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for DuplicatePermutationGenerator<
 
         Ok(result.into())
     }
-}
 
-impl<R: Randomizer + ?Sized> DuplicatePermutationGenerator<R> {
     /// default count range
     fn default_range() -> ValueBound<SbrdInt> {
         ValueBound::new(Some(1), Some((true, 15)))
@@ -1,4 +1,4 @@
-use crate::builder::GeneratorBuilder;
+use crate::builder::{GeneratorBuilder, Nullable};
 use crate::error::{BuildError, GenerateError};
 use crate::eval::Evaluator;
 use crate::generator::{GeneratorBase, Randomizer};
@@ -11,7 +11,7 @@ use crate::GeneratorType;
 /// [`Evaluator`]: ../../eval/struct.Evaluator.html
 #[derive(Debug, PartialEq, Clone)]
 pub struct FormatGenerator {
-    nullable: bool,
+    nullable: Nullable,
     format: String,
 }
 
@@ -40,8 +40,8 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for FormatGenerator {
         }
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
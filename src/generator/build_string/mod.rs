@@ -2,6 +2,8 @@
 
 pub use duplicate_permutation::*;
 pub use format_generator::*;
+pub use regex_generator::*;
 
 mod duplicate_permutation;
 mod format_generator;
+mod regex_generator;
@@ -0,0 +1,364 @@
+use crate::builder::{GeneratorBuilder, Nullable};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap};
+use crate::GeneratorType;
+use regex_syntax::ast::parse::Parser;
+use regex_syntax::ast::{
+    self, Ast, ClassAscii, ClassAsciiKind, ClassBracketed, ClassPerl, ClassPerlKind, ClassSet,
+    ClassSetBinaryOpKind, ClassSetItem,
+};
+use regex_syntax::hir::translate::Translator;
+use regex_syntax::hir::{Anchor, Class, Hir, HirKind, Literal, RepetitionKind, RepetitionRange};
+
+/// Default of the maximum count of the repetition for the unbounded quantifier such as `*` and `+`
+const DEFAULT_MAX_REPEAT: u32 = 8;
+
+/// Start of the code points of the surrogates which are not valid as a char
+const SURROGATE_START: u32 = 0xD800;
+/// End of the code points of the surrogates which are not valid as a char
+const SURROGATE_END: u32 = 0xDFFF;
+
+/// The generator with generate [`DataValue::String`] value matching the specified `pattern` as a regular expression.
+///
+/// The pattern supports the literals, the character classes, the quantifiers, the alternations and the groups.
+/// The upper bound of the unbounded quantifier such as `*`, `+` and `{n,}` is the greater of the lower bound and `max_repeat` option. Default for `max_repeat` is 8.
+/// The anchors such as `^` and `$` are ignored at the start and the end of the pattern, and cause an error elsewhere.
+/// The perl classes such as `\d`, `\w` and `\s` and the any character `.` generate only the ascii characters.
+/// The backreferences, the look-around and the word boundaries are not supported.
+///
+/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+#[derive(Debug, PartialEq, Clone)]
+pub struct RegexGenerator {
+    nullable: Nullable,
+    node: RegexNode,
+}
+
+/// Node of the parsed regular expression to generate a string
+#[derive(Debug, PartialEq, Clone)]
+enum RegexNode {
+    /// Generate an empty string
+    Empty,
+    /// Generate the character
+    Literal(char),
+    /// Generate a character in the not empty ranges of the code points
+    Class { ranges: Vec<(u32, u32)>, size: u32 },
+    /// Generate the child repeatedly
+    Repeat {
+        node: Box<RegexNode>,
+        min: u32,
+        max: u32,
+    },
+    /// Generate all children in order
+    Concat(Vec<RegexNode>),
+    /// Generate one of the children
+    Alternation(Vec<RegexNode>),
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for RegexGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            pattern,
+            max_repeat,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::Regex {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let pattern = match pattern {
+            None => return Err(BuildError::NotExistValueOf("pattern".to_string())),
+            Some(pattern) => pattern,
+        };
+        let mut ast = Parser::new()
+            .parse(&pattern)
+            .map_err(|e| BuildError::InvalidPattern(pattern.clone(), e.to_string()))?;
+        restrict_to_ascii(&mut ast);
+        let hir = Translator::new()
+            .translate(&pattern, &ast)
+            .map_err(|e| BuildError::InvalidPattern(pattern.clone(), e.to_string()))?;
+        let node = RegexNode::from_hir(&hir, max_repeat.unwrap_or(DEFAULT_MAX_REPEAT), true, true)
+            .map_err(|e| BuildError::InvalidPattern(pattern.clone(), e))?;
+
+        Ok(Self { nullable, node })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        let mut result = String::new();
+        self.node.generate(rng, &mut result);
+
+        Ok(DataValue::String(result))
+    }
+}
+
+/// Replace the perl classes and the any character with the ascii classes.
+///
+/// The ascii class matches a subset of the characters matched by the replaced one,
+/// so the generated string still matches the pattern.
+fn restrict_to_ascii(ast: &mut Ast) {
+    match ast {
+        Ast::Dot(span) => {
+            let span = *span;
+            *ast = Ast::Class(ast::Class::Bracketed(ClassBracketed {
+                span,
+                negated: false,
+                kind: ClassSet::Item(ClassSetItem::Ascii(ClassAscii {
+                    span,
+                    kind: ClassAsciiKind::Print,
+                    negated: false,
+                })),
+            }));
+        }
+        Ast::Class(ast::Class::Perl(perl)) => {
+            if let Some(ascii) = perl_to_ascii(perl, true) {
+                *ast = Ast::Class(ast::Class::Bracketed(ClassBracketed {
+                    span: perl.span,
+                    negated: false,
+                    kind: ClassSet::Item(ascii),
+                }));
+            }
+        }
+        Ast::Class(ast::Class::Bracketed(bracketed)) => {
+            restrict_set_to_ascii(&mut bracketed.kind, !bracketed.negated)
+        }
+        Ast::Repetition(repetition) => restrict_to_ascii(&mut repetition.ast),
+        Ast::Group(group) => restrict_to_ascii(&mut group.ast),
+        Ast::Alternation(alternation) => alternation.asts.iter_mut().for_each(restrict_to_ascii),
+        Ast::Concat(concat) => concat.asts.iter_mut().for_each(restrict_to_ascii),
+        Ast::Empty(_)
+        | Ast::Flags(_)
+        | Ast::Literal(_)
+        | Ast::Assertion(_)
+        | Ast::Class(ast::Class::Unicode(_)) => {}
+    }
+}
+
+/// Replace the perl classes in the set with the ascii classes only if the set is not negated
+fn restrict_set_to_ascii(set: &mut ClassSet, positive: bool) {
+    match set {
+        ClassSet::Item(item) => restrict_set_item_to_ascii(item, positive),
+        ClassSet::BinaryOp(op) => match op.kind {
+            ClassSetBinaryOpKind::Intersection => {
+                restrict_set_to_ascii(&mut op.lhs, positive);
+                restrict_set_to_ascii(&mut op.rhs, positive);
+            }
+            ClassSetBinaryOpKind::Difference => {
+                restrict_set_to_ascii(&mut op.lhs, positive);
+                restrict_set_to_ascii(&mut op.rhs, !positive);
+            }
+            // the result is not a subset in either case
+            ClassSetBinaryOpKind::SymmetricDifference => {}
+        },
+    }
+}
+
+/// Replace the perl class in the item with the ascii class only if the item is not negated
+fn restrict_set_item_to_ascii(item: &mut ClassSetItem, positive: bool) {
+    match item {
+        ClassSetItem::Perl(perl) => {
+            if let Some(ascii) = perl_to_ascii(perl, positive) {
+                *item = ascii;
+            }
+        }
+        ClassSetItem::Bracketed(bracketed) => {
+            let positive = positive != bracketed.negated;
+            restrict_set_to_ascii(&mut bracketed.kind, positive);
+        }
+        ClassSetItem::Union(union) => union
+            .items
+            .iter_mut()
+            .for_each(|item| restrict_set_item_to_ascii(item, positive)),
+        ClassSetItem::Empty(_)
+        | ClassSetItem::Literal(_)
+        | ClassSetItem::Range(_)
+        | ClassSetItem::Ascii(_)
+        | ClassSetItem::Unicode(_) => {}
+    }
+}
+
+/// Convert the perl class to the ascii class if it is a subset of the perl class
+fn perl_to_ascii(perl: &ClassPerl, positive: bool) -> Option<ClassSetItem> {
+    if perl.negated || !positive {
+        return None;
+    }
+
+    let kind = match perl.kind {
+        ClassPerlKind::Digit => ClassAsciiKind::Digit,
+        ClassPerlKind::Space => ClassAsciiKind::Space,
+        ClassPerlKind::Word => ClassAsciiKind::Word,
+    };
+    Some(ClassSetItem::Ascii(ClassAscii {
+        span: perl.span,
+        kind,
+        negated: false,
+    }))
+}
+
+impl RegexNode {
+    /// Convert from the parsed regular expression. If the expression is not supported, return the error message.
+    ///
+    /// `at_start` and `at_end` are whether the expression is at the start and the end of the pattern, where the anchors are allowed.
+    fn from_hir(hir: &Hir, max_repeat: u32, at_start: bool, at_end: bool) -> Result<Self, String> {
+        match hir.kind() {
+            HirKind::Empty => Ok(RegexNode::Empty),
+            HirKind::Anchor(anchor) => match anchor {
+                Anchor::StartLine | Anchor::StartText if at_start => Ok(RegexNode::Empty),
+                Anchor::EndLine | Anchor::EndText if at_end => Ok(RegexNode::Empty),
+                Anchor::StartLine | Anchor::StartText => {
+                    Err("the anchor ^ is supported only at the start of the pattern".to_string())
+                }
+                Anchor::EndLine | Anchor::EndText => {
+                    Err("the anchor $ is supported only at the end of the pattern".to_string())
+                }
+            },
+            HirKind::Literal(Literal::Unicode(c)) => Ok(RegexNode::Literal(*c)),
+            HirKind::Literal(Literal::Byte(b)) => {
+                if b.is_ascii() {
+                    Ok(RegexNode::Literal(*b as char))
+                } else {
+                    Err(format!("the byte literal \\x{:02X} is not supported", b))
+                }
+            }
+            HirKind::Class(class) => {
+                let ranges: Vec<(u32, u32)> = match class {
+                    Class::Unicode(class) => class
+                        .iter()
+                        .map(|range| (range.start() as u32, range.end() as u32))
+                        .collect(),
+                    Class::Bytes(class) => {
+                        if !class.is_all_ascii() {
+                            return Err(
+                                "the class of the non ascii bytes is not supported".to_string()
+                            );
+                        }
+                        class
+                            .iter()
+                            .map(|range| (range.start() as u32, range.end() as u32))
+                            .collect()
+                    }
+                };
+
+                RegexNode::new_class(ranges)
+            }
+            HirKind::WordBoundary(_) => Err("the word boundary is not supported".to_string()),
+            HirKind::Repetition(repetition) => {
+                let (min, max) = match &repetition.kind {
+                    RepetitionKind::ZeroOrOne => (0, 1),
+                    RepetitionKind::ZeroOrMore => (0, max_repeat),
+                    RepetitionKind::OneOrMore => (1, max_repeat.max(1)),
+                    RepetitionKind::Range(RepetitionRange::Exactly(n)) => (*n, *n),
+                    RepetitionKind::Range(RepetitionRange::AtLeast(n)) => (*n, max_repeat.max(*n)),
+                    RepetitionKind::Range(RepetitionRange::Bounded(m, n)) => (*m, *n),
+                };
+
+                Ok(RegexNode::Repeat {
+                    node: Box::new(RegexNode::from_hir(
+                        &repetition.hir,
+                        max_repeat,
+                        false,
+                        false,
+                    )?),
+                    min,
+                    max,
+                })
+            }
+            HirKind::Group(group) => RegexNode::from_hir(&group.hir, max_repeat, at_start, at_end),
+            HirKind::Concat(hirs) => Ok(RegexNode::Concat(
+                hirs.iter()
+                    .enumerate()
+                    .map(|(i, hir)| {
+                        RegexNode::from_hir(
+                            hir,
+                            max_repeat,
+                            at_start && i == 0,
+                            at_end && i + 1 == hirs.len(),
+                        )
+                    })
+                    .collect::<Result<Vec<RegexNode>, String>>()?,
+            )),
+            HirKind::Alternation(hirs) => Ok(RegexNode::Alternation(
+                hirs.iter()
+                    .map(|hir| RegexNode::from_hir(hir, max_repeat, at_start, at_end))
+                    .collect::<Result<Vec<RegexNode>, String>>()?,
+            )),
+        }
+    }
+
+    /// Create the class from the ranges of the code points without the surrogates
+    fn new_class(ranges: Vec<(u32, u32)>) -> Result<Self, String> {
+        let ranges: Vec<(u32, u32)> = ranges
+            .into_iter()
+            .flat_map(|(start, end)| {
+                if end < SURROGATE_START || SURROGATE_END < start {
+                    vec![(start, end)]
+                } else {
+                    let mut split = Vec::new();
+                    if start < SURROGATE_START {
+                        split.push((start, SURROGATE_START - 1));
+                    }
+                    if SURROGATE_END < end {
+                        split.push((SURROGATE_END + 1, end));
+                    }
+                    split
+                }
+            })
+            .collect();
+        let size = ranges.iter().map(|(start, end)| end - start + 1).sum();
+        if size == 0 {
+            return Err(
+                "the class which does not match any character is not supported".to_string(),
+            );
+        }
+
+        Ok(RegexNode::Class { ranges, size })
+    }
+
+    /// Generate a string matching this node and push it to the result
+    fn generate<R: Randomizer + ?Sized>(&self, rng: &mut R, result: &mut String) {
+        match self {
+            RegexNode::Empty => {}
+            RegexNode::Literal(c) => result.push(*c),
+            RegexNode::Class { ranges, size } => {
+                let mut index = rng.gen_range(0..*size);
+                for (start, end) in ranges.iter() {
+                    let range_size = end - start + 1;
+                    if index < range_size {
+                        if let Some(c) = char::from_u32(start + index) {
+                            result.push(c);
+                        }
+                        return;
+                    }
+                    index -= range_size;
+                }
+            }
+            RegexNode::Repeat { node, min, max } => {
+                let count = rng.gen_range(*min..=*max);
+                for _ in 0..count {
+                    node.generate(rng, result);
+                }
+            }
+            RegexNode::Concat(nodes) => {
+                for node in nodes.iter() {
+                    node.generate(rng, result);
+                }
+            }
+            RegexNode::Alternation(nodes) => {
+                nodes[rng.gen_range(0..nodes.len())].generate(rng, result);
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::distribution::parameter::{
+    clamp_int, parse_int_range, parse_real_parameter, require_parameters,
+};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap, SbrdInt};
+use crate::GeneratorType;
+use rand::distributions::Bernoulli;
+use rand::distributions::Distribution;
+
+/// The generator with generate [`DataValue::Int`] from Bernoulli distribution
+///
+/// The generated value is 1 on success, otherwise 0.
+/// If `range` is specified, then the generated value is clamped within the range.
+///
+/// [`DataValue::Int`]: ../../value/enum.DataValue.html#variant.Int
+#[derive(Debug, Clone, Copy)]
+pub struct BernoulliGenerator {
+    nullable: Nullable,
+    range: Option<ValueBound<SbrdInt>>,
+    distribution: Bernoulli,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for BernoulliGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            range,
+            parameters,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::DistBernoulli {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let parameters = require_parameters(parameters)?;
+        let p = parse_real_parameter(&parameters, Self::P, Some(0.5))?;
+
+        Ok(Self {
+            nullable,
+            range: parse_int_range(range)?,
+            distribution: Bernoulli::new(p).map_err(|e| {
+                BuildError::FailBuildDistribution("Bernoulli".to_string(), e.to_string())
+            })?,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        let value = SbrdInt::from(self.distribution.sample(rng));
+
+        Ok(DataValue::Int(clamp_int(value, &self.range)))
+    }
+}
+
+impl BernoulliGenerator {
+    /// probability of success
+    pub const P: &'static str = "p";
+}
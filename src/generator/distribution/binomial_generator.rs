@@ -0,0 +1,78 @@
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::distribution::parameter::{
+    clamp_int, parse_int_parameter, parse_int_range, parse_real_parameter, require_parameters,
+};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap, SbrdInt};
+use crate::GeneratorType;
+use rand::distributions::Distribution;
+use rand_distr::Binomial;
+
+/// The generator with generate [`DataValue::Int`] from binomial distribution
+///
+/// If `range` is specified, then the generated value is clamped within the range.
+///
+/// [`DataValue::Int`]: ../../value/enum.DataValue.html#variant.Int
+#[derive(Debug, Clone, Copy)]
+pub struct BinomialGenerator {
+    nullable: Nullable,
+    range: Option<ValueBound<SbrdInt>>,
+    distribution: Binomial,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for BinomialGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            range,
+            parameters,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::DistBinomial {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let parameters = require_parameters(parameters)?;
+        let n = parse_int_parameter(&parameters, Self::N, None)?;
+        if n < 0 {
+            return Err(BuildError::InvalidValue(format!("n {} is less than 0", n)));
+        }
+        let p = parse_real_parameter(&parameters, Self::P, Some(0.5))?;
+
+        Ok(Self {
+            nullable,
+            range: parse_int_range(range)?,
+            distribution: Binomial::new(n as u64, p).map_err(|e| {
+                BuildError::FailBuildDistribution("Binomial".to_string(), e.to_string())
+            })?,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        // the count over the maximum of Integer is saturated
+        let value = SbrdInt::try_from(self.distribution.sample(rng)).unwrap_or(SbrdInt::MAX);
+
+        Ok(DataValue::Int(clamp_int(value, &self.range)))
+    }
+}
+
+impl BinomialGenerator {
+    /// number of trials
+    pub const N: &'static str = "n";
+    /// probability of success
+    pub const P: &'static str = "p";
+}
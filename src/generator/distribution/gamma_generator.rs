@@ -0,0 +1,75 @@
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::distribution::parameter::{
+    clamp_real, parse_real_parameter, parse_real_range, require_parameters,
+};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap, SbrdReal};
+use crate::GeneratorType;
+use rand::distributions::Distribution;
+use rand_distr::Gamma;
+
+/// The generator with generate [`DataValue::Real`] from gamma distribution
+///
+/// If `range` is specified, then the generated value is clamped within the range.
+///
+/// [`DataValue::Real`]: ../../value/enum.DataValue.html#variant.Real
+#[derive(Debug, Clone, Copy)]
+pub struct GammaGenerator {
+    nullable: Nullable,
+    range: Option<ValueBound<SbrdReal>>,
+    distribution: Gamma<SbrdReal>,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for GammaGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            range,
+            parameters,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::DistGamma {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let parameters = require_parameters(parameters)?;
+        let shape = parse_real_parameter(&parameters, Self::SHAPE, Some(1.0))?;
+        let scale = parse_real_parameter(&parameters, Self::SCALE, Some(1.0))?;
+
+        Ok(Self {
+            nullable,
+            range: parse_real_range(range)?,
+            distribution: Gamma::new(shape, scale).map_err(|e| {
+                BuildError::FailBuildDistribution("Gamma".to_string(), e.to_string())
+            })?,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        Ok(DataValue::Real(clamp_real(
+            self.distribution.sample(rng),
+            &self.range,
+        )))
+    }
+}
+
+impl GammaGenerator {
+    /// shape
+    pub const SHAPE: &'static str = "shape";
+    /// scale
+    pub const SCALE: &'static str = "scale";
+}
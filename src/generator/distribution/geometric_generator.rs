@@ -0,0 +1,73 @@
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::distribution::parameter::{
+    clamp_int, parse_int_range, parse_real_parameter, require_parameters,
+};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap, SbrdInt};
+use crate::GeneratorType;
+use rand::distributions::Distribution;
+use rand_distr::Geometric;
+
+/// The generator with generate [`DataValue::Int`] from geometric distribution
+///
+/// The generated value is the count of the failures before the first success.
+/// If `range` is specified, then the generated value is clamped within the range.
+///
+/// [`DataValue::Int`]: ../../value/enum.DataValue.html#variant.Int
+#[derive(Debug, Clone, Copy)]
+pub struct GeometricGenerator {
+    nullable: Nullable,
+    range: Option<ValueBound<SbrdInt>>,
+    distribution: Geometric,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for GeometricGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            range,
+            parameters,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::DistGeometric {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let parameters = require_parameters(parameters)?;
+        let p = parse_real_parameter(&parameters, Self::P, Some(0.5))?;
+
+        Ok(Self {
+            nullable,
+            range: parse_int_range(range)?,
+            distribution: Geometric::new(p).map_err(|e| {
+                BuildError::FailBuildDistribution("Geometric".to_string(), e.to_string())
+            })?,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        // the count over the maximum of Integer is saturated
+        let value = SbrdInt::try_from(self.distribution.sample(rng)).unwrap_or(SbrdInt::MAX);
+
+        Ok(DataValue::Int(clamp_int(value, &self.range)))
+    }
+}
+
+impl GeometricGenerator {
+    /// probability of success
+    pub const P: &'static str = "p";
+}
@@ -1,5 +1,30 @@
 //! Module for generator with distribution
 
+pub use bernoulli_generator::*;
+pub use beta_generator::*;
+pub use binomial_generator::*;
+pub use exponential_generator::*;
+pub use gamma_generator::*;
+pub use geometric_generator::*;
+pub use log_normal_generator::*;
 pub use normal_generator::*;
+pub use pareto_generator::*;
+pub use poisson_generator::*;
+pub use uniform_generator::*;
+pub use weibull_generator::*;
+pub use zipf_generator::*;
 
+mod bernoulli_generator;
+mod beta_generator;
+mod binomial_generator;
+mod exponential_generator;
+mod gamma_generator;
+mod geometric_generator;
+mod log_normal_generator;
 mod normal_generator;
+mod parameter;
+mod pareto_generator;
+mod poisson_generator;
+mod uniform_generator;
+mod weibull_generator;
+mod zipf_generator;
@@ -1,5 +1,8 @@
-use crate::builder::GeneratorBuilder;
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
 use crate::error::{BuildError, GenerateError};
+use crate::generator::distribution::parameter::{
+    clamp_real, parse_real_parameter, parse_real_range, require_parameters,
+};
 use crate::generator::{GeneratorBase, Randomizer};
 use crate::value::{DataValue, DataValueMap, SbrdReal};
 use crate::GeneratorType;
@@ -8,10 +11,13 @@ use rand_distr::Normal;
 
 /// The generator with generate [`DataValue::Real`] from normal distribution
 ///
+/// If `range` is specified, then the generated value is clamped within the range.
+///
 /// [`DataValue::Real`]: ../../value/enum.DataValue.html#variant.Real
 #[derive(Debug, Clone, Copy)]
 pub struct NormalGenerator {
-    nullable: bool,
+    nullable: Nullable,
+    range: Option<ValueBound<SbrdReal>>,
     distribution: Normal<SbrdReal>,
 }
 
@@ -23,6 +29,7 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for NormalGenerator {
         let GeneratorBuilder {
             generator_type,
             nullable,
+            range,
             parameters,
             ..
         } = builder;
@@ -31,55 +38,27 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for NormalGenerator {
             return Err(BuildError::InvalidType(generator_type));
         }
 
-        let (mean, std_dev): (SbrdReal, SbrdReal) = match parameters {
-            None => Err(BuildError::NotExistValueOf("parameters".to_string())),
-            Some(parameters) => {
-                let _mean = parameters
-                    .get(Self::MEAN)
-                    .map(|v| {
-                        v.to_parse_string().parse::<SbrdReal>().map_err(|e| {
-                            BuildError::FailParseValue(
-                                v.to_parse_string(),
-                                "Real".to_string(),
-                                e.to_string(),
-                            )
-                        })
-                    })
-                    .unwrap_or_else(|| Ok(0.0))?;
-
-                let _std_dev = parameters
-                    .get(Self::STD_DEV)
-                    .map(|v| {
-                        v.to_parse_string().parse::<SbrdReal>().map_err(|e| {
-                            BuildError::FailParseValue(
-                                v.to_parse_string(),
-                                "Real".to_string(),
-                                e.to_string(),
-                            )
-                        })
-                    })
-                    .unwrap_or_else(|| Ok(1.0))?;
-                if _std_dev < 0.0 {
-                    return Err(BuildError::InvalidValue(format!(
-                        "std_dev {} is less than 0.0",
-                        _std_dev
-                    )));
-                }
-
-                Ok((_mean, _std_dev))
-            }
-        }?;
+        let parameters = require_parameters(parameters)?;
+        let mean = parse_real_parameter(&parameters, Self::MEAN, Some(0.0))?;
+        let std_dev = parse_real_parameter(&parameters, Self::STD_DEV, Some(1.0))?;
+        if std_dev < 0.0 {
+            return Err(BuildError::InvalidValue(format!(
+                "std_dev {} is less than 0.0",
+                std_dev
+            )));
+        }
 
         Ok(Self {
             nullable,
+            range: parse_real_range(range)?,
             distribution: Normal::new(mean, std_dev).map_err(|e| {
                 BuildError::FailBuildDistribution("Normal".to_string(), e.to_string())
             })?,
         })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
@@ -87,7 +66,10 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for NormalGenerator {
         rng: &mut R,
         _context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError> {
-        Ok(DataValue::Real(self.distribution.sample(rng)))
+        Ok(DataValue::Real(clamp_real(
+            self.distribution.sample(rng),
+            &self.range,
+        )))
     }
 }
 
@@ -0,0 +1,132 @@
+//! Module for the parameters and the range of the distributions
+
+use crate::builder::ValueBound;
+use crate::error::BuildError;
+use crate::value::{DataValue, DataValueMap, SbrdInt, SbrdReal};
+
+/// Get the `parameters` option which is required for the distributions
+pub(crate) fn require_parameters(
+    parameters: Option<DataValueMap<String>>,
+) -> Result<DataValueMap<String>, BuildError> {
+    parameters.ok_or_else(|| BuildError::NotExistValueOf("parameters".to_string()))
+}
+
+/// Parse the parameter at the key as Real-number.
+/// If the parameter does not exist, then return the default. If also the default does not exist, then return error.
+pub(crate) fn parse_real_parameter(
+    parameters: &DataValueMap<String>,
+    key: &str,
+    default: Option<SbrdReal>,
+) -> Result<SbrdReal, BuildError> {
+    match parameters.get(key) {
+        None => default.ok_or_else(|| BuildError::NotExistValueOf(key.to_string())),
+        Some(v) => v.to_parse_string().parse::<SbrdReal>().map_err(|e| {
+            BuildError::FailParseValue(v.to_parse_string(), "Real".to_string(), e.to_string())
+        }),
+    }
+}
+
+/// Parse the parameter at the key as Integer.
+/// If the parameter does not exist, then return the default. If also the default does not exist, then return error.
+pub(crate) fn parse_int_parameter(
+    parameters: &DataValueMap<String>,
+    key: &str,
+    default: Option<SbrdInt>,
+) -> Result<SbrdInt, BuildError> {
+    match parameters.get(key) {
+        None => default.ok_or_else(|| BuildError::NotExistValueOf(key.to_string())),
+        Some(v) => v.to_parse_string().parse::<SbrdInt>().map_err(|e| {
+            BuildError::FailParseValue(v.to_parse_string(), "Int".to_string(), e.to_string())
+        }),
+    }
+}
+
+/// Parse the `range` option as the range of Real-number to clamp the generated value
+pub(crate) fn parse_real_range(
+    range: Option<ValueBound<DataValue>>,
+) -> Result<Option<ValueBound<SbrdReal>>, BuildError> {
+    let range = match range {
+        None => return Ok(None),
+        Some(range) => range.try_convert_with(|s| {
+            s.to_parse_string().parse::<SbrdReal>().map_err(|e| {
+                BuildError::FailParseValue(s.to_parse_string(), "Real".to_string(), e.to_string())
+            })
+        })?,
+    };
+    if range.is_empty() {
+        return Err(BuildError::RangeEmpty(range.convert_into()));
+    }
+
+    Ok(Some(range))
+}
+
+/// Parse the `range` option as the range of Integer to clamp the generated value
+pub(crate) fn parse_int_range(
+    range: Option<ValueBound<DataValue>>,
+) -> Result<Option<ValueBound<SbrdInt>>, BuildError> {
+    let range = match range {
+        None => return Ok(None),
+        Some(range) => range.try_convert_with(|s| {
+            s.to_parse_string().parse::<SbrdInt>().map_err(|e| {
+                BuildError::FailParseValue(s.to_parse_string(), "Int".to_string(), e.to_string())
+            })
+        })?,
+    };
+    if range.is_empty() {
+        return Err(BuildError::RangeEmpty(range.convert_into()));
+    }
+
+    Ok(Some(range))
+}
+
+/// Clamp the generated value within the range.
+/// If the end is not included, then clamp to the largest value less than the end.
+pub(crate) fn clamp_real(value: SbrdReal, range: &Option<ValueBound<SbrdReal>>) -> SbrdReal {
+    let range = match range {
+        None => return value,
+        Some(range) => range,
+    };
+
+    if let Some(start) = range.get_start() {
+        if value < *start {
+            return *start;
+        }
+    }
+    if let Some(end) = range.get_end() {
+        if range.is_include_end() {
+            if value > *end {
+                return *end;
+            }
+        } else if value >= *end {
+            return end.next_down();
+        }
+    }
+
+    value
+}
+
+/// Clamp the generated value within the range.
+/// If the end is not included, then clamp to the largest value less than the end.
+pub(crate) fn clamp_int(value: SbrdInt, range: &Option<ValueBound<SbrdInt>>) -> SbrdInt {
+    let range = match range {
+        None => return value,
+        Some(range) => range,
+    };
+
+    if let Some(start) = range.get_start() {
+        if value < *start {
+            return *start;
+        }
+    }
+    if let Some(end) = range.get_end() {
+        if range.is_include_end() {
+            if value > *end {
+                return *end;
+            }
+        } else if value >= *end {
+            return end - 1;
+        }
+    }
+
+    value
+}
@@ -0,0 +1,72 @@
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::distribution::parameter::{
+    clamp_int, parse_int_range, parse_real_parameter, require_parameters,
+};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap, SbrdInt};
+use crate::GeneratorType;
+use rand::distributions::Distribution;
+use rand_distr::Poisson;
+
+/// The generator with generate [`DataValue::Int`] from Poisson distribution
+///
+/// If `range` is specified, then the generated value is clamped within the range.
+///
+/// [`DataValue::Int`]: ../../value/enum.DataValue.html#variant.Int
+#[derive(Debug, Clone, Copy)]
+pub struct PoissonGenerator {
+    nullable: Nullable,
+    range: Option<ValueBound<SbrdInt>>,
+    distribution: Poisson<f64>,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for PoissonGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            range,
+            parameters,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::DistPoisson {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let parameters = require_parameters(parameters)?;
+        let lambda = parse_real_parameter(&parameters, Self::LAMBDA, Some(1.0))?;
+
+        Ok(Self {
+            nullable,
+            range: parse_int_range(range)?,
+            distribution: Poisson::new(lambda).map_err(|e| {
+                BuildError::FailBuildDistribution("Poisson".to_string(), e.to_string())
+            })?,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        // the cast from Real-number to Integer is saturated
+        let value = self.distribution.sample(rng) as SbrdInt;
+
+        Ok(DataValue::Int(clamp_int(value, &self.range)))
+    }
+}
+
+impl PoissonGenerator {
+    /// rate
+    pub const LAMBDA: &'static str = "lambda";
+}
@@ -0,0 +1,80 @@
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::distribution::parameter::{
+    clamp_real, parse_real_parameter, parse_real_range, require_parameters,
+};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap, SbrdReal};
+use crate::GeneratorType;
+use rand::distributions::Distribution;
+use rand::distributions::Uniform;
+
+/// The generator with generate [`DataValue::Real`] from uniform distribution
+///
+/// If `range` is specified, then the generated value is clamped within the range.
+///
+/// [`DataValue::Real`]: ../../value/enum.DataValue.html#variant.Real
+#[derive(Debug, Clone, Copy)]
+pub struct UniformGenerator {
+    nullable: Nullable,
+    range: Option<ValueBound<SbrdReal>>,
+    distribution: Uniform<SbrdReal>,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for UniformGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            range,
+            parameters,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::DistUniform {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let parameters = require_parameters(parameters)?;
+        let low = parse_real_parameter(&parameters, Self::LOW, Some(0.0))?;
+        let high = parse_real_parameter(&parameters, Self::HIGH, Some(1.0))?;
+        // the range must be not empty and finite to build the distribution without panic
+        if low >= high || !(high - low).is_finite() {
+            return Err(BuildError::InvalidValue(format!(
+                "low {} is not less than high {} or the range is not finite",
+                low, high
+            )));
+        }
+
+        Ok(Self {
+            nullable,
+            range: parse_real_range(range)?,
+            distribution: Uniform::new(low, high),
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        Ok(DataValue::Real(clamp_real(
+            self.distribution.sample(rng),
+            &self.range,
+        )))
+    }
+}
+
+impl UniformGenerator {
+    /// lower bound
+    pub const LOW: &'static str = "low";
+    /// upper bound which is not included
+    pub const HIGH: &'static str = "high";
+}
@@ -0,0 +1,79 @@
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::distribution::parameter::{
+    clamp_int, parse_int_parameter, parse_int_range, parse_real_parameter, require_parameters,
+};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap, SbrdInt};
+use crate::GeneratorType;
+use rand::distributions::Distribution;
+use rand_distr::Zipf;
+
+/// The generator with generate [`DataValue::Int`] from Zipf distribution
+///
+/// The generated value is the rank between 1 and `n`.
+/// If `range` is specified, then the generated value is clamped within the range.
+///
+/// [`DataValue::Int`]: ../../value/enum.DataValue.html#variant.Int
+#[derive(Debug, Clone, Copy)]
+pub struct ZipfGenerator {
+    nullable: Nullable,
+    range: Option<ValueBound<SbrdInt>>,
+    distribution: Zipf<f64>,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for ZipfGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            range,
+            parameters,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::DistZipf {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let parameters = require_parameters(parameters)?;
+        let n = parse_int_parameter(&parameters, Self::N, None)?;
+        if n < 0 {
+            return Err(BuildError::InvalidValue(format!("n {} is less than 0", n)));
+        }
+        let s = parse_real_parameter(&parameters, Self::S, Some(1.0))?;
+
+        Ok(Self {
+            nullable,
+            range: parse_int_range(range)?,
+            distribution: Zipf::new(n as u64, s).map_err(|e| {
+                BuildError::FailBuildDistribution("Zipf".to_string(), e.to_string())
+            })?,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        // the cast from Real-number to Integer is saturated
+        let value = self.distribution.sample(rng) as SbrdInt;
+
+        Ok(DataValue::Int(clamp_int(value, &self.range)))
+    }
+}
+
+impl ZipfGenerator {
+    /// number of elements
+    pub const N: &'static str = "n";
+    /// exponent
+    pub const S: &'static str = "s";
+}
@@ -1,8 +1,11 @@
-use crate::builder::GeneratorBuilder;
+use crate::builder::{GeneratorBuilder, Nullable};
 use crate::error::{BuildError, GenerateError};
 use crate::eval::{EvalResult, Evaluator};
 use crate::generator::{GeneratorBase, Randomizer};
-use crate::value::{DataValue, DataValueMap, SbrdBool, SbrdInt, SbrdReal, SbrdString};
+use crate::value::{
+    DataValue, DataValueMap, SbrdBool, SbrdDate, SbrdDateTime, SbrdInt, SbrdReal, SbrdString,
+    SbrdTime,
+};
 use crate::GeneratorType;
 use std::marker::PhantomData;
 
@@ -11,7 +14,7 @@ use std::marker::PhantomData;
 /// [`Evaluator`]: ../../eval/struct.Evaluator.html
 #[derive(Debug, PartialEq, Clone)]
 pub struct EvalGenerator<T> {
-    nullable: bool,
+    nullable: Nullable,
     script: String,
     _calculated_type: PhantomData<T>,
 }
@@ -42,16 +45,17 @@ impl<R: Randomizer + ?Sized, F: ForEvalGeneratorType> GeneratorBase<R> for EvalG
         }
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
         &self,
-        _rng: &mut R,
+        rng: &mut R,
         context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError> {
-        F::eval_script(&self.script, context).map_err(|e| {
+        let evaluator = Evaluator::new(context).with_randomizer(rng);
+        F::eval_script(&evaluator, &self.script).map_err(|e| {
             GenerateError::FailEval(
                 e,
                 self.script.clone(),
@@ -65,12 +69,12 @@ impl<R: Randomizer + ?Sized, F: ForEvalGeneratorType> GeneratorBase<R> for EvalG
 }
 
 /// Helper traits for generators that generate evaluable values
-pub trait ForEvalGeneratorType {
+pub trait ForEvalGeneratorType: Send + Sync {
     /// The type of the generator
     fn get_generator_type() -> GeneratorType;
 
-    /// Evaluate the script with the context
-    fn eval_script<'a>(script: &'a str, context: &'a DataValueMap<&str>) -> EvalResult<DataValue>;
+    /// Evaluate the script with the evaluator
+    fn eval_script(evaluator: &Evaluator, script: &str) -> EvalResult<DataValue>;
 }
 
 impl ForEvalGeneratorType for SbrdInt {
@@ -78,8 +82,7 @@ impl ForEvalGeneratorType for SbrdInt {
         GeneratorType::EvalInt
     }
 
-    fn eval_script<'a>(script: &'a str, context: &'a DataValueMap<&str>) -> EvalResult<DataValue> {
-        let evaluator = Evaluator::new(context);
+    fn eval_script(evaluator: &Evaluator, script: &str) -> EvalResult<DataValue> {
         evaluator.eval_int(script).map(|v| v.into())
     }
 }
@@ -88,8 +91,7 @@ impl ForEvalGeneratorType for SbrdReal {
         GeneratorType::EvalReal
     }
 
-    fn eval_script<'a>(script: &'a str, context: &'a DataValueMap<&str>) -> EvalResult<DataValue> {
-        let evaluator = Evaluator::new(context);
+    fn eval_script(evaluator: &Evaluator, script: &str) -> EvalResult<DataValue> {
         evaluator.eval_real(script).map(|v| v.into())
     }
 }
@@ -98,8 +100,7 @@ impl ForEvalGeneratorType for SbrdBool {
         GeneratorType::EvalBool
     }
 
-    fn eval_script<'a>(script: &'a str, context: &'a DataValueMap<&str>) -> EvalResult<DataValue> {
-        let evaluator = Evaluator::new(context);
+    fn eval_script(evaluator: &Evaluator, script: &str) -> EvalResult<DataValue> {
         evaluator.eval_bool(script).map(|v| v.into())
     }
 }
@@ -108,8 +109,34 @@ impl ForEvalGeneratorType for SbrdString {
         GeneratorType::EvalString
     }
 
-    fn eval_script<'a>(script: &'a str, context: &'a DataValueMap<&str>) -> EvalResult<DataValue> {
-        let evaluator = Evaluator::new(context);
+    fn eval_script(evaluator: &Evaluator, script: &str) -> EvalResult<DataValue> {
         evaluator.eval_string(script).map(|v| v.into())
     }
 }
+impl ForEvalGeneratorType for SbrdDateTime {
+    fn get_generator_type() -> GeneratorType {
+        GeneratorType::EvalDateTime
+    }
+
+    fn eval_script(evaluator: &Evaluator, script: &str) -> EvalResult<DataValue> {
+        evaluator.eval_date_time(script).map(|v| v.into())
+    }
+}
+impl ForEvalGeneratorType for SbrdDate {
+    fn get_generator_type() -> GeneratorType {
+        GeneratorType::EvalDate
+    }
+
+    fn eval_script(evaluator: &Evaluator, script: &str) -> EvalResult<DataValue> {
+        evaluator.eval_date(script).map(|v| v.into())
+    }
+}
+impl ForEvalGeneratorType for SbrdTime {
+    fn get_generator_type() -> GeneratorType {
+        GeneratorType::EvalTime
+    }
+
+    fn eval_script(evaluator: &Evaluator, script: &str) -> EvalResult<DataValue> {
+        evaluator.eval_time(script).map(|v| v.into())
+    }
+}
@@ -1,11 +1,12 @@
-use crate::builder::{ChildGeneratorBuilder, GeneratorBuilder, Weight};
+use crate::builder::{ChildGeneratorBuilder, GeneratorBuilder, Nullable, Weight};
 use crate::error::{BuildError, GenerateError};
 use crate::eval::Evaluator;
 use crate::file::open_sbrd_file;
 use crate::value::{DataValue, DataValueMap};
 use either::Either;
 use rand::seq::SliceRandom;
-use rand::Rng;
+use rand::{thread_rng, Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 
@@ -13,15 +14,54 @@ use std::path::PathBuf;
 pub trait Randomizer: 'static + Rng {}
 impl<R: 'static + Rng> Randomizer for R {}
 
-/// Base trait for a generator
-pub trait GeneratorBase<R: Randomizer + ?Sized> {
+/// Seed for [`SeededRandomizer`]
+///
+/// [`SeededRandomizer`]: ./type.SeededRandomizer.html
+pub type RandomizerSeed = u64;
+
+/// Randomizer which always generates the same sequence from the same seed on any platform
+pub type SeededRandomizer = ChaCha20Rng;
+
+/// Create [`SeededRandomizer`] from the seed
+///
+/// [`SeededRandomizer`]: ./type.SeededRandomizer.html
+pub fn build_seeded_randomizer(seed: RandomizerSeed) -> SeededRandomizer {
+    SeededRandomizer::seed_from_u64(seed)
+}
+
+/// Create [`SeededRandomizer`] for the values set at the index from the seed.
+///
+/// Each index has an independent stream of the randomizer, so the values set at the index can be generated in any order.
+///
+/// [`SeededRandomizer`]: ./type.SeededRandomizer.html
+pub fn build_indexed_randomizer(seed: RandomizerSeed, index: u64) -> SeededRandomizer {
+    let mut rng = build_seeded_randomizer(seed);
+    rng.set_stream(index);
+    rng
+}
+
+/// Pick out a seed at random.
+/// Remember the picked seed if you want to replay the generation.
+pub fn gen_randomizer_seed() -> RandomizerSeed {
+    thread_rng().gen()
+}
+
+/// Base trait for a generator.
+///
+/// A generator is shareable across threads, so the state of the generator must be thread safe.
+pub trait GeneratorBase<R: Randomizer + ?Sized>: Send + Sync {
     /// Create generator from builder
     fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
     where
         Self: Sized;
 
+    /// Get nullable status
+    fn get_nullable(&self) -> &Nullable;
+
     /// Can generate null flag
-    fn is_nullable(&self) -> bool;
+    fn is_nullable(&self) -> bool {
+        self.get_nullable().is_nullable()
+    }
 
     /// Cannot generate null flag
     fn is_required(&self) -> bool {
@@ -37,7 +77,7 @@ pub trait GeneratorBase<R: Randomizer + ?Sized> {
         if self.is_required() {
             self.generate_without_null(rng, context)
         } else {
-            if rng.gen_bool(0.1) {
+            if rng.gen_bool(self.get_nullable().get_probability()) {
                 return Ok(DataValue::Null);
             }
 
@@ -51,6 +91,56 @@ pub trait GeneratorBase<R: Randomizer + ?Sized> {
         rng: &mut R,
         context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError>;
+
+    /// Generate dummy data considering nullable as the value in the values set at the index
+    fn generate_at(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+        index: u64,
+    ) -> Result<DataValue, GenerateError> {
+        if self.is_required() {
+            self.generate_without_null_at(rng, context, index)
+        } else {
+            if rng.gen_bool(self.get_nullable().get_probability()) {
+                return Ok(DataValue::Null);
+            }
+
+            self.generate_without_null_at(rng, context, index)
+        }
+    }
+
+    /// Generate dummy data not considering nullable as the value in the values set at the index.
+    ///
+    /// By default, the index is not used. A generator whose value depends on the order of generation,
+    /// or has child generators, overrides this.
+    fn generate_without_null_at(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+        _index: u64,
+    ) -> Result<DataValue, GenerateError> {
+        self.generate_without_null(rng, context)
+    }
+
+    /// Forget the state depending on the values generated before such as the counts of referencing.
+    ///
+    /// By default, do nothing. A generator with such state, or has child generators, overrides this.
+    fn reset(&self) {}
+}
+
+/// Generate dummy data considering nullable by the generator.
+/// If the index is specified, generate as the value in the values set at the index.
+pub(crate) fn generate_with_index<R: Randomizer + ?Sized>(
+    generator: &dyn GeneratorBase<R>,
+    rng: &mut R,
+    context: &DataValueMap<&str>,
+    index: Option<u64>,
+) -> Result<DataValue, GenerateError> {
+    match index {
+        None => generator.generate(rng, context),
+        Some(index) => generator.generate_at(rng, context, index),
+    }
 }
 
 /// Child generator with condition
@@ -96,32 +186,36 @@ pub trait CasedChildGeneratorBase<R: Randomizer + ?Sized> {
     /// Get selectable child generators
     fn get_children(&self) -> &[CasedChild<R>];
 
-    /// Generate dummy data considering nullable from picked out child generator
+    /// Generate dummy data considering nullable from picked out child generator.
+    /// If the index is specified, generate as the value in the values set at the index.
     fn generate_from_children(
         &self,
         rng: &mut R,
         context: &DataValueMap<&str>,
+        index: Option<u64>,
     ) -> Result<DataValue, GenerateError> {
         for (condition, generator) in self.get_children().iter() {
             return match condition {
-                None => generator.generate(rng, context),
+                None => generate_with_index(generator.as_ref(), rng, context, index),
                 Some(_condition) => {
-                    let evaluator = Evaluator::new(context);
-                    let is_satisfy = evaluator.eval_bool(_condition).map_err(|e| {
-                        GenerateError::FailEval(
-                            e,
-                            _condition.clone(),
-                            context
-                                .iter()
-                                .map(|(k, v)| (k.to_string(), v.clone()))
-                                .collect::<DataValueMap<String>>(),
-                        )
-                    })?;
+                    let is_satisfy = Evaluator::new(context)
+                        .with_randomizer(rng)
+                        .eval_bool(_condition)
+                        .map_err(|e| {
+                            GenerateError::FailEval(
+                                e,
+                                _condition.clone(),
+                                context
+                                    .iter()
+                                    .map(|(k, v)| (k.to_string(), v.clone()))
+                                    .collect::<DataValueMap<String>>(),
+                            )
+                        })?;
                     if !is_satisfy {
                         continue;
                     }
 
-                    generator.generate(rng, context)
+                    generate_with_index(generator.as_ref(), rng, context, index)
                 }
             };
         }
@@ -167,21 +261,116 @@ pub trait WeightedChildGeneratorBase<R: Randomizer + ?Sized> {
     /// Get selectable list
     fn get_selectable(&self) -> &[WeightedChild<R>];
 
-    /// Generate value from picked out child generator
+    /// Generate value from picked out child generator.
+    /// If the index is specified, generate as the value in the values set at the index.
     fn generate_from_children(
         &self,
         rng: &mut R,
         context: &DataValueMap<&str>,
+        index: Option<u64>,
     ) -> Result<DataValue, GenerateError> {
         let item = self
             .get_selectable()
             .choose_weighted(rng, |item| item.0)
             .map_err(|err| GenerateError::FailGenerate(err.to_string()))?;
 
-        item.1.generate(rng, context)
+        generate_with_index(item.1.as_ref(), rng, context, index)
+    }
+}
+
+/// Key of the value in a weighted value such as `{value: "active", weight: 80}`
+const WEIGHTED_VALUE_KEY: &str = "value";
+
+/// Key of the weight in a weighted value such as `{value: "active", weight: 80}`
+const WEIGHTED_WEIGHT_KEY: &str = "weight";
+
+/// Split the input value into the weight and the value.
+/// The value which is not a weighted value such as `{value: "active", weight: 80}` has the weight 1.
+fn split_weighted_value(value: DataValue) -> Result<(Weight, DataValue), BuildError> {
+    let mut map = match value {
+        DataValue::Object(map) if map.contains_key(WEIGHTED_VALUE_KEY) => map,
+        _ => return Ok((1, value)),
+    };
+
+    let weight = match map.remove(WEIGHTED_WEIGHT_KEY) {
+        None => 1,
+        Some(DataValue::Int(weight)) => Weight::try_from(weight).map_err(|e| {
+            BuildError::FailParseValue(weight.to_string(), "Weight".to_string(), e.to_string())
+        })?,
+        Some(weight) => {
+            return Err(BuildError::InvalidValue(format!(
+                "{} is not a weight at the key {}",
+                weight, WEIGHTED_WEIGHT_KEY
+            )))
+        }
+    };
+    let value = map
+        .remove(WEIGHTED_VALUE_KEY)
+        .expect("The weighted value always has the value");
+    if let Some(key) = map.keys().next() {
+        return Err(BuildError::InvalidValue(format!(
+            "{} is not a key of the weighted value",
+            key
+        )));
+    }
+
+    Ok((weight, value))
+}
+
+/// Split the line in the file into the weight and the value separated by the last tab.
+/// The line without a tab has the weight 1.
+fn split_weighted_line(line: String) -> Result<(Weight, String), BuildError> {
+    match line.rsplit_once('\t') {
+        None => Ok((1, line)),
+        Some((value, weight)) => {
+            let weight = weight.trim().parse::<Weight>().map_err(|e| {
+                BuildError::FailParseValue(weight.to_string(), "Weight".to_string(), e.to_string())
+            })?;
+
+            Ok((weight, value.to_string()))
+        }
+    }
+}
+
+/// Read the weighted values from the characters, the values and the lines in the file.
+/// If not weighted, each line in the file is the value as it is with the weight 1.
+fn read_weighted_values(
+    chars: Option<String>,
+    values: Option<Vec<DataValue>>,
+    filepath: Option<PathBuf>,
+    weighted: bool,
+) -> Result<Vec<(Weight, DataValue)>, BuildError> {
+    let mut weighted_values = Vec::new();
+    if let Some(chars) = chars {
+        weighted_values.extend(chars.chars().map(|c| (1, DataValue::String(c.to_string()))));
+    }
+
+    if let Some(values) = values {
+        for value in values.into_iter() {
+            weighted_values.push(split_weighted_value(value)?);
+        }
+    }
+
+    if let Some(filepath) = filepath {
+        let file = open_sbrd_file(filepath.as_path())
+            .map_err(|e| BuildError::FileError(e, filepath.clone()))?;
+        let reader = BufReader::new(file);
+        for line in reader.lines() {
+            let line = line.map_err(|e| BuildError::FileError(e, filepath.clone()))?;
+            let (weight, line) = if weighted {
+                split_weighted_line(line)?
+            } else {
+                (1, line)
+            };
+            weighted_values.push((weight, DataValue::String(line)));
+        }
     }
+
+    Ok(weighted_values)
 }
 
+/// Value with weight
+pub type WeightedValue<T> = (Weight, T);
 /// Base trait for a generator from input values
 pub trait ValueGeneratorBase<R: Randomizer + ?Sized, T> {
     /// Function of parser the input value
@@ -222,6 +411,34 @@ pub trait ValueGeneratorBase<R: Randomizer + ?Sized, T> {
 
         Ok(selectable_values)
     }
+
+    /// Build selectable value with weight.
+    ///
+    /// Each value in `values` can be a weighted value such as `{value: "active", weight: 80}`,
+    /// and if weighted, each line in the file at `filepath` can be the value and the weight separated by the last tab.
+    /// The weight of the other value is 1.
+    fn build_weighted_selectable(
+        chars: Option<String>,
+        values: Option<Vec<DataValue>>,
+        filepath: Option<PathBuf>,
+        weighted: bool,
+    ) -> Result<Vec<WeightedValue<T>>, BuildError> {
+        let mut selectable_values: Vec<WeightedValue<T>> = Vec::new();
+        for (weight, value) in read_weighted_values(chars, values, filepath, weighted)?.into_iter()
+        {
+            selectable_values.push((weight, Self::parse(&value.to_parse_string())?));
+        }
+
+        if selectable_values.is_empty() {
+            return Err(BuildError::EmptySelectableChildren);
+        }
+
+        if selectable_values.iter().all(|item| item.0 == 0) {
+            return Err(BuildError::AllWeightsZero);
+        }
+
+        Ok(selectable_values)
+    }
 }
 
 /// Value as String or Child for a generator.
@@ -229,63 +446,67 @@ pub trait ValueGeneratorBase<R: Randomizer + ?Sized, T> {
 /// Usually, this structure is used by a generator which generate value as string,
 /// because input value's type is unknown and a type of the generated value by child generator is also unknown.
 pub type ValueOrChild<R> = Either<String, Box<dyn GeneratorBase<R>>>;
+/// Value or Child with weight
+pub type WeightedValueOrChild<R> = (Weight, ValueOrChild<R>);
 /// Base trait for a generator use picked out value from input values or generated value picked out child generator
+///
+/// The weight of the child is `weight` of the child, and the weight of the value is the same as
+/// [`ValueGeneratorBase::build_weighted_selectable`].
+///
+/// [`ValueGeneratorBase::build_weighted_selectable`]: ./trait.ValueGeneratorBase.html#method.build_weighted_selectable
 pub trait ValueChildGeneratorBase<R: Randomizer + ?Sized> {
-    /// Build selectable value and child generator
+    /// Build selectable value and child generator with weight
     fn build_selectable(
         children: Option<Vec<ChildGeneratorBuilder>>,
         chars: Option<String>,
         values: Option<Vec<DataValue>>,
         filepath: Option<PathBuf>,
-    ) -> Result<Vec<ValueOrChild<R>>, BuildError> {
+        weighted: bool,
+    ) -> Result<Vec<WeightedValueOrChild<R>>, BuildError> {
         let mut select_values = Vec::new();
         if let Some(children) = children {
             for child_builder in children.into_iter() {
-                let ChildGeneratorBuilder { builder, .. } = child_builder;
-                select_values.push(Either::Right(builder.build()?));
+                let ChildGeneratorBuilder {
+                    weight, builder, ..
+                } = child_builder;
+                select_values.push((weight.unwrap_or(1), Either::Right(builder.build()?)));
             }
         }
 
-        if let Some(chars) = chars {
-            select_values.extend(chars.chars().map(|c| Either::Left(c.to_string())));
-        }
-
-        if let Some(values) = values {
-            select_values.extend(values.into_iter().map(|v| Either::Left(v.to_string())));
-        }
-
-        if let Some(filepath) = filepath {
-            let file = open_sbrd_file(filepath.as_path())
-                .map_err(|e| BuildError::FileError(e, filepath.clone()))?;
-            let reader = BufReader::new(file);
-            for line in reader.lines() {
-                let line = line.map_err(|e| BuildError::FileError(e, filepath.clone()))?;
-                select_values.push(Either::Left(line));
-            }
-        }
+        select_values.extend(
+            read_weighted_values(chars, values, filepath, weighted)?
+                .into_iter()
+                .map(|(weight, value)| (weight, Either::Left(value.to_string()))),
+        );
 
         if select_values.is_empty() {
             return Err(BuildError::EmptySelectable);
         }
 
+        if select_values.iter().all(|item| item.0 == 0) {
+            return Err(BuildError::AllWeightsZero);
+        }
+
         Ok(select_values)
     }
 
     /// Get selectable list
-    fn get_selectable(&self) -> &[ValueOrChild<R>];
+    fn get_selectable(&self) -> &[WeightedValueOrChild<R>];
 
-    /// Pick out value from input values or generated value picked out child generator
+    /// Pick out value from input values or generated value picked out child generator.
+    /// If the index is specified, generate as the value in the values set at the index.
     fn generate_from_values_or_children(
         &self,
         rng: &mut R,
         context: &DataValueMap<&str>,
+        index: Option<u64>,
     ) -> Result<DataValue, GenerateError> {
         self.get_selectable()
-            .choose_weighted(rng, |_| 1)
+            .choose_weighted(rng, |item| item.0)
             .map_err(|err| GenerateError::FailGenerate(err.to_string()))
-            .and_then(|either| match either {
+            .and_then(|(_, either)| match either {
                 Either::Left(item) => Ok(item.clone().into()),
-                Either::Right(item) => item.generate(rng, context),
+                Either::Right(item) => generate_with_index(item.as_ref(), rng, context, index),
             })
     }
 }
@@ -0,0 +1,132 @@
+//! Module for the parts of the identifiers such as the timestamp and the encoding
+
+use crate::builder::{IdEncoding, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::scriptable_bound::{eval_date_bound, ScriptableBound};
+use crate::generator::Randomizer;
+use crate::value::{
+    DataValue, DataValueMap, SbrdDateTime, SbrdZonedDateTime, DATE_TIME_DEFAULT_FORMAT,
+    ZONED_DATE_TIME_DEFAULT_FORMAT,
+};
+
+/// Characters of Crockford's Base32
+const CROCKFORD_BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Parse the date-time for the timestamp.
+/// The date-time with the offset is converted into UTC, and the date-time without the offset is treated as UTC.
+fn parse_date_time(s: &str) -> Option<SbrdDateTime> {
+    SbrdDateTime::parse_from_str(s, DATE_TIME_DEFAULT_FORMAT)
+        .ok()
+        .or_else(|| {
+            SbrdZonedDateTime::parse_from_str(s, ZONED_DATE_TIME_DEFAULT_FORMAT)
+                .ok()
+                .map(|date_time| date_time.naive_utc())
+        })
+}
+
+/// Parse the `range` option as the range of the date-time for the timestamp.
+/// An unspecified bound is filled with the default.
+pub(crate) fn parse_timestamp_range(
+    range: Option<ValueBound<DataValue>>,
+    default_range: ValueBound<SbrdDateTime>,
+) -> Result<ValueBound<ScriptableBound<SbrdDateTime>>, BuildError> {
+    let range = match range {
+        None => ScriptableBound::fixed_range(default_range),
+        Some(r) => ScriptableBound::parse_range(r, |s| {
+            parse_date_time(&s.to_parse_string()).ok_or_else(|| {
+                BuildError::FailParseValue(
+                    s.to_parse_string(),
+                    "DateTime".to_string(),
+                    "invalid date time".to_string(),
+                )
+            })
+        })
+        .map(|range| {
+            range.without_no_bound_from_other(ScriptableBound::fixed_range(default_range))
+        })?,
+    };
+    ScriptableBound::check_range(&range)?;
+
+    Ok(range)
+}
+
+/// Generate the timestamp in milliseconds in the range
+pub(crate) fn generate_timestamp_millis<R: Randomizer + ?Sized>(
+    range: &ValueBound<ScriptableBound<SbrdDateTime>>,
+    rng: &mut R,
+    context: &DataValueMap<&str>,
+) -> Result<i64, GenerateError> {
+    let range = ScriptableBound::resolve_range(range, rng, context, |evaluator, script| {
+        eval_date_bound(evaluator, script, parse_date_time, |evaluator, script| {
+            evaluator.eval_date_time(script)
+        })
+    })?;
+
+    Ok(rng.gen_range(range.convert_with(|date_time| date_time.timestamp_millis())))
+}
+
+/// Check the timestamp is within the bits from the epoch
+pub(crate) fn check_timestamp_millis(
+    timestamp: i64,
+    epoch: i64,
+    bits: u32,
+) -> Result<u64, GenerateError> {
+    match timestamp.checked_sub(epoch) {
+        Some(elapsed) if (0..1_i64 << bits).contains(&elapsed) => Ok(elapsed as u64),
+        _ => Err(GenerateError::FailGenerate(format!(
+            "Timestamp {} is out of range for {} bits from the epoch {}",
+            timestamp, bits, epoch
+        ))),
+    }
+}
+
+/// Check the encoding is available for the generator
+pub(crate) fn check_encoding(
+    encoding: IdEncoding,
+    available: &[IdEncoding],
+) -> Result<IdEncoding, BuildError> {
+    if !available.contains(&encoding) {
+        return Err(BuildError::InvalidValue(format!(
+            "encoding {} is not available. Available encodings are {}",
+            encoding,
+            available
+                .iter()
+                .map(|encoding| encoding.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )));
+    }
+
+    Ok(encoding)
+}
+
+/// Encode the value of the bits with the encoding as a string
+pub(crate) fn encode(value: u128, bits: u32, encoding: IdEncoding) -> String {
+    let simple = || format!("{:0width$x}", value, width = (bits as usize).div_ceil(4));
+    let hyphenated = || {
+        let hex = simple();
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        )
+    };
+
+    match encoding {
+        IdEncoding::Hyphenated => hyphenated(),
+        IdEncoding::Simple => simple(),
+        IdEncoding::Uppercase if bits == 128 => hyphenated().to_uppercase(),
+        IdEncoding::Uppercase => simple().to_uppercase(),
+        IdEncoding::Base32 => {
+            let length = (bits as usize).div_ceil(5);
+            (0..length)
+                .rev()
+                .map(|i| CROCKFORD_BASE32[((value >> (i * 5)) & 0x1f) as usize] as char)
+                .collect()
+        }
+        IdEncoding::Integer => value.to_string(),
+    }
+}
@@ -0,0 +1,10 @@
+//! Module for generator with generate an identifier such as UUID.
+
+pub use snowflake_generator::*;
+pub use ulid_generator::*;
+pub use uuid_generator::*;
+
+mod id_parts;
+mod snowflake_generator;
+mod ulid_generator;
+mod uuid_generator;
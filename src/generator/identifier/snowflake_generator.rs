@@ -0,0 +1,156 @@
+use crate::builder::{GeneratorBuilder, IdEncoding, Nullable, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::identifier::id_parts::{
+    check_encoding, check_timestamp_millis, encode, generate_timestamp_millis,
+    parse_timestamp_range,
+};
+use crate::generator::scriptable_bound::ScriptableBound;
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap, SbrdDateTime, SbrdInt};
+use crate::GeneratorType;
+
+/// The generator with generate Twitter-style snowflake ID as [`DataValue::Int`]
+///
+/// The snowflake ID consists of 41 bits of the timestamp in milliseconds from `epoch`,
+/// 10 bits of `machine_id` and 12 bits of the sequence number.
+/// The timestamp is in `range`, and the sequence number is random.
+/// If `machine_id` is not specified, then it is random for each value.
+/// The bound of the range can be a script such as "{key}" evaluated while generating,
+/// so the timestamp can be taken from the date-time generated before.
+/// The date-time without the offset is treated as UTC.
+///
+/// The default of `encoding` is [`IdEncoding::Integer`], and the other encodings generate [`DataValue::String`].
+///
+/// [`DataValue::Int`]: ../../value/enum.DataValue.html#variant.Int
+/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+/// [`IdEncoding::Integer`]: ../../builder/enum.IdEncoding.html#variant.Integer
+#[derive(Debug, PartialEq, Clone)]
+pub struct SnowflakeGenerator {
+    nullable: Nullable,
+    range: ValueBound<ScriptableBound<SbrdDateTime>>,
+    epoch: SbrdInt,
+    machine_id: Option<SbrdInt>,
+    encoding: IdEncoding,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for SnowflakeGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            range,
+            parameters,
+            encoding,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::Snowflake {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let parameters = parameters.unwrap_or_default();
+        let epoch = Self::parse_parameter(&parameters, Self::EPOCH)?.unwrap_or(Self::DEFAULT_EPOCH);
+        let machine_id = Self::parse_parameter(&parameters, Self::MACHINE_ID)?;
+        if let Some(machine_id) = machine_id {
+            if !(0..=Self::MAX_MACHINE_ID).contains(&machine_id) {
+                return Err(BuildError::InvalidValue(format!(
+                    "machine_id {} is not in 0..={}",
+                    machine_id,
+                    Self::MAX_MACHINE_ID
+                )));
+            }
+        }
+
+        let start = epoch
+            .checked_add(999)
+            .and_then(|start| SbrdDateTime::from_timestamp_opt(start.div_euclid(1000), 0));
+        let end = epoch
+            .checked_add(1 << Self::TIMESTAMP_BITS)
+            .and_then(|end| SbrdDateTime::from_timestamp_opt(end / 1000, 0));
+        let default_range = match (start, end) {
+            (Some(start), Some(end)) => ValueBound::new(Some(start), Some((false, end))),
+            _ => {
+                return Err(BuildError::InvalidValue(format!(
+                    "epoch {} is out of range of the date-time",
+                    epoch
+                )))
+            }
+        };
+
+        let encoding = check_encoding(
+            encoding.unwrap_or(IdEncoding::Integer),
+            &[
+                IdEncoding::Integer,
+                IdEncoding::Simple,
+                IdEncoding::Uppercase,
+                IdEncoding::Base32,
+            ],
+        )?;
+
+        Ok(Self {
+            nullable,
+            range: parse_timestamp_range(range, default_range)?,
+            epoch,
+            machine_id,
+            encoding,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        let timestamp = generate_timestamp_millis(&self.range, rng, context)?;
+        let elapsed = check_timestamp_millis(timestamp, self.epoch, Self::TIMESTAMP_BITS)?;
+        let machine_id = match self.machine_id {
+            Some(machine_id) => machine_id,
+            None => rng.gen_range(0..=Self::MAX_MACHINE_ID),
+        };
+        let sequence = rng.gen_range(0..=Self::MAX_SEQUENCE);
+        let value = ((elapsed as SbrdInt) << 22) | (machine_id << 12) | sequence;
+
+        Ok(match self.encoding {
+            IdEncoding::Integer => DataValue::Int(value),
+            encoding => DataValue::String(encode(value as u128, 64, encoding)),
+        })
+    }
+}
+
+impl SnowflakeGenerator {
+    /// epoch of the timestamp in milliseconds since the Unix epoch
+    pub const EPOCH: &'static str = "epoch";
+    /// machine id from 0 to 1023
+    pub const MACHINE_ID: &'static str = "machine_id";
+
+    /// Epoch of Twitter's snowflake ID (2010-11-04 01:42:54.657 UTC)
+    const DEFAULT_EPOCH: SbrdInt = 1288834974657;
+    const TIMESTAMP_BITS: u32 = 41;
+    const MAX_MACHINE_ID: SbrdInt = (1 << 10) - 1;
+    const MAX_SEQUENCE: SbrdInt = (1 << 12) - 1;
+
+    fn parse_parameter(
+        parameters: &DataValueMap<String>,
+        key: &str,
+    ) -> Result<Option<SbrdInt>, BuildError> {
+        parameters
+            .get(key)
+            .map(|v| {
+                v.to_parse_string().parse::<SbrdInt>().map_err(|e| {
+                    BuildError::FailParseValue(
+                        v.to_parse_string(),
+                        "Int".to_string(),
+                        e.to_string(),
+                    )
+                })
+            })
+            .transpose()
+    }
+}
@@ -0,0 +1,89 @@
+use crate::builder::{GeneratorBuilder, IdEncoding, Nullable, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::identifier::id_parts::{
+    check_encoding, check_timestamp_millis, encode, generate_timestamp_millis,
+    parse_timestamp_range,
+};
+use crate::generator::scriptable_bound::ScriptableBound;
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap, SbrdDate, SbrdDateTime};
+use crate::GeneratorType;
+
+/// The generator with generate ULID as [`DataValue::String`]
+///
+/// The timestamp of ULID is in `range`.
+/// The bound of the range can be a script such as "{key}" evaluated while generating,
+/// so the timestamp can be taken from the date-time generated before.
+/// The date-time without the offset is treated as UTC.
+///
+/// The default of `encoding` is [`IdEncoding::Base32`].
+///
+/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+/// [`IdEncoding::Base32`]: ../../builder/enum.IdEncoding.html#variant.Base32
+#[derive(Debug, PartialEq, Clone)]
+pub struct UlidGenerator {
+    nullable: Nullable,
+    range: ValueBound<ScriptableBound<SbrdDateTime>>,
+    encoding: IdEncoding,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for UlidGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            range,
+            encoding,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::Ulid {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let encoding = check_encoding(
+            encoding.unwrap_or(IdEncoding::Base32),
+            &[
+                IdEncoding::Base32,
+                IdEncoding::Hyphenated,
+                IdEncoding::Simple,
+                IdEncoding::Uppercase,
+            ],
+        )?;
+
+        Ok(Self {
+            nullable,
+            range: parse_timestamp_range(range, Self::default_range())?,
+            encoding,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        let timestamp = generate_timestamp_millis(&self.range, rng, context)?;
+        let timestamp = check_timestamp_millis(timestamp, 0, 48)?;
+        let random = rng.gen::<u128>() & ((1 << 80) - 1);
+        let value = ((timestamp as u128) << 80) | random;
+
+        Ok(DataValue::String(encode(value, 128, self.encoding)))
+    }
+}
+
+impl UlidGenerator {
+    fn default_range() -> ValueBound<SbrdDateTime> {
+        ValueBound::new(
+            Some(SbrdDate::from_ymd(1970, 1, 1).and_hms(0, 0, 0)),
+            Some((false, SbrdDate::from_ymd(2151, 1, 1).and_hms(0, 0, 0))),
+        )
+    }
+}
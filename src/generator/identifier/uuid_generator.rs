@@ -0,0 +1,101 @@
+use crate::builder::{GeneratorBuilder, IdEncoding, Nullable, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::identifier::id_parts::{
+    check_encoding, check_timestamp_millis, encode, generate_timestamp_millis,
+    parse_timestamp_range,
+};
+use crate::generator::scriptable_bound::ScriptableBound;
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap, SbrdDate, SbrdDateTime};
+use crate::GeneratorType;
+
+/// The generator with generate UUID as [`DataValue::String`]
+///
+/// The type `uuid-v4` generates the random UUID version 4,
+/// and the type `uuid-v7` generates the time-ordered UUID version 7 with the timestamp in `range`.
+/// The bound of the range can be a script such as "{key}" evaluated while generating,
+/// so the timestamp can be taken from the date-time generated before.
+/// The date-time without the offset is treated as UTC.
+///
+/// The default of `encoding` is [`IdEncoding::Hyphenated`].
+///
+/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+/// [`IdEncoding::Hyphenated`]: ../../builder/enum.IdEncoding.html#variant.Hyphenated
+#[derive(Debug, PartialEq, Clone)]
+pub struct UuidGenerator {
+    nullable: Nullable,
+    range: Option<ValueBound<ScriptableBound<SbrdDateTime>>>,
+    encoding: IdEncoding,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for UuidGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            range,
+            encoding,
+            ..
+        } = builder;
+
+        let range = match generator_type {
+            GeneratorType::UuidV4 => None,
+            GeneratorType::UuidV7 => Some(parse_timestamp_range(range, Self::default_range())?),
+            _ => return Err(BuildError::InvalidType(generator_type)),
+        };
+
+        let encoding = check_encoding(
+            encoding.unwrap_or(IdEncoding::Hyphenated),
+            &[
+                IdEncoding::Hyphenated,
+                IdEncoding::Simple,
+                IdEncoding::Uppercase,
+                IdEncoding::Base32,
+            ],
+        )?;
+
+        Ok(Self {
+            nullable,
+            range,
+            encoding,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        let random = rng.gen::<u128>();
+        let value = match &self.range {
+            None => (random & !(0xf << 76) & !(0x3 << 62)) | (0x4 << 76) | (0x2 << 62),
+            Some(range) => {
+                let timestamp = generate_timestamp_millis(range, rng, context)?;
+                let timestamp = check_timestamp_millis(timestamp, 0, 48)?;
+                ((timestamp as u128) << 80)
+                    | (0x7 << 76)
+                    | (random & (0xfff << 64))
+                    | (0x2 << 62)
+                    | (random & ((1 << 62) - 1))
+            }
+        };
+
+        Ok(DataValue::String(encode(value, 128, self.encoding)))
+    }
+}
+
+impl UuidGenerator {
+    fn default_range() -> ValueBound<SbrdDateTime> {
+        ValueBound::new(
+            Some(SbrdDate::from_ymd(1970, 1, 1).and_hms(0, 0, 0)),
+            Some((false, SbrdDate::from_ymd(2151, 1, 1).and_hms(0, 0, 0))),
+        )
+    }
+}
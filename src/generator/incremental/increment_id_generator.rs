@@ -1,20 +1,61 @@
-use crate::builder::GeneratorBuilder;
+use crate::builder::{GeneratorBuilder, Nullable};
 use crate::error::{BuildError, GenerateError};
 use crate::generator::{GeneratorBase, Randomizer};
 use crate::value::{DataValue, DataValueMap, SbrdInt};
 use crate::GeneratorType;
-use std::cell::Cell;
+use std::sync::atomic::{AtomicI64, Ordering};
 
 const INITIAL_ID: SbrdInt = 1;
 const DEFAULT_STEP: SbrdInt = 1;
 
 /// The generator with generate [`DataValue::Int`] value with the initial value and the step value.
 ///
+/// When generating as the value in the values set at the index, the generated value is `initial + step * index`
+/// regardless of the order of generation.
+/// A null value also uses up its id, so generating in order gives the same id to each values set.
+/// This generator is not available as a child of the other generator except a not nullable object generator,
+/// because the count of the values at an index is not determined.
+///
+/// # Examples
+/// ```
+/// fn main() {
+///     use sbrd_gen::builder::GeneratorBuilder;
+///     use sbrd_gen::generator::{build_seeded_randomizer, SeededRandomizer};
+///     use sbrd_gen::value::DataValue;
+///     use sbrd_gen::{Schema, SchemaBuilder};
+///
+///     let builders = vec![GeneratorBuilder::new_increment_id(None)
+///         .nullable_with_probability(0.5)
+///         .into_parent("id")];
+///     let schema: Schema<SeededRandomizer> =
+///         SchemaBuilder::new(vec!["id".to_string()], builders).build().unwrap();
+///
+///     let mut rng = build_seeded_randomizer(42);
+///     let in_order = (0..10).map(|_| schema.generate(&mut rng).unwrap().get_all_values()["id"].clone());
+///     let from_seed = schema.generate_from_seed(42, 10).unwrap();
+///     let at_index = from_seed.iter().map(|values| values.get_all_values()["id"].clone());
+///     for values in [in_order.collect::<Vec<_>>(), at_index.collect()] {
+///         for (index, value) in values.into_iter().enumerate() {
+///             assert!(value == DataValue::Null || value == DataValue::Int(index as i64 + 1));
+///         }
+///     }
+///
+///     let in_array = GeneratorBuilder::new_array(
+///         None,
+///         vec![GeneratorBuilder::new_increment_id(None).into_child()],
+///     );
+///     let schema = SchemaBuilder::new(vec!["ids".to_string()], vec![in_array.into_parent("ids")])
+///         .build::<SeededRandomizer>();
+///     assert!(schema.is_err());
+/// }
+/// ```
+///
 /// [`DataValue::Int`]: ../../value/enum.DataValue.html#variant.Int
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug)]
 pub struct IncrementIdGenerator {
-    nullable: bool,
-    current_id: Cell<SbrdInt>,
+    nullable: Nullable,
+    initial_id: SbrdInt,
+    current_id: AtomicI64,
     step: SbrdInt,
 }
 
@@ -68,13 +109,27 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for IncrementIdGenerator {
 
         Ok(Self {
             nullable,
-            current_id: Cell::new(initial_id),
+            initial_id,
+            current_id: AtomicI64::new(initial_id),
             step,
         })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        let id = self.generate_without_null(rng, context)?;
+        if self.nullable.is_nullable() && rng.gen_bool(self.nullable.get_probability()) {
+            return Ok(DataValue::Null);
+        }
+
+        Ok(id)
     }
 
     fn generate_without_null(
@@ -82,8 +137,27 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for IncrementIdGenerator {
         _rng: &mut R,
         _context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError> {
-        let id = self.current_id.get();
-        self.current_id.replace(id + self.step);
+        let id = self.current_id.fetch_add(self.step, Ordering::SeqCst);
+
+        Ok(DataValue::Int(id))
+    }
+
+    fn generate_without_null_at(
+        &self,
+        _rng: &mut R,
+        _context: &DataValueMap<&str>,
+        index: u64,
+    ) -> Result<DataValue, GenerateError> {
+        let id = SbrdInt::try_from(index)
+            .ok()
+            .and_then(|index| self.step.checked_mul(index))
+            .and_then(|diff| self.initial_id.checked_add(diff))
+            .ok_or_else(|| {
+                GenerateError::FailGenerate(format!(
+                    "The id at the index {} is out of range of Int",
+                    index
+                ))
+            })?;
 
         Ok(DataValue::Int(id))
     }
@@ -0,0 +1,50 @@
+use crate::builder::{GeneratorBuilder, Nullable};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::locale::dataset::{load_dataset, pick, DatasetKind};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap};
+use crate::GeneratorType;
+
+/// The generator with generate [`DataValue::String`] value as a city's name from the bundled dataset for the locale
+///
+/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+#[derive(Debug, PartialEq, Clone)]
+pub struct CityGenerator {
+    nullable: Nullable,
+    cities: Vec<&'static str>,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for CityGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            locale,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::City {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let locale = locale.unwrap_or_default();
+        let cities = load_dataset(locale, DatasetKind::City);
+
+        Ok(Self { nullable, cities })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        Ok(DataValue::String(pick(rng, &self.cities).to_string()))
+    }
+}
@@ -0,0 +1,200 @@
+//! Module for the bundled datasets for each locale
+
+use crate::builder::Locale;
+use crate::error::GenerateError;
+use crate::eval::Evaluator;
+use crate::generator::Randomizer;
+use crate::value::DataValueMap;
+
+/// Kind of the bundled dataset
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub(crate) enum DatasetKind {
+    /// First names as `display,romanized` per line
+    FirstName,
+    /// Last names as `display,romanized` per line
+    LastName,
+    /// Templates of a person's name
+    PersonNameFormat,
+    /// Templates of a company's name
+    CompanyFormat,
+    /// Names of streets or towns
+    StreetName,
+    /// Templates of a street address
+    StreetFormat,
+    /// Names of cities
+    City,
+    /// Templates of a postal code
+    PostalCodeFormat,
+    /// Templates of a phone number
+    PhoneNumberFormat,
+    /// Domains for an email address and an url
+    Domain,
+    /// Words or phrases for a lorem text
+    LoremWord,
+}
+
+/// A name consisting of the display name and the romanized name
+pub(crate) type NameEntry = (&'static str, &'static str);
+
+/// Get the source of the bundled dataset
+fn get_source(locale: Locale, kind: DatasetKind) -> &'static str {
+    match (locale, kind) {
+        (Locale::En, DatasetKind::FirstName) => include_str!("data/en/first_names.txt"),
+        (Locale::En, DatasetKind::LastName) => include_str!("data/en/last_names.txt"),
+        (Locale::En, DatasetKind::PersonNameFormat) => {
+            include_str!("data/en/person_name_formats.txt")
+        }
+        (Locale::En, DatasetKind::CompanyFormat) => include_str!("data/en/company_formats.txt"),
+        (Locale::En, DatasetKind::StreetName) => include_str!("data/en/street_names.txt"),
+        (Locale::En, DatasetKind::StreetFormat) => include_str!("data/en/street_formats.txt"),
+        (Locale::En, DatasetKind::City) => include_str!("data/en/cities.txt"),
+        (Locale::En, DatasetKind::PostalCodeFormat) => {
+            include_str!("data/en/postal_code_formats.txt")
+        }
+        (Locale::En, DatasetKind::PhoneNumberFormat) => {
+            include_str!("data/en/phone_number_formats.txt")
+        }
+        (Locale::En, DatasetKind::Domain) => include_str!("data/en/domains.txt"),
+        (Locale::En, DatasetKind::LoremWord) => include_str!("data/en/lorem_words.txt"),
+
+        (Locale::Ja, DatasetKind::FirstName) => include_str!("data/ja/first_names.txt"),
+        (Locale::Ja, DatasetKind::LastName) => include_str!("data/ja/last_names.txt"),
+        (Locale::Ja, DatasetKind::PersonNameFormat) => {
+            include_str!("data/ja/person_name_formats.txt")
+        }
+        (Locale::Ja, DatasetKind::CompanyFormat) => include_str!("data/ja/company_formats.txt"),
+        (Locale::Ja, DatasetKind::StreetName) => include_str!("data/ja/street_names.txt"),
+        (Locale::Ja, DatasetKind::StreetFormat) => include_str!("data/ja/street_formats.txt"),
+        (Locale::Ja, DatasetKind::City) => include_str!("data/ja/cities.txt"),
+        (Locale::Ja, DatasetKind::PostalCodeFormat) => {
+            include_str!("data/ja/postal_code_formats.txt")
+        }
+        (Locale::Ja, DatasetKind::PhoneNumberFormat) => {
+            include_str!("data/ja/phone_number_formats.txt")
+        }
+        (Locale::Ja, DatasetKind::Domain) => include_str!("data/ja/domains.txt"),
+        (Locale::Ja, DatasetKind::LoremWord) => include_str!("data/ja/lorem_words.txt"),
+    }
+}
+
+/// Load the bundled dataset as the not empty lines
+pub(crate) fn load_dataset(locale: Locale, kind: DatasetKind) -> Vec<&'static str> {
+    get_source(locale, kind)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Load the bundled dataset of the names
+pub(crate) fn load_names(locale: Locale, kind: DatasetKind) -> Vec<NameEntry> {
+    load_dataset(locale, kind)
+        .into_iter()
+        .map(|line| match line.split_once(',') {
+            None => (line, line),
+            Some((display, romanized)) => (display.trim(), romanized.trim()),
+        })
+        .collect()
+}
+
+/// Load the bundled datasets of the first names and the last names for all locales to romanize the words
+pub(crate) fn load_romanization_names() -> Vec<NameEntry> {
+    [Locale::En, Locale::Ja]
+        .into_iter()
+        .flat_map(|locale| {
+            load_names(locale, DatasetKind::LastName)
+                .into_iter()
+                .chain(load_names(locale, DatasetKind::FirstName))
+        })
+        .collect()
+}
+
+/// Pick out an item from the not empty items
+pub(crate) fn pick<'a, R: Randomizer + ?Sized, T>(rng: &mut R, items: &'a [T]) -> &'a T {
+    &items[rng.gen_range(0..items.len())]
+}
+
+/// Fill the template.
+///
+/// The placeholder `{name}` is replaced by the value for the name, `#` by a digit and `%` by a not zero digit.
+pub(crate) fn fill_template<R, F>(rng: &mut R, template: &str, mut replace: F) -> String
+where
+    R: Randomizer + ?Sized,
+    F: FnMut(&mut R, &str) -> Option<String>,
+{
+    let mut result = String::new();
+    let mut rest = template;
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '#' => result.push_str(&rng.gen_range(0..=9).to_string()),
+            '%' => result.push_str(&rng.gen_range(1..=9).to_string()),
+            '{' => {
+                if let Some(end) = rest.find('}') {
+                    let name = &rest[1..end];
+                    if let Some(value) = replace(rng, name) {
+                        result.push_str(&value);
+                        rest = &rest[end + 1..];
+                        continue;
+                    }
+                }
+                result.push(c);
+            }
+            _ => result.push(c),
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+
+    result
+}
+
+/// Convert the text to the words consisting of lowercase ascii alphanumerics.
+///
+/// A not ascii word containing the display name of the names is converted to the romanized name.
+pub(crate) fn romanize_words(text: &str, names: &[NameEntry]) -> Vec<String> {
+    text.split_whitespace()
+        .filter_map(|word| {
+            let romanized = if word.is_ascii() {
+                word
+            } else {
+                names
+                    .iter()
+                    .filter(|(display, _)| word.contains(display))
+                    .max_by_key(|(display, _)| display.len())
+                    .map(|(_, romanized)| *romanized)
+                    .unwrap_or(word)
+            };
+
+            let romanized: String = romanized
+                .chars()
+                .filter(|c| c.is_ascii_alphanumeric())
+                .map(|c| c.to_ascii_lowercase())
+                .collect();
+            if romanized.is_empty() {
+                None
+            } else {
+                Some(romanized)
+            }
+        })
+        .collect()
+}
+
+/// Evaluate the format and convert it to the words consisting of lowercase ascii alphanumerics
+pub(crate) fn format_words(
+    format: &str,
+    context: &DataValueMap<&str>,
+    names: &[NameEntry],
+) -> Result<Vec<String>, GenerateError> {
+    let text = Evaluator::new(context).format_script(format).map_err(|e| {
+        GenerateError::FailEval(
+            e,
+            format.to_string(),
+            context
+                .clone()
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect::<DataValueMap<String>>(),
+        )
+    })?;
+
+    Ok(romanize_words(&text, names))
+}
@@ -0,0 +1,89 @@
+use crate::builder::{GeneratorBuilder, Nullable};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::locale::dataset::{
+    format_words, load_dataset, load_names, load_romanization_names, pick, DatasetKind, NameEntry,
+};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap};
+use crate::GeneratorType;
+
+/// Separators between the words in the local part of an email address
+const SEPARATORS: [&str; 3] = [".", "_", ""];
+
+/// The generator with generate [`DataValue::String`] value as an email address with the domain from the bundled dataset for the locale.
+///
+/// If `format` is specified, the local part is derived from the evaluated format such as a generated person's name.
+/// Otherwise, the local part is derived from a person's name picked out from the bundled dataset.
+///
+/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+#[derive(Debug, PartialEq, Clone)]
+pub struct EmailGenerator {
+    nullable: Nullable,
+    format: Option<String>,
+    first_names: Vec<NameEntry>,
+    last_names: Vec<NameEntry>,
+    domains: Vec<&'static str>,
+    romanization_names: Vec<NameEntry>,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for EmailGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            format,
+            locale,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::Email {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let locale = locale.unwrap_or_default();
+        Ok(Self {
+            nullable,
+            format,
+            first_names: load_names(locale, DatasetKind::FirstName),
+            last_names: load_names(locale, DatasetKind::LastName),
+            domains: load_dataset(locale, DatasetKind::Domain),
+            romanization_names: load_romanization_names(),
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        let mut words = match &self.format {
+            None => Vec::new(),
+            Some(format) => format_words(format, context, &self.romanization_names)?,
+        };
+        if words.is_empty() {
+            words = vec![
+                pick(rng, &self.first_names).1.to_string(),
+                pick(rng, &self.last_names).1.to_string(),
+            ];
+        }
+
+        let mut local_part = words.join(*pick(rng, &SEPARATORS));
+        // a number makes the address more varied
+        if rng.gen_bool(0.3) {
+            local_part.push_str(&rng.gen_range(1..100).to_string());
+        }
+
+        Ok(DataValue::String(format!(
+            "{}@{}",
+            local_part,
+            pick(rng, &self.domains)
+        )))
+    }
+}
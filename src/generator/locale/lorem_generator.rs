@@ -0,0 +1,102 @@
+use crate::builder::{GeneratorBuilder, Locale, Nullable, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::locale::dataset::{load_dataset, pick, DatasetKind};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap, SbrdInt};
+use crate::GeneratorType;
+
+/// The generator with generate [`DataValue::String`] value as a lorem text consisting of the words from the bundled dataset for the locale.
+/// The count of the words is picked out from the range.
+///
+/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+#[derive(Debug, PartialEq, Clone)]
+pub struct LoremGenerator {
+    nullable: Nullable,
+    locale: Locale,
+    count_range: ValueBound<SbrdInt>,
+    words: Vec<&'static str>,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for LoremGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            range,
+            locale,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::Lorem {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let count_range = match range {
+            None => Self::default_range(),
+            Some(r) => r
+                .try_convert_with(|s| {
+                    s.to_parse_string().parse::<SbrdInt>().map_err(|e| {
+                        BuildError::FailParseValue(
+                            s.to_parse_string(),
+                            "Int".to_string(),
+                            e.to_string(),
+                        )
+                    })
+                })?
+                .without_no_bound_from_other(Self::default_range()),
+        };
+        if let Some(s) = count_range.get_start() {
+            if s < &1 {
+                return Err(BuildError::InvalidValue(count_range.to_string()));
+            }
+        }
+        if count_range.is_empty() {
+            return Err(BuildError::RangeEmpty(count_range.convert_into()));
+        }
+
+        let locale = locale.unwrap_or_default();
+        Ok(Self {
+            nullable,
+            locale,
+            count_range,
+            words: load_dataset(locale, DatasetKind::LoremWord),
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        let count = rng.gen_range(self.count_range);
+        let words: Vec<&str> = (0..count).map(|_| *pick(rng, &self.words)).collect();
+
+        let text = match self.locale {
+            Locale::En => {
+                let sentence = words.join(" ");
+                let mut chars = sentence.chars();
+                match chars.next() {
+                    None => sentence,
+                    Some(c) => format!("{}{}.", c.to_ascii_uppercase(), chars.as_str()),
+                }
+            }
+            Locale::Ja => format!("{}。", words.join("、")),
+        };
+
+        Ok(DataValue::String(text))
+    }
+}
+
+impl LoremGenerator {
+    /// default count range
+    fn default_range() -> ValueBound<SbrdInt> {
+        ValueBound::new(Some(5), Some((true, 15)))
+    }
+}
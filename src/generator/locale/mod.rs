@@ -0,0 +1,22 @@
+//! Module for generator with generate a realistic value from the bundled dataset for the locale.
+
+pub use city_generator::*;
+pub use company_name_generator::*;
+pub use email_generator::*;
+pub use lorem_generator::*;
+pub use person_name_generator::*;
+pub use phone_number_generator::*;
+pub use postal_code_generator::*;
+pub use street_address_generator::*;
+pub use url_generator::*;
+
+mod city_generator;
+mod company_name_generator;
+mod dataset;
+mod email_generator;
+mod lorem_generator;
+mod person_name_generator;
+mod phone_number_generator;
+mod postal_code_generator;
+mod street_address_generator;
+mod url_generator;
@@ -0,0 +1,68 @@
+use crate::builder::{GeneratorBuilder, Nullable};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::locale::dataset::{
+    fill_template, load_dataset, load_names, pick, DatasetKind, NameEntry,
+};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap};
+use crate::GeneratorType;
+
+/// The generator with generate [`DataValue::String`] value as a person's name from the bundled dataset for the locale
+///
+/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+#[derive(Debug, PartialEq, Clone)]
+pub struct PersonNameGenerator {
+    nullable: Nullable,
+    formats: Vec<&'static str>,
+    first_names: Vec<NameEntry>,
+    last_names: Vec<NameEntry>,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for PersonNameGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            locale,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::PersonName {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let locale = locale.unwrap_or_default();
+        let formats = load_dataset(locale, DatasetKind::PersonNameFormat);
+        let first_names = load_names(locale, DatasetKind::FirstName);
+        let last_names = load_names(locale, DatasetKind::LastName);
+
+        Ok(Self {
+            nullable,
+            formats,
+            first_names,
+            last_names,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        let format = *pick(rng, &self.formats);
+        let name = fill_template(rng, format, |rng, placeholder| match placeholder {
+            "first" => Some(pick(rng, &self.first_names).0.to_string()),
+            "last" => Some(pick(rng, &self.last_names).0.to_string()),
+            _ => None,
+        });
+
+        Ok(DataValue::String(name))
+    }
+}
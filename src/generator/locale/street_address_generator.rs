@@ -0,0 +1,62 @@
+use crate::builder::{GeneratorBuilder, Nullable};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::locale::dataset::{fill_template, load_dataset, pick, DatasetKind};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap};
+use crate::GeneratorType;
+
+/// The generator with generate [`DataValue::String`] value as a street address from the bundled dataset for the locale
+///
+/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+#[derive(Debug, PartialEq, Clone)]
+pub struct StreetAddressGenerator {
+    nullable: Nullable,
+    formats: Vec<&'static str>,
+    street_names: Vec<&'static str>,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for StreetAddressGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            locale,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::StreetAddress {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let locale = locale.unwrap_or_default();
+        let formats = load_dataset(locale, DatasetKind::StreetFormat);
+        let street_names = load_dataset(locale, DatasetKind::StreetName);
+
+        Ok(Self {
+            nullable,
+            formats,
+            street_names,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        let format = *pick(rng, &self.formats);
+        let address = fill_template(rng, format, |rng, placeholder| match placeholder {
+            "street" => Some(pick(rng, &self.street_names).to_string()),
+            _ => None,
+        });
+
+        Ok(DataValue::String(address))
+    }
+}
@@ -0,0 +1,75 @@
+use crate::builder::{GeneratorBuilder, Nullable};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::locale::dataset::{
+    format_words, load_dataset, load_names, load_romanization_names, pick, DatasetKind, NameEntry,
+};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap};
+use crate::GeneratorType;
+
+/// The generator with generate [`DataValue::String`] value as an url with the domain from the bundled dataset for the locale.
+///
+/// If `format` is specified, the subdomain is derived from the evaluated format such as a generated company's name.
+/// Otherwise, the subdomain is derived from a name picked out from the bundled dataset.
+///
+/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+#[derive(Debug, PartialEq, Clone)]
+pub struct UrlGenerator {
+    nullable: Nullable,
+    format: Option<String>,
+    last_names: Vec<NameEntry>,
+    domains: Vec<&'static str>,
+    romanization_names: Vec<NameEntry>,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for UrlGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            format,
+            locale,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::Url {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let locale = locale.unwrap_or_default();
+        Ok(Self {
+            nullable,
+            format,
+            last_names: load_names(locale, DatasetKind::LastName),
+            domains: load_dataset(locale, DatasetKind::Domain),
+            romanization_names: load_romanization_names(),
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        let mut words = match &self.format {
+            None => Vec::new(),
+            Some(format) => format_words(format, context, &self.romanization_names)?,
+        };
+        if words.is_empty() {
+            words = vec![pick(rng, &self.last_names).1.to_string()];
+        }
+
+        Ok(DataValue::String(format!(
+            "https://{}.{}/",
+            words.join("-"),
+            pick(rng, &self.domains)
+        )))
+    }
+}
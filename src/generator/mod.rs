@@ -6,7 +6,11 @@ pub mod build_string;
 pub mod distribution;
 pub mod evaluate;
 mod generator_base;
+pub mod identifier;
 pub mod incremental;
+pub mod locale;
 pub mod primitive;
 pub mod random_children;
 pub mod random_values;
+mod scriptable_bound;
+pub mod structure;
@@ -1,4 +1,4 @@
-use crate::builder::GeneratorBuilder;
+use crate::builder::{GeneratorBuilder, Nullable};
 use crate::error::{BuildError, GenerateError};
 use crate::generator::{GeneratorBase, Randomizer};
 use crate::value::{DataValue, DataValueMap};
@@ -24,8 +24,8 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for AlwaysNullGenerator {
         Ok(Self {})
     }
 
-    fn is_nullable(&self) -> bool {
-        true
+    fn get_nullable(&self) -> &Nullable {
+        &Nullable::Flag(true)
     }
 
     fn generate_without_null(
@@ -1,4 +1,4 @@
-use crate::builder::GeneratorBuilder;
+use crate::builder::{GeneratorBuilder, Nullable};
 use crate::error::{BuildError, GenerateError};
 use crate::generator::{GeneratorBase, Randomizer};
 use crate::value::{DataValue, DataValueMap};
@@ -7,9 +7,9 @@ use crate::GeneratorType;
 /// The generator with generate [`DataValue::Bool`] value
 ///
 /// [`DataValue::Bool`]: ../../value/enum.DataValue.html#variant.Bool
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct BoolGenerator {
-    nullable: bool,
+    nullable: Nullable,
 }
 
 impl<R: Randomizer + ?Sized> GeneratorBase<R> for BoolGenerator {
@@ -30,8 +30,8 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for BoolGenerator {
         Ok(Self { nullable })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
@@ -1,25 +1,28 @@
-use crate::builder::{GeneratorBuilder, ValueBound};
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
 use crate::error::{BuildError, GenerateError};
 use crate::eval::Evaluator;
+use crate::generator::scriptable_bound::{eval_date_bound, ScriptableBound};
 use crate::generator::{GeneratorBase, Randomizer};
 use crate::value::{DataValue, DataValueMap, SbrdDate, DATE_DEFAULT_FORMAT};
 use crate::GeneratorType;
 use chrono::Datelike;
+use std::fmt::Write;
 
-/// The generator with generate [`SbrdDate`] value as [`DataValue::String`] with the format
+/// The generator with generate [`SbrdDate`] value as [`DataValue::Date`] with the format applied when writing
 ///
 /// See [`format::strftime` module] for more information on `format` option.
 /// The default for `format` and the format when parsing is [`DATE_DEFAULT_FORMAT`].
+/// The bound of the range can be a script such as "{key}" evaluated while generating.
 ///
 /// [`SbrdDate`]: ../../value/type.SbrdDate.html
-/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+/// [`DataValue::Date`]: ../../value/enum.DataValue.html#variant.Date
 /// [`format::strftime` module]: https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html#specifiers
 /// [`DATE_DEFAULT_FORMAT`]: ../../value/constant.DATE_DEFAULT_FORMAT.html
 #[derive(Debug, PartialEq, Clone)]
 pub struct DateGenerator {
-    nullable: bool,
-    format: String,
-    range: ValueBound<SbrdDate>,
+    nullable: Nullable,
+    format: Option<String>,
+    range: ValueBound<ScriptableBound<SbrdDate>>,
 }
 
 impl<R: Randomizer + ?Sized> GeneratorBase<R> for DateGenerator {
@@ -41,37 +44,32 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for DateGenerator {
 
         let default_range = Self::default_range();
         let _range = match range {
-            None => default_range,
-            Some(r) => r
-                .try_convert_with(|s| {
-                    SbrdDate::parse_from_str(&s.to_parse_string(), DATE_DEFAULT_FORMAT).map_err(
-                        |e| {
-                            BuildError::FailParseValue(
-                                s.to_parse_string(),
-                                "Date".to_string(),
-                                e.to_string(),
-                            )
-                        },
+            None => ScriptableBound::fixed_range(default_range),
+            Some(r) => ScriptableBound::parse_range(r, |s| {
+                SbrdDate::parse_from_str(&s.to_parse_string(), DATE_DEFAULT_FORMAT).map_err(|e| {
+                    BuildError::FailParseValue(
+                        s.to_parse_string(),
+                        "Date".to_string(),
+                        e.to_string(),
                     )
                 })
-                .map(|range| {
-                    // If it is not specified so that it can be generated in an appropriate range, use the default boundary.
-                    range.without_no_bound_from_other(default_range)
-                })?,
+            })
+            .map(|range| {
+                // If it is not specified so that it can be generated in an appropriate range, use the default boundary.
+                range.without_no_bound_from_other(ScriptableBound::fixed_range(default_range))
+            })?,
         };
-        if _range.is_empty() {
-            return Err(BuildError::RangeEmpty(_range.convert_into()));
-        }
+        ScriptableBound::check_range(&_range)?;
 
         Ok(Self {
             nullable,
-            format: format.unwrap_or_else(|| DATE_DEFAULT_FORMAT.to_string()),
+            format,
             range: _range,
         })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
@@ -79,7 +77,16 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for DateGenerator {
         rng: &mut R,
         context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError> {
-        let num_days_range = self.range.convert_with(|date| date.num_days_from_ce());
+        let range =
+            ScriptableBound::resolve_range(&self.range, rng, context, |evaluator, script| {
+                eval_date_bound(
+                    evaluator,
+                    script,
+                    |s| SbrdDate::parse_from_str(s, DATE_DEFAULT_FORMAT).ok(),
+                    |evaluator, script| evaluator.eval_date(script),
+                )
+            })?;
+        let num_days_range = range.convert_with(|date| date.num_days_from_ce());
         let num_days_value = rng.gen_range(num_days_range);
         let date_value = SbrdDate::from_num_days_from_ce_opt(num_days_value).ok_or_else(|| {
             GenerateError::FailGenerate(format!(
@@ -88,20 +95,31 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for DateGenerator {
             ))
         })?;
 
-        let evaluator = Evaluator::new(context);
-        let format = evaluator.format_script(&self.format).map_err(|e| {
-            GenerateError::FailEval(
-                e,
-                self.format.to_string(),
-                context
-                    .clone()
-                    .into_iter()
-                    .map(|(k, v)| (k.to_string(), v))
-                    .collect::<DataValueMap<String>>(),
-            )
-        })?;
+        let format = match &self.format {
+            None => None,
+            Some(format) => {
+                let evaluator = Evaluator::new(context);
+                let format = evaluator.format_script(format).map_err(|e| {
+                    GenerateError::FailEval(
+                        e,
+                        format.to_string(),
+                        context
+                            .clone()
+                            .into_iter()
+                            .map(|(k, v)| (k.to_string(), v))
+                            .collect::<DataValueMap<String>>(),
+                    )
+                })?;
+                // check the format is available before writing
+                write!(String::new(), "{}", date_value.format(&format)).map_err(|_| {
+                    GenerateError::FailGenerate(format!("Invalid format \"{}\"", format))
+                })?;
+
+                Some(format)
+            }
+        };
 
-        Ok(DataValue::String(date_value.format(&format).to_string()))
+        Ok(DataValue::Date(date_value, format))
     }
 }
 
@@ -1,24 +1,139 @@
-use crate::builder::{GeneratorBuilder, ValueBound};
+use crate::builder::{GeneratorBuilder, Nullable, TimeZoneOption, ValueBound, Weight};
 use crate::error::{BuildError, GenerateError};
 use crate::eval::Evaluator;
+use crate::generator::scriptable_bound::{eval_date_bound, ScriptableBound};
 use crate::generator::{GeneratorBase, Randomizer};
-use crate::value::{DataValue, DataValueMap, SbrdDate, SbrdDateTime, DATE_TIME_DEFAULT_FORMAT};
+use crate::value::{
+    DataValue, DataValueMap, SbrdDate, SbrdDateTime, SbrdZonedDateTime, DATE_TIME_DEFAULT_FORMAT,
+};
 use crate::GeneratorType;
+use chrono::{Duration, FixedOffset, LocalResult, Offset, TimeZone};
+use chrono_tz::Tz;
+use rand::seq::SliceRandom;
+use std::fmt::Write;
 
-/// The generator with generate [`SbrdDateTime`] value as [`DataValue::String`] with the format
+/// The generator with generate [`SbrdDateTime`] value as [`DataValue::DateTime`] with the format applied when writing
 ///
 /// See [`format::strftime` module] for more information on `format` option.
 /// The default for `format` and the format when parsing is [`DATE_TIME_DEFAULT_FORMAT`].
+/// The bound of the range can be a script such as "{key}" evaluated while generating.
+///
+/// If `timezone` option is specified, then generate [`SbrdZonedDateTime`] value as [`DataValue::ZonedDateTime`].
+/// The range is interpreted as the local date-time in the time zone, and the value is picked out uniformly from the instants in the range.
+/// The local date-time skipped by the daylight saving time is moved forward to the end of the skipped period.
 ///
 /// [`SbrdDateTime`]: ../../value/type.SbrdDateTime.html
-/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+/// [`DataValue::DateTime`]: ../../value/enum.DataValue.html#variant.DateTime
+/// [`SbrdZonedDateTime`]: ../../value/type.SbrdZonedDateTime.html
+/// [`DataValue::ZonedDateTime`]: ../../value/enum.DataValue.html#variant.ZonedDateTime
 /// [`format::strftime` module]: https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html#specifiers
 /// [`DATE_TIME_DEFAULT_FORMAT`]: ../../value/constant.DATE_TIME_DEFAULT_FORMAT.html
 #[derive(Debug, PartialEq, Clone)]
 pub struct DateTimeGenerator {
-    nullable: bool,
-    format: String,
-    range: ValueBound<SbrdDateTime>,
+    nullable: Nullable,
+    format: Option<String>,
+    range: ValueBound<ScriptableBound<SbrdDateTime>>,
+    timezones: Option<Vec<(Weight, GeneratorTimeZone)>>,
+}
+
+/// Time zone of the generated date-time
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum GeneratorTimeZone {
+    /// Fixed offset such as "+09:00"
+    Fixed(FixedOffset),
+    /// Time zone in the IANA time zone database such as "Asia/Tokyo"
+    Named(Tz),
+}
+
+impl GeneratorTimeZone {
+    /// Max minutes to search the end of the period skipped by the daylight saving time
+    const MAX_SKIPPED_MINUTES: i64 = 2 * 24 * 60;
+
+    /// Parse a fixed offset such as "+09:00", "-0530" and "Z", otherwise parse as a name in the IANA time zone database
+    fn parse(zone: &str) -> Result<GeneratorTimeZone, BuildError> {
+        let fail_parse = |message: &str| {
+            BuildError::FailParseValue(
+                zone.to_string(),
+                "TimeZone".to_string(),
+                message.to_string(),
+            )
+        };
+
+        if zone == "Z" {
+            return Ok(GeneratorTimeZone::Fixed(FixedOffset::east(0)));
+        }
+
+        let sign = match zone.chars().next() {
+            Some('+') => 1,
+            Some('-') => -1,
+            _ => {
+                return zone
+                    .parse::<Tz>()
+                    .map(GeneratorTimeZone::Named)
+                    .map_err(|e| fail_parse(&e));
+            }
+        };
+        let digits = zone[1..].replacen(':', "", 1);
+        if !(digits.len() == 2 || digits.len() == 4) || !digits.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(fail_parse("offset must be the form of \"+hh:mm\""));
+        }
+        let hours = digits[0..2]
+            .parse::<i32>()
+            .map_err(|e| fail_parse(&e.to_string()))?;
+        let minutes = match digits.get(2..4) {
+            None => 0,
+            Some(minutes) => minutes
+                .parse::<i32>()
+                .map_err(|e| fail_parse(&e.to_string()))?,
+        };
+        if minutes >= 60 {
+            return Err(fail_parse("minutes of the offset must be less than 60"));
+        }
+
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+            .map(GeneratorTimeZone::Fixed)
+            .ok_or_else(|| fail_parse("offset is out of range"))
+    }
+
+    /// Convert the local date-time in this time zone into the timestamp.
+    /// If the local date-time is ambiguous, then use the earliest or the latest.
+    fn timestamp_of_local(&self, local: &SbrdDateTime, earliest: bool) -> Option<i64> {
+        match self {
+            GeneratorTimeZone::Fixed(offset) => Self::resolve_local(offset, local, earliest),
+            GeneratorTimeZone::Named(tz) => Self::resolve_local(tz, local, earliest),
+        }
+    }
+
+    fn resolve_local<Z: TimeZone>(zone: &Z, local: &SbrdDateTime, earliest: bool) -> Option<i64> {
+        // the local date-time skipped by the daylight saving time is moved forward to the end of the skipped period
+        for minutes in 0..=Self::MAX_SKIPPED_MINUTES {
+            match zone.from_local_datetime(&(*local + Duration::minutes(minutes))) {
+                LocalResult::None => continue,
+                LocalResult::Single(v) => return Some(v.timestamp()),
+                LocalResult::Ambiguous(earliest_value, latest_value) => {
+                    return Some(if earliest {
+                        earliest_value.timestamp()
+                    } else {
+                        latest_value.timestamp()
+                    })
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Convert the timestamp into the date-time with the offset in this time zone
+    fn date_time_at(&self, timestamp: i64) -> Option<SbrdZonedDateTime> {
+        let utc = SbrdDateTime::from_timestamp_opt(timestamp, 0)?;
+        let offset = match self {
+            GeneratorTimeZone::Fixed(offset) => *offset,
+            GeneratorTimeZone::Named(tz) => tz.offset_from_utc_datetime(&utc).fix(),
+        };
+
+        Some(SbrdZonedDateTime::from_utc(utc, offset))
+    }
 }
 
 impl<R: Randomizer + ?Sized> GeneratorBase<R> for DateTimeGenerator {
@@ -31,6 +146,7 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for DateTimeGenerator {
             nullable,
             range,
             format,
+            timezone,
             ..
         } = builder;
 
@@ -40,36 +156,61 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for DateTimeGenerator {
 
         let default_range = Self::default_range();
         let _range = match range {
-            None => default_range,
-            Some(r) => r
-                .try_convert_with(|s| {
-                    SbrdDateTime::parse_from_str(&s.to_parse_string(), DATE_TIME_DEFAULT_FORMAT)
-                        .map_err(|e| {
-                            BuildError::FailParseValue(
-                                s.to_parse_string(),
-                                "DateTime".to_string(),
-                                e.to_string(),
-                            )
+            None => ScriptableBound::fixed_range(default_range),
+            Some(r) => ScriptableBound::parse_range(r, |s| {
+                SbrdDateTime::parse_from_str(&s.to_parse_string(), DATE_TIME_DEFAULT_FORMAT)
+                    .map_err(|e| {
+                        BuildError::FailParseValue(
+                            s.to_parse_string(),
+                            "DateTime".to_string(),
+                            e.to_string(),
+                        )
+                    })
+            })
+            .map(|range| {
+                // If it is not specified so that it can be generated in an appropriate range, use the default boundary.
+                range.without_no_bound_from_other(ScriptableBound::fixed_range(default_range))
+            })?,
+        };
+        ScriptableBound::check_range(&_range)?;
+
+        let timezones = match timezone {
+            None => None,
+            Some(TimeZoneOption::Zone(zone)) => Some(vec![(1, GeneratorTimeZone::parse(&zone)?)]),
+            Some(TimeZoneOption::Weighted(zones)) => {
+                if zones.is_empty() {
+                    return Err(BuildError::InvalidValue(
+                        "timezone must have at least one time zone".to_string(),
+                    ));
+                }
+                if zones.iter().all(|zone| zone.get_weight() == 0) {
+                    return Err(BuildError::AllWeightsZero);
+                }
+
+                Some(
+                    zones
+                        .iter()
+                        .map(|zone| {
+                            Ok((
+                                zone.get_weight(),
+                                GeneratorTimeZone::parse(zone.get_zone())?,
+                            ))
                         })
-                })
-                .map(|range| {
-                    // If it is not specified so that it can be generated in an appropriate range, use the default boundary.
-                    range.without_no_bound_from_other(default_range)
-                })?,
+                        .collect::<Result<Vec<(Weight, GeneratorTimeZone)>, BuildError>>()?,
+                )
+            }
         };
-        if _range.is_empty() {
-            return Err(BuildError::RangeEmpty(_range.convert_into()));
-        }
 
         Ok(Self {
             nullable,
-            format: format.unwrap_or_else(|| DATE_TIME_DEFAULT_FORMAT.to_string()),
+            format,
             range: _range,
+            timezones,
         })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
@@ -77,32 +218,112 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for DateTimeGenerator {
         rng: &mut R,
         context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError> {
-        let timestamp_range = self.range.convert_with(|date_time| date_time.timestamp());
-        let timestamp_value = rng.gen_range(timestamp_range);
-        let date_time_value =
-            SbrdDateTime::from_timestamp_opt(timestamp_value, 0).ok_or_else(|| {
-                GenerateError::FailGenerate(format!(
-                    "Fail parse date time from timestamp: {}",
-                    timestamp_value
-                ))
+        let range =
+            ScriptableBound::resolve_range(&self.range, rng, context, |evaluator, script| {
+                eval_date_bound(
+                    evaluator,
+                    script,
+                    |s| SbrdDateTime::parse_from_str(s, DATE_TIME_DEFAULT_FORMAT).ok(),
+                    |evaluator, script| evaluator.eval_date_time(script),
+                )
             })?;
+        let timezone = match &self.timezones {
+            None => None,
+            Some(timezones) => Some(
+                timezones
+                    .choose_weighted(rng, |item| item.0)
+                    .map_err(|err| GenerateError::FailGenerate(err.to_string()))?
+                    .1,
+            ),
+        };
 
-        let evaluator = Evaluator::new(context);
-        let format = evaluator.format_script(&self.format).map_err(|e| {
-            GenerateError::FailEval(
-                e,
-                self.format.to_string(),
-                context
-                    .clone()
-                    .into_iter()
-                    .map(|(k, v)| (k.to_string(), v))
-                    .collect::<DataValueMap<String>>(),
-            )
-        })?;
+        let timestamp_range = match &timezone {
+            None => range.convert_with(|date_time| date_time.timestamp()),
+            Some(timezone) => {
+                let to_timestamp = |date_time: &Option<SbrdDateTime>, earliest: bool| {
+                    date_time
+                        .as_ref()
+                        .map(|date_time| {
+                            timezone
+                                .timestamp_of_local(date_time, earliest)
+                                .ok_or_else(|| {
+                                    GenerateError::FailGenerate(format!(
+                                        "Fail convert date time {} in the time zone",
+                                        date_time
+                                    ))
+                                })
+                        })
+                        .transpose()
+                };
 
-        Ok(DataValue::String(
-            date_time_value.format(&format).to_string(),
-        ))
+                ValueBound::new(
+                    to_timestamp(range.get_start(), true)?,
+                    to_timestamp(range.get_end(), false)?.map(|end| (range.is_include_end(), end)),
+                )
+            }
+        };
+        if timestamp_range.is_empty() {
+            return Err(GenerateError::RangeEmpty(
+                range.convert_with(|date_time| DataValue::DateTime(date_time, None)),
+            ));
+        }
+        let timestamp_value = rng.gen_range(timestamp_range);
+        let fail_from_timestamp = || {
+            GenerateError::FailGenerate(format!(
+                "Fail parse date time from timestamp: {}",
+                timestamp_value
+            ))
+        };
+        let date_time_value = match &timezone {
+            None => DataValue::DateTime(
+                SbrdDateTime::from_timestamp_opt(timestamp_value, 0)
+                    .ok_or_else(fail_from_timestamp)?,
+                None,
+            ),
+            Some(timezone) => DataValue::ZonedDateTime(
+                timezone
+                    .date_time_at(timestamp_value)
+                    .ok_or_else(fail_from_timestamp)?,
+                None,
+            ),
+        };
+
+        let format = match &self.format {
+            None => None,
+            Some(format) => {
+                let evaluator = Evaluator::new(context);
+                let format = evaluator.format_script(format).map_err(|e| {
+                    GenerateError::FailEval(
+                        e,
+                        format.to_string(),
+                        context
+                            .clone()
+                            .into_iter()
+                            .map(|(k, v)| (k.to_string(), v))
+                            .collect::<DataValueMap<String>>(),
+                    )
+                })?;
+                // check the format is available before writing
+                let checked = match &date_time_value {
+                    DataValue::DateTime(v, _) => write!(String::new(), "{}", v.format(&format)),
+                    DataValue::ZonedDateTime(v, _) => {
+                        write!(String::new(), "{}", v.format(&format))
+                    }
+                    _ => unreachable!(),
+                };
+                checked.map_err(|_| {
+                    GenerateError::FailGenerate(format!("Invalid format \"{}\"", format))
+                })?;
+
+                Some(format)
+            }
+        };
+
+        Ok(match date_time_value {
+            DataValue::DateTime(v, _) => DataValue::DateTime(v, format),
+            DataValue::ZonedDateTime(v, _) => DataValue::ZonedDateTime(v, format),
+            _ => unreachable!(),
+        })
     }
 }
 
@@ -1,16 +1,19 @@
-use crate::builder::{GeneratorBuilder, ValueBound};
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
 use crate::error::{BuildError, GenerateError};
+use crate::generator::scriptable_bound::ScriptableBound;
 use crate::generator::{GeneratorBase, Randomizer};
 use crate::value::{DataValue, DataValueMap, SbrdInt};
 use crate::GeneratorType;
 
 /// The generator with generate [`DataValue::Int`] value with range of generated value.
 ///
+/// The bound of the range can be a script such as "{key}" evaluated while generating.
+///
 /// [`DataValue::Int`]: ../../value/enum.DataValue.html#variant.Int
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct IntGenerator {
-    nullable: bool,
-    range: ValueBound<SbrdInt>,
+    nullable: Nullable,
+    range: ValueBound<ScriptableBound<SbrdInt>>,
 }
 
 impl<R: Randomizer + ?Sized> GeneratorBase<R> for IntGenerator {
@@ -31,8 +34,8 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for IntGenerator {
 
         let default_range = Self::default_range();
         let _range = match range {
-            None => default_range,
-            Some(r) => r.try_convert_with(|s| {
+            None => ScriptableBound::fixed_range(default_range),
+            Some(r) => ScriptableBound::parse_range(r, |s| {
                 s.to_parse_string().parse::<SbrdInt>().map_err(|e| {
                     BuildError::FailParseValue(
                         s.to_parse_string(),
@@ -42,9 +45,7 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for IntGenerator {
                 })
             })?,
         };
-        if _range.is_empty() {
-            return Err(BuildError::RangeEmpty(_range.convert_into()));
-        }
+        ScriptableBound::check_range(&_range)?;
 
         Ok(Self {
             nullable,
@@ -52,16 +53,20 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for IntGenerator {
         })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
         &self,
         rng: &mut R,
-        _context: &DataValueMap<&str>,
+        context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError> {
-        let v: SbrdInt = rng.gen_range(self.range);
+        let range =
+            ScriptableBound::resolve_range(&self.range, rng, context, |evaluator, script| {
+                evaluator.eval_int(script)
+            })?;
+        let v: SbrdInt = rng.gen_range(range);
 
         Ok(DataValue::Int(v))
     }
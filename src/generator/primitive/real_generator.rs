@@ -1,16 +1,19 @@
-use crate::builder::{GeneratorBuilder, ValueBound};
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
 use crate::error::{BuildError, GenerateError};
+use crate::generator::scriptable_bound::ScriptableBound;
 use crate::generator::{GeneratorBase, Randomizer};
 use crate::value::{DataValue, DataValueMap, SbrdReal};
 use crate::GeneratorType;
 
 /// The generator with generate [`DataValue::Real`] value with range of generated value.
 ///
+/// The bound of the range can be a script such as "{key}" evaluated while generating.
+///
 /// [`DataValue::Real`]: ../../value/enum.DataValue.html#variant.Real
 #[derive(Debug, PartialEq, Clone)]
 pub struct RealGenerator {
-    nullable: bool,
-    range: ValueBound<SbrdReal>,
+    nullable: Nullable,
+    range: ValueBound<ScriptableBound<SbrdReal>>,
 }
 
 impl<R: Randomizer + ?Sized> GeneratorBase<R> for RealGenerator {
@@ -31,25 +34,22 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for RealGenerator {
 
         let default_range = Self::default_range();
         let _range = match range {
-            None => default_range,
-            Some(r) => r
-                .try_convert_with(|s| {
-                    s.to_parse_string().parse::<SbrdReal>().map_err(|e| {
-                        BuildError::FailParseValue(
-                            s.to_parse_string(),
-                            "Real".to_string(),
-                            e.to_string(),
-                        )
-                    })
+            None => ScriptableBound::fixed_range(default_range),
+            Some(r) => ScriptableBound::parse_range(r, |s| {
+                s.to_parse_string().parse::<SbrdReal>().map_err(|e| {
+                    BuildError::FailParseValue(
+                        s.to_parse_string(),
+                        "Real".to_string(),
+                        e.to_string(),
+                    )
                 })
-                .map(|range| {
-                    // 範囲指定がないと[0, 1)で生成されてしまうため上限下限を設定する
-                    range.without_no_bound_from_other(default_range)
-                })?,
+            })
+            .map(|range| {
+                // 範囲指定がないと[0, 1)で生成されてしまうため上限下限を設定する
+                range.without_no_bound_from_other(ScriptableBound::fixed_range(default_range))
+            })?,
         };
-        if _range.is_empty() {
-            return Err(BuildError::RangeEmpty(_range.convert_into()));
-        }
+        ScriptableBound::check_range(&_range)?;
 
         Ok(Self {
             nullable,
@@ -57,16 +57,20 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for RealGenerator {
         })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
         &self,
         rng: &mut R,
-        _context: &DataValueMap<&str>,
+        context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError> {
-        let real = rng.gen_range(self.range);
+        let range =
+            ScriptableBound::resolve_range(&self.range, rng, context, |evaluator, script| {
+                evaluator.eval_real(script)
+            })?;
+        let real = rng.gen_range(range);
 
         Ok(DataValue::Real(real))
     }
@@ -1,26 +1,29 @@
-use crate::builder::{GeneratorBuilder, ValueBound};
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
 use crate::error::{BuildError, GenerateError};
 use crate::eval::Evaluator;
+use crate::generator::scriptable_bound::{eval_date_bound, ScriptableBound};
 use crate::generator::{GeneratorBase, Randomizer};
 use crate::value::{DataValue, DataValueMap, SbrdTime, TIME_DEFAULT_FORMAT};
 use crate::GeneratorType;
 use chrono::Duration;
+use std::fmt::Write;
 use std::ops::AddAssign;
 
-/// The generator with generate [`SbrdTime`] value as [`DataValue::String`] with the format
+/// The generator with generate [`SbrdTime`] value as [`DataValue::Time`] with the format applied when writing
 ///
 /// See [`format::strftime` module] for more information on `format` option.
 /// The default for `format` and the format when parsing is [`TIME_DEFAULT_FORMAT`].
+/// The bound of the range can be a script such as "{key}" evaluated while generating.
 ///
 /// [`SbrdTime`]: ../../value/type.SbrdTime.html
-/// [`DataValue::String`]: ../../value/enum.DataValue.html#variant.String
+/// [`DataValue::Time`]: ../../value/enum.DataValue.html#variant.Time
 /// [`format::strftime` module]: https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html#specifiers
 /// [`TIME_DEFAULT_FORMAT`]: ../../value/constant.TIME_DEFAULT_FORMAT.html
 #[derive(Debug, PartialEq, Clone)]
 pub struct TimeGenerator {
-    nullable: bool,
-    format: String,
-    range: ValueBound<SbrdTime>,
+    nullable: Nullable,
+    format: Option<String>,
+    range: ValueBound<ScriptableBound<SbrdTime>>,
 }
 
 impl<R: Randomizer + ?Sized> GeneratorBase<R> for TimeGenerator {
@@ -42,37 +45,32 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for TimeGenerator {
 
         let default_range = Self::default_range();
         let _range = match range {
-            None => default_range,
-            Some(r) => r
-                .try_convert_with(|s| {
-                    SbrdTime::parse_from_str(&s.to_parse_string(), TIME_DEFAULT_FORMAT).map_err(
-                        |e| {
-                            BuildError::FailParseValue(
-                                s.to_parse_string(),
-                                "Time".to_string(),
-                                e.to_string(),
-                            )
-                        },
+            None => ScriptableBound::fixed_range(default_range),
+            Some(r) => ScriptableBound::parse_range(r, |s| {
+                SbrdTime::parse_from_str(&s.to_parse_string(), TIME_DEFAULT_FORMAT).map_err(|e| {
+                    BuildError::FailParseValue(
+                        s.to_parse_string(),
+                        "Time".to_string(),
+                        e.to_string(),
                     )
                 })
-                .map(|range| {
-                    // If it is not specified so that it can be generated in an appropriate range, use the default boundary.
-                    range.without_no_bound_from_other(default_range)
-                })?,
+            })
+            .map(|range| {
+                // If it is not specified so that it can be generated in an appropriate range, use the default boundary.
+                range.without_no_bound_from_other(ScriptableBound::fixed_range(default_range))
+            })?,
         };
-        if _range.is_empty() {
-            return Err(BuildError::RangeEmpty(_range.convert_into()));
-        }
+        ScriptableBound::check_range(&_range)?;
 
         Ok(Self {
             nullable,
-            format: format.unwrap_or_else(|| TIME_DEFAULT_FORMAT.to_string()),
+            format,
             range: _range,
         })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
@@ -80,36 +78,50 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for TimeGenerator {
         rng: &mut R,
         context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError> {
-        let upper_bound = self
-            .range
-            .get_end()
-            .expect("Exist upper bound is not exist");
-        let lower_bound = self
-            .range
-            .get_start()
-            .expect("Exist lower bound is not exist");
+        let range =
+            ScriptableBound::resolve_range(&self.range, rng, context, |evaluator, script| {
+                eval_date_bound(
+                    evaluator,
+                    script,
+                    |s| SbrdTime::parse_from_str(s, TIME_DEFAULT_FORMAT).ok(),
+                    |evaluator, script| evaluator.eval_time(script),
+                )
+            })?;
+        let upper_bound = range.get_end().expect("Exist upper bound is not exist");
+        let lower_bound = range.get_start().expect("Exist lower bound is not exist");
         let since_duration_seconds = upper_bound.signed_duration_since(lower_bound).num_seconds();
         let diff_seconds = rng.gen_range(ValueBound::new(
             Some(0),
-            Some((self.range.is_include_end(), since_duration_seconds)),
+            Some((range.is_include_end(), since_duration_seconds)),
         ));
         let mut time_value = lower_bound;
         time_value.add_assign(Duration::seconds(diff_seconds));
 
-        let evaluator = Evaluator::new(context);
-        let format = evaluator.format_script(&self.format).map_err(|e| {
-            GenerateError::FailEval(
-                e,
-                self.format.to_string(),
-                context
-                    .clone()
-                    .into_iter()
-                    .map(|(k, v)| (k.to_string(), v))
-                    .collect::<DataValueMap<String>>(),
-            )
-        })?;
+        let format = match &self.format {
+            None => None,
+            Some(format) => {
+                let evaluator = Evaluator::new(context);
+                let format = evaluator.format_script(format).map_err(|e| {
+                    GenerateError::FailEval(
+                        e,
+                        format.to_string(),
+                        context
+                            .clone()
+                            .into_iter()
+                            .map(|(k, v)| (k.to_string(), v))
+                            .collect::<DataValueMap<String>>(),
+                    )
+                })?;
+                // check the format is available before writing
+                write!(String::new(), "{}", time_value.format(&format)).map_err(|_| {
+                    GenerateError::FailGenerate(format!("Invalid format \"{}\"", format))
+                })?;
+
+                Some(format)
+            }
+        };
 
-        Ok(DataValue::String(time_value.format(&format).to_string()))
+        Ok(DataValue::Time(time_value, format))
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::builder::GeneratorBuilder;
+use crate::builder::{GeneratorBuilder, Nullable};
 use crate::error::{BuildError, GenerateError};
 use crate::generator::{CasedChild, CasedChildGeneratorBase, GeneratorBase, Randomizer};
 use crate::value::{DataValue, DataValueMap};
@@ -11,7 +11,7 @@ use crate::GeneratorType;
 /// [`Option::Some`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.Some
 /// [`Option::None`]: https://doc.rust-lang.org/std/option/enum.Option.html#variant.None
 pub struct CaseWhenGenerator<R: Randomizer + ?Sized> {
-    nullable: bool,
+    nullable: Nullable,
     children: Vec<CasedChild<R>>,
 }
 
@@ -45,8 +45,8 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for CaseWhenGenerator<R> {
         })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
@@ -54,6 +54,21 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for CaseWhenGenerator<R> {
         rng: &mut R,
         context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError> {
-        self.generate_from_children(rng, context)
+        self.generate_from_children(rng, context, None)
+    }
+
+    fn generate_without_null_at(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+        index: u64,
+    ) -> Result<DataValue, GenerateError> {
+        self.generate_from_children(rng, context, Some(index))
+    }
+
+    fn reset(&self) {
+        for (_, child) in self.children.iter() {
+            child.reset();
+        }
     }
 }
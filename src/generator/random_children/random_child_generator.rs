@@ -1,4 +1,4 @@
-use crate::builder::GeneratorBuilder;
+use crate::builder::{GeneratorBuilder, Nullable};
 use crate::error::{BuildError, GenerateError};
 use crate::generator::{GeneratorBase, Randomizer, WeightedChild, WeightedChildGeneratorBase};
 use crate::value::{DataValue, DataValueMap};
@@ -6,7 +6,7 @@ use crate::GeneratorType;
 
 /// The generator with picked out the value from the input values or the value generated by picked out child generator
 pub struct RandomChildGenerator<R: Randomizer + ?Sized> {
-    nullable: bool,
+    nullable: Nullable,
     selectable_values: Vec<WeightedChild<R>>,
 }
 
@@ -40,8 +40,8 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for RandomChildGenerator<R> {
         })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
@@ -49,6 +49,21 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for RandomChildGenerator<R> {
         rng: &mut R,
         context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError> {
-        self.generate_from_children(rng, context)
+        self.generate_from_children(rng, context, None)
+    }
+
+    fn generate_without_null_at(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+        index: u64,
+    ) -> Result<DataValue, GenerateError> {
+        self.generate_from_children(rng, context, Some(index))
+    }
+
+    fn reset(&self) {
+        for (_, child) in self.selectable_values.iter() {
+            child.reset();
+        }
     }
 }
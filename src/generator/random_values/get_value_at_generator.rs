@@ -1,4 +1,4 @@
-use crate::builder::GeneratorBuilder;
+use crate::builder::{GeneratorBuilder, Nullable};
 use crate::error::{BuildError, GenerateError};
 use crate::eval::{EvalError, Evaluator};
 use crate::generator::{GeneratorBase, Randomizer, ValueGeneratorBase};
@@ -9,7 +9,7 @@ use std::str::FromStr;
 
 /// The generator that gets a T value from the values with the value evaluated by `script` as the index of 0-index.
 pub struct GetValueAtGenerator<T> {
-    nullable: bool,
+    nullable: Nullable,
     script: String,
     selectable_values: Vec<T>,
 }
@@ -56,16 +56,16 @@ impl<R: Randomizer + ?Sized, T: ForGetValueAtGeneratorType> GeneratorBase<R>
         }
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
         &self,
-        _rng: &mut R,
+        rng: &mut R,
         context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError> {
-        let evaluator = Evaluator::new(context);
+        let evaluator = Evaluator::new(context).with_randomizer(rng);
         let index: usize = evaluator
             .eval_int(&self.script)
             .and_then(|v| {
@@ -100,7 +100,7 @@ impl<R: Randomizer + ?Sized, T: ForGetValueAtGeneratorType> GeneratorBase<R>
 }
 
 /// Helper traits for generators that the generate value
-pub trait ForGetValueAtGeneratorType {
+pub trait ForGetValueAtGeneratorType: Send + Sync {
     /// The type of the generator
     fn get_generator_type() -> GeneratorType;
 
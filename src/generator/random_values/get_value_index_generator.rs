@@ -1,4 +1,4 @@
-use crate::builder::GeneratorBuilder;
+use crate::builder::{GeneratorBuilder, Nullable};
 use crate::error::{BuildError, GenerateError};
 use crate::generator::{GeneratorBase, Randomizer, ValueGeneratorBase};
 use crate::value::{DataValue, DataValueMap, SbrdInt};
@@ -6,7 +6,7 @@ use crate::GeneratorType;
 
 /// The generator that get available index  of 0-index for value from the values.
 pub struct GetValueIndexGenerator {
-    nullable: bool,
+    nullable: Nullable,
     values_count: usize,
 }
 
@@ -43,8 +43,8 @@ impl<R: Randomizer + ?Sized> GeneratorBase<R> for GetValueIndexGenerator {
         })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
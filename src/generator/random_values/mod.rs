@@ -2,8 +2,10 @@
 
 pub use get_value_at_generator::*;
 pub use get_value_index_generator::*;
+pub use reference_generator::*;
 pub use select_generator::*;
 
 mod get_value_at_generator;
 mod get_value_index_generator;
+mod reference_generator;
 mod select_generator;
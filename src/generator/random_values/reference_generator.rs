@@ -0,0 +1,218 @@
+use crate::builder::{GeneratorBuilder, Nullable, ValueReference};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::{GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap, SbrdInt};
+use crate::GeneratorType;
+use rand::seq::SliceRandom;
+use std::sync::{Mutex, PoisonError};
+
+/// The generator that pick out a value at the referenced key from the records as the values.
+///
+/// The records are objects at the key `values`.
+/// When generating tables, the records are replaced with the generated records of the referenced table.
+/// With the cardinality, the picked record depends on the counts of referencing in the order of generation,
+/// so the schema with the cardinality generates the values sets in order on a thread.
+/// The records referenced fewer than the lower bound of the cardinality are picked out first,
+/// but the lower bound is not guaranteed if the values sets are too few to reference every record as many times or the record has the weight 0.
+/// The counts of referencing are forgotten at the start of each generation from a seed.
+///
+/// # Examples
+/// ```
+/// fn main() {
+///     use sbrd_gen::builder::{GeneratorBuilder, ValueBound, ValueReference};
+///     use sbrd_gen::generator::SeededRandomizer;
+///     use sbrd_gen::value::{DataValue, DataValueMap};
+///     use sbrd_gen::{Schema, SchemaBuilder};
+///
+///     let records = (1..=3)
+///         .map(|id| DataValue::Object(DataValueMap::from([("id".to_string(), DataValue::Int(id))])))
+///         .collect();
+///     let reference = ValueReference::new("users", "id").cardinality(ValueBound::new(Some(1), Some((true, 2))));
+///     let builders = vec![GeneratorBuilder::new_reference(reference, Some(records)).into_parent("user_id")];
+///     let schema: Schema<SeededRandomizer> =
+///         SchemaBuilder::new(vec!["user_id".to_string()], builders).build().unwrap();
+///
+///     let to_string = |seed| format!("{:?}", schema.generate_from_seed(seed, 6).unwrap());
+///     assert_eq!(to_string(42), to_string(42));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ReferenceGenerator {
+    nullable: Nullable,
+    values: Vec<DataValue>,
+    weights: Option<Vec<u64>>,
+    min_count: u64,
+    max_count: Option<u64>,
+    referenced_counts: Mutex<Vec<u64>>,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for ReferenceGenerator {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            values,
+            reference,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::Reference {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let reference =
+            reference.ok_or_else(|| BuildError::NotExistValueOf("reference".to_string()))?;
+        let records = values.unwrap_or_default();
+
+        let mut _values = Vec::new();
+        let mut _weights = Vec::new();
+        for record in records.into_iter() {
+            let mut record = match record {
+                DataValue::Object(record) => record,
+                _ => {
+                    return Err(BuildError::InvalidValue(format!(
+                        "{} is not a record for reference",
+                        record
+                    )))
+                }
+            };
+
+            let value = record
+                .remove(reference.get_key())
+                .ok_or_else(|| BuildError::NotExistValueOf(reference.get_key().to_string()))?;
+            _values.push(value);
+
+            if let Some(weight_key) = reference.get_weight_key() {
+                let weight = match record.remove(weight_key) {
+                    None => return Err(BuildError::NotExistValueOf(weight_key.to_string())),
+                    Some(DataValue::Int(weight)) if weight >= 0 => weight as u64,
+                    Some(weight) => {
+                        return Err(BuildError::InvalidValue(format!(
+                            "{} is not a weight at the key {}",
+                            weight, weight_key
+                        )))
+                    }
+                };
+                _weights.push(weight);
+            }
+        }
+
+        let weights = match reference.get_weight_key() {
+            None => None,
+            Some(_) => {
+                if !_weights.is_empty() && _weights.iter().all(|weight| *weight == 0) {
+                    return Err(BuildError::AllWeightsZero);
+                }
+
+                Some(_weights)
+            }
+        };
+
+        let (min_count, max_count) = Self::build_cardinality(&reference)?;
+
+        Ok(Self {
+            nullable,
+            referenced_counts: Mutex::new(vec![0; _values.len()]),
+            values: _values,
+            weights,
+            min_count,
+            max_count,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        _context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        let mut referenced_counts = self
+            .referenced_counts
+            .lock()
+            .map_err(|e| GenerateError::FailGenerate(e.to_string()))?;
+        let index = {
+            let is_pickable = |index: &usize| match &self.weights {
+                None => true,
+                Some(weights) => weights[*index] != 0,
+            };
+
+            // the records referenced fewer than the minimum count take precedence
+            let mut candidates: Vec<usize> = (0..self.values.len())
+                .filter(|index| referenced_counts[*index] < self.min_count)
+                .filter(is_pickable)
+                .collect();
+            if candidates.is_empty() {
+                candidates = (0..self.values.len())
+                    .filter(|index| match self.max_count {
+                        None => true,
+                        Some(max_count) => referenced_counts[*index] < max_count,
+                    })
+                    .filter(is_pickable)
+                    .collect();
+            }
+
+            let picked = match &self.weights {
+                None => candidates.choose(rng),
+                Some(weights) => candidates
+                    .choose_weighted(rng, |index| weights[*index])
+                    .ok(),
+            };
+
+            *picked.ok_or_else(|| {
+                GenerateError::FailGenerate(
+                    "Not exist a record which can be referenced".to_string(),
+                )
+            })?
+        };
+
+        referenced_counts[index] += 1;
+
+        Ok(self.values[index].clone())
+    }
+
+    fn reset(&self) {
+        self.referenced_counts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .fill(0);
+    }
+}
+
+impl ReferenceGenerator {
+    fn build_cardinality(reference: &ValueReference) -> Result<(u64, Option<u64>), BuildError> {
+        let cardinality = match reference.get_cardinality() {
+            None => return Ok((0, None)),
+            Some(cardinality) => cardinality,
+        };
+
+        if cardinality.is_empty() {
+            return Err(BuildError::RangeEmpty(cardinality.convert_into()));
+        }
+
+        let min_count = cardinality.get_start().unwrap_or(0);
+        let max_count = cardinality.get_end().map(|end| {
+            if cardinality.is_include_end() {
+                end
+            } else {
+                end - 1
+            }
+        });
+        if min_count < 0 || max_count.map(|max_count| max_count < 0).unwrap_or(false) {
+            return Err(BuildError::InvalidValue(format!(
+                "{} is not a range of count",
+                cardinality
+            )));
+        }
+
+        Ok((
+            min_count as u64,
+            max_count.map(|max_count: SbrdInt| max_count as u64),
+        ))
+    }
+}
@@ -1,15 +1,18 @@
-use crate::builder::GeneratorBuilder;
+use crate::builder::{GeneratorBuilder, Nullable};
 use crate::error::{BuildError, GenerateError};
-use crate::generator::{GeneratorBase, Randomizer, ValueGeneratorBase};
+use crate::generator::{GeneratorBase, Randomizer, ValueGeneratorBase, WeightedValue};
 use crate::value::{DataValue, DataValueMap, SbrdInt, SbrdReal, SbrdString};
 use crate::GeneratorType;
 use rand::seq::SliceRandom;
 use std::str::FromStr;
 
 /// The generator with generate value as the type T from value's list as the type
+///
+/// The value is picked out with its weight. If all weights are 1, the value is picked out uniformly.
 pub struct SelectGenerator<T> {
-    nullable: bool,
-    selectable_values: Vec<T>,
+    nullable: Nullable,
+    selectable_values: Vec<WeightedValue<T>>,
+    is_weighted: bool,
 }
 
 impl<R: Randomizer + ?Sized, T: ForSelectGeneratorType> ValueGeneratorBase<R, T>
@@ -31,6 +34,7 @@ impl<R: Randomizer + ?Sized, T: ForSelectGeneratorType> GeneratorBase<R> for Sel
             chars,
             values,
             filepath,
+            weighted,
             ..
         } = builder;
 
@@ -38,17 +42,20 @@ impl<R: Randomizer + ?Sized, T: ForSelectGeneratorType> GeneratorBase<R> for Sel
             return Err(BuildError::InvalidType(generator_type));
         }
 
-        let selectable_values =
-            <Self as ValueGeneratorBase<R, T>>::build_selectable(chars, values, filepath)?;
+        let selectable_values = <Self as ValueGeneratorBase<R, T>>::build_weighted_selectable(
+            chars, values, filepath, weighted,
+        )?;
+        let is_weighted = selectable_values.iter().any(|item| item.0 != 1);
 
         Ok(Self {
             nullable,
             selectable_values,
+            is_weighted,
         })
     }
 
-    fn is_nullable(&self) -> bool {
-        self.nullable
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
     }
 
     fn generate_without_null(
@@ -56,15 +63,22 @@ impl<R: Randomizer + ?Sized, T: ForSelectGeneratorType> GeneratorBase<R> for Sel
         rng: &mut R,
         _context: &DataValueMap<&str>,
     ) -> Result<DataValue, GenerateError> {
-        self.selectable_values
-            .choose(rng)
-            .map(|v| v.to_data_value())
+        let picked = if self.is_weighted {
+            self.selectable_values
+                .choose_weighted(rng, |item| item.0)
+                .ok()
+        } else {
+            self.selectable_values.choose(rng)
+        };
+
+        picked
+            .map(|item| item.1.to_data_value())
             .ok_or_else(|| GenerateError::FailGenerate("Fail Select Value".to_string()))
     }
 }
 
 /// Helper traits for generators that the generate value
-pub trait ForSelectGeneratorType {
+pub trait ForSelectGeneratorType: Send + Sync {
     /// The type of the generator
     fn get_generator_type() -> GeneratorType;
 
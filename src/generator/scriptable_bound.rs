@@ -0,0 +1,126 @@
+//! Module for the bound of the `range` option which is evaluated while generating
+
+use crate::builder::ValueBound;
+use crate::error::{BuildError, GenerateError};
+use crate::eval::{EvalResult, Evaluator};
+use crate::generator::Randomizer;
+use crate::value::{DataValue, DataValueMap};
+
+/// Bound value of the `range` option.
+///
+/// A string value with the replace-key-syntax such as "{key}" is the script evaluated with the context while generating.
+/// Others are the fixed value parsed while building.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum ScriptableBound<T> {
+    /// Fixed value
+    Value(T),
+    /// Script evaluated with the context while generating
+    Script(String),
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for ScriptableBound<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScriptableBound::Value(v) => write!(f, "{}", v),
+            ScriptableBound::Script(script) => write!(f, "{:?}", script),
+        }
+    }
+}
+
+impl<T: Clone + PartialOrd + Into<DataValue>> ScriptableBound<T> {
+    /// Parse the `range` option. The bound which is not a script is parsed by the parser.
+    pub(crate) fn parse_range<F>(
+        range: ValueBound<DataValue>,
+        mut parse: F,
+    ) -> Result<ValueBound<ScriptableBound<T>>, BuildError>
+    where
+        F: FnMut(DataValue) -> Result<T, BuildError>,
+    {
+        range.try_convert_with(|v| match v {
+            DataValue::String(s) if s.contains('{') => Ok(ScriptableBound::Script(s)),
+            _ => parse(v).map(ScriptableBound::Value),
+        })
+    }
+
+    /// Check the range is not empty if all bounds are fixed
+    pub(crate) fn check_range(range: &ValueBound<ScriptableBound<T>>) -> Result<(), BuildError> {
+        match Self::to_fixed_range(range) {
+            Some(fixed_range) if fixed_range.is_empty() => {
+                Err(BuildError::RangeEmpty(fixed_range.convert_into()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Convert the fixed range into the range of the value
+    pub(crate) fn fixed_range(range: ValueBound<T>) -> ValueBound<ScriptableBound<T>> {
+        range.convert_with(ScriptableBound::Value)
+    }
+
+    /// Get the range of the value if all bounds are fixed
+    fn to_fixed_range(range: &ValueBound<ScriptableBound<T>>) -> Option<ValueBound<T>> {
+        range
+            .clone()
+            .try_convert_with(|b| match b {
+                ScriptableBound::Value(v) => Ok(v),
+                ScriptableBound::Script(_) => Err(()),
+            })
+            .ok()
+    }
+
+    /// Evaluate the scripts in the range with the context, and check the evaluated range is not empty
+    pub(crate) fn resolve_range<R, F>(
+        range: &ValueBound<ScriptableBound<T>>,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+        evaluate: F,
+    ) -> Result<ValueBound<T>, GenerateError>
+    where
+        R: Randomizer + ?Sized,
+        F: Fn(&Evaluator, &str) -> EvalResult<T>,
+    {
+        let resolved = match Self::to_fixed_range(range) {
+            Some(fixed_range) => return Ok(fixed_range),
+            None => {
+                let evaluator = Evaluator::new(context).with_randomizer(rng);
+                range.clone().try_convert_with(|b| match b {
+                    ScriptableBound::Value(v) => Ok(v),
+                    ScriptableBound::Script(script) => evaluate(&evaluator, &script).map_err(|e| {
+                        GenerateError::FailEval(
+                            e,
+                            script,
+                            context
+                                .iter()
+                                .map(|(k, v)| (k.to_string(), v.clone()))
+                                .collect::<DataValueMap<String>>(),
+                        )
+                    }),
+                })?
+            }
+        };
+        if resolved.is_empty() {
+            return Err(GenerateError::RangeEmpty(resolved.convert_into()));
+        }
+
+        Ok(resolved)
+    }
+}
+
+/// Evaluate the script for the bound of the date-time, date or time.
+/// The formatted script with the context is parsed, and if it fails, the script is evaluated as the expression such as `date_add(...)`.
+pub(crate) fn eval_date_bound<T, P, E>(
+    evaluator: &Evaluator,
+    script: &str,
+    parse: P,
+    eval: E,
+) -> EvalResult<T>
+where
+    P: Fn(&str) -> Option<T>,
+    E: Fn(&Evaluator, &str) -> EvalResult<T>,
+{
+    let formatted = evaluator.apply_value_context(script, false)?;
+    match parse(&formatted) {
+        Some(v) => Ok(v),
+        None => eval(evaluator, script),
+    }
+}
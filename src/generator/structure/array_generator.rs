@@ -0,0 +1,125 @@
+use crate::builder::{GeneratorBuilder, Nullable, ValueBound};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::{GeneratorBase, Randomizer, WeightedChild, WeightedChildGeneratorBase};
+use crate::value::{DataValue, DataValueMap, SbrdInt};
+use crate::GeneratorType;
+
+/// The generator with generate [`DataValue::Array`] consisting of the values generated by picked out child generator.
+/// The length of the array is picked out from the range.
+///
+/// [`DataValue::Array`]: ../../value/enum.DataValue.html#variant.Array
+pub struct ArrayGenerator<R: Randomizer + ?Sized> {
+    nullable: Nullable,
+    count_range: ValueBound<SbrdInt>,
+    selectable_values: Vec<WeightedChild<R>>,
+}
+
+impl<R: Randomizer + ?Sized> WeightedChildGeneratorBase<R> for ArrayGenerator<R> {
+    fn get_selectable(&self) -> &[WeightedChild<R>] {
+        &self.selectable_values
+    }
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for ArrayGenerator<R> {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            range,
+            children,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::Array {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let count_range = match range {
+            None => Self::default_range(),
+            Some(r) => r
+                .try_convert_with(|s| {
+                    s.to_parse_string().parse::<SbrdInt>().map_err(|e| {
+                        BuildError::FailParseValue(
+                            s.to_parse_string(),
+                            "Int".to_string(),
+                            e.to_string(),
+                        )
+                    })
+                })?
+                // the count needs the upper bound, so fill the missing end with the default end
+                .without_no_bound_from_other(ValueBound::new(
+                    Some(0),
+                    Self::default_range().get_end().map(|end| (true, end)),
+                )),
+        };
+        if let Some(s) = count_range.get_start() {
+            if s < &0 {
+                return Err(BuildError::InvalidValue(count_range.to_string()));
+            }
+        }
+        if count_range.is_empty() {
+            return Err(BuildError::RangeEmpty(count_range.convert_into()));
+        }
+
+        let selectable_values = Self::build_selectable(children)?;
+
+        Ok(Self {
+            nullable,
+            count_range,
+            selectable_values,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        self.generate_array(rng, context, None)
+    }
+
+    fn generate_without_null_at(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+        index: u64,
+    ) -> Result<DataValue, GenerateError> {
+        self.generate_array(rng, context, Some(index))
+    }
+
+    fn reset(&self) {
+        for (_, child) in self.selectable_values.iter() {
+            child.reset();
+        }
+    }
+}
+
+impl<R: Randomizer + ?Sized> ArrayGenerator<R> {
+    /// Generate the array. If the index is specified, generate as the value in the values set at the index.
+    fn generate_array(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+        index: Option<u64>,
+    ) -> Result<DataValue, GenerateError> {
+        let count = rng.gen_range(self.count_range);
+        let mut values = Vec::new();
+        for _ in 0..count {
+            values.push(self.generate_from_children(rng, context, index)?);
+        }
+
+        Ok(DataValue::Array(values))
+    }
+
+    /// default count range
+    fn default_range() -> ValueBound<SbrdInt> {
+        ValueBound::new(Some(1), Some((true, 15)))
+    }
+}
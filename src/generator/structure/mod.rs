@@ -0,0 +1,7 @@
+//! Module for generator with generate a structured value
+
+pub use array_generator::*;
+pub use object_generator::*;
+
+mod array_generator;
+mod object_generator;
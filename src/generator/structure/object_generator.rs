@@ -0,0 +1,104 @@
+use crate::builder::{ChildGeneratorBuilder, GeneratorBuilder, Nullable};
+use crate::error::{BuildError, GenerateError};
+use crate::generator::{generate_with_index, GeneratorBase, Randomizer};
+use crate::value::{DataValue, DataValueMap};
+use crate::GeneratorType;
+
+/// The generator with generate [`DataValue::Object`] consisting of the values generated by the keyed children.
+/// The children are generated from the top, and each child can refer to the values of the preceding children in addition to the context.
+///
+/// [`DataValue::Object`]: ../../value/enum.DataValue.html#variant.Object
+pub struct ObjectGenerator<R: Randomizer + ?Sized> {
+    nullable: Nullable,
+    children: Vec<(String, Box<dyn GeneratorBase<R>>)>,
+}
+
+impl<R: Randomizer + ?Sized> GeneratorBase<R> for ObjectGenerator<R> {
+    fn create(builder: GeneratorBuilder) -> Result<Self, BuildError>
+    where
+        Self: Sized,
+    {
+        let GeneratorBuilder {
+            generator_type,
+            nullable,
+            children,
+            ..
+        } = builder;
+
+        if generator_type != GeneratorType::Object {
+            return Err(BuildError::InvalidType(generator_type));
+        }
+
+        let children = match children {
+            None => return Err(BuildError::NotExistValueOf("children".to_string())),
+            Some(children) => children,
+        };
+
+        let mut _children: Vec<(String, Box<dyn GeneratorBase<R>>)> = Vec::new();
+        for child_builder in children.into_iter() {
+            let ChildGeneratorBuilder { key, builder, .. } = child_builder;
+            let key = key.ok_or_else(|| BuildError::NotExistValueOf("key".to_string()))?;
+            if _children.iter().any(|(k, _)| k == &key) {
+                return Err(BuildError::AlreadyExistKey(key));
+            }
+
+            _children.push((key, builder.build()?));
+        }
+
+        if _children.is_empty() {
+            return Err(BuildError::EmptySelectableChildren);
+        }
+
+        Ok(Self {
+            nullable,
+            children: _children,
+        })
+    }
+
+    fn get_nullable(&self) -> &Nullable {
+        &self.nullable
+    }
+
+    fn generate_without_null(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+    ) -> Result<DataValue, GenerateError> {
+        self.generate_object(rng, context, None)
+    }
+
+    fn generate_without_null_at(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+        index: u64,
+    ) -> Result<DataValue, GenerateError> {
+        self.generate_object(rng, context, Some(index))
+    }
+
+    fn reset(&self) {
+        for (_, child) in self.children.iter() {
+            child.reset();
+        }
+    }
+}
+
+impl<R: Randomizer + ?Sized> ObjectGenerator<R> {
+    /// Generate the object. If the index is specified, generate as the value in the values set at the index.
+    fn generate_object(
+        &self,
+        rng: &mut R,
+        context: &DataValueMap<&str>,
+        index: Option<u64>,
+    ) -> Result<DataValue, GenerateError> {
+        let mut child_context = context.clone();
+        let mut values = DataValueMap::new();
+        for (key, generator) in self.children.iter() {
+            let value = generate_with_index(generator.as_ref(), rng, &child_context, index)?;
+            child_context.insert(key, value.clone());
+            values.insert(key.to_string(), value);
+        }
+
+        Ok(DataValue::Object(values))
+    }
+}
@@ -18,6 +18,10 @@ pub enum GeneratorType {
     ///
     /// [`FormatGenerator`]: ../generator/build_string/struct.FormatGenerator.html
     Format,
+    /// Type for [`RegexGenerator`]
+    ///
+    /// [`RegexGenerator`]: ../generator/build_string/struct.RegexGenerator.html
+    Regex,
 
     //
     // distribution
@@ -26,6 +30,54 @@ pub enum GeneratorType {
     ///
     /// [`NormalGenerator`]: ../generator/distribution/struct.NormalGenerator.html
     DistNormal,
+    /// Type for [`UniformGenerator`]
+    ///
+    /// [`UniformGenerator`]: ../generator/distribution/struct.UniformGenerator.html
+    DistUniform,
+    /// Type for [`LogNormalGenerator`]
+    ///
+    /// [`LogNormalGenerator`]: ../generator/distribution/struct.LogNormalGenerator.html
+    DistLogNormal,
+    /// Type for [`ExponentialGenerator`]
+    ///
+    /// [`ExponentialGenerator`]: ../generator/distribution/struct.ExponentialGenerator.html
+    DistExponential,
+    /// Type for [`PoissonGenerator`]
+    ///
+    /// [`PoissonGenerator`]: ../generator/distribution/struct.PoissonGenerator.html
+    DistPoisson,
+    /// Type for [`BinomialGenerator`]
+    ///
+    /// [`BinomialGenerator`]: ../generator/distribution/struct.BinomialGenerator.html
+    DistBinomial,
+    /// Type for [`GeometricGenerator`]
+    ///
+    /// [`GeometricGenerator`]: ../generator/distribution/struct.GeometricGenerator.html
+    DistGeometric,
+    /// Type for [`GammaGenerator`]
+    ///
+    /// [`GammaGenerator`]: ../generator/distribution/struct.GammaGenerator.html
+    DistGamma,
+    /// Type for [`BetaGenerator`]
+    ///
+    /// [`BetaGenerator`]: ../generator/distribution/struct.BetaGenerator.html
+    DistBeta,
+    /// Type for [`WeibullGenerator`]
+    ///
+    /// [`WeibullGenerator`]: ../generator/distribution/struct.WeibullGenerator.html
+    DistWeibull,
+    /// Type for [`ParetoGenerator`]
+    ///
+    /// [`ParetoGenerator`]: ../generator/distribution/struct.ParetoGenerator.html
+    DistPareto,
+    /// Type for [`ZipfGenerator`]
+    ///
+    /// [`ZipfGenerator`]: ../generator/distribution/struct.ZipfGenerator.html
+    DistZipf,
+    /// Type for [`BernoulliGenerator`]
+    ///
+    /// [`BernoulliGenerator`]: ../generator/distribution/struct.BernoulliGenerator.html
+    DistBernoulli,
 
     //
     // evaluate
@@ -50,6 +102,41 @@ pub enum GeneratorType {
     /// [`EvalGenerator`]: ../generator/evaluate/struct.EvalGenerator.html
     /// [`DataValue::String`]: ../value/enum.DataValue.html#variant.String
     EvalString,
+    /// Type for [`EvalGenerator`] as  [`DataValue::DateTime`]
+    ///
+    /// [`EvalGenerator`]: ../generator/evaluate/struct.EvalGenerator.html
+    /// [`DataValue::DateTime`]: ../value/enum.DataValue.html#variant.DateTime
+    EvalDateTime,
+    /// Type for [`EvalGenerator`] as  [`DataValue::Date`]
+    ///
+    /// [`EvalGenerator`]: ../generator/evaluate/struct.EvalGenerator.html
+    /// [`DataValue::Date`]: ../value/enum.DataValue.html#variant.Date
+    EvalDate,
+    /// Type for [`EvalGenerator`] as  [`DataValue::Time`]
+    ///
+    /// [`EvalGenerator`]: ../generator/evaluate/struct.EvalGenerator.html
+    /// [`DataValue::Time`]: ../value/enum.DataValue.html#variant.Time
+    EvalTime,
+
+    //
+    // identifier
+    //
+    /// Type for [`UuidGenerator`] as UUID version 4
+    ///
+    /// [`UuidGenerator`]: ../generator/identifier/struct.UuidGenerator.html
+    UuidV4,
+    /// Type for [`UuidGenerator`] as UUID version 7
+    ///
+    /// [`UuidGenerator`]: ../generator/identifier/struct.UuidGenerator.html
+    UuidV7,
+    /// Type for [`UlidGenerator`]
+    ///
+    /// [`UlidGenerator`]: ../generator/identifier/struct.UlidGenerator.html
+    Ulid,
+    /// Type for [`SnowflakeGenerator`]
+    ///
+    /// [`SnowflakeGenerator`]: ../generator/identifier/struct.SnowflakeGenerator.html
+    Snowflake,
 
     //
     // incremental
@@ -59,6 +146,46 @@ pub enum GeneratorType {
     /// [`IncrementIdGenerator`]: ../generator/incremental/struct.IncrementIdGenerator.html
     IncrementId,
 
+    //
+    // locale
+    //
+    /// Type for [`PersonNameGenerator`]
+    ///
+    /// [`PersonNameGenerator`]: ../generator/locale/struct.PersonNameGenerator.html
+    PersonName,
+    /// Type for [`CompanyNameGenerator`]
+    ///
+    /// [`CompanyNameGenerator`]: ../generator/locale/struct.CompanyNameGenerator.html
+    CompanyName,
+    /// Type for [`StreetAddressGenerator`]
+    ///
+    /// [`StreetAddressGenerator`]: ../generator/locale/struct.StreetAddressGenerator.html
+    StreetAddress,
+    /// Type for [`CityGenerator`]
+    ///
+    /// [`CityGenerator`]: ../generator/locale/struct.CityGenerator.html
+    City,
+    /// Type for [`PostalCodeGenerator`]
+    ///
+    /// [`PostalCodeGenerator`]: ../generator/locale/struct.PostalCodeGenerator.html
+    PostalCode,
+    /// Type for [`EmailGenerator`]
+    ///
+    /// [`EmailGenerator`]: ../generator/locale/struct.EmailGenerator.html
+    Email,
+    /// Type for [`PhoneNumberGenerator`]
+    ///
+    /// [`PhoneNumberGenerator`]: ../generator/locale/struct.PhoneNumberGenerator.html
+    PhoneNumber,
+    /// Type for [`UrlGenerator`]
+    ///
+    /// [`UrlGenerator`]: ../generator/locale/struct.UrlGenerator.html
+    Url,
+    /// Type for [`LoremGenerator`]
+    ///
+    /// [`LoremGenerator`]: ../generator/locale/struct.LoremGenerator.html
+    Lorem,
+
     //
     // primitive
     //
@@ -140,6 +267,22 @@ pub enum GeneratorType {
     ///
     /// [`GetValueIndexGenerator`]: ../generator/random_values/struct.GetValueIndexGenerator.html
     GetValueIndex,
+    /// Type for [`ReferenceGenerator`]
+    ///
+    /// [`ReferenceGenerator`]: ../generator/random_values/struct.ReferenceGenerator.html
+    Reference,
+
+    //
+    // structure
+    //
+    /// Type for [`ObjectGenerator`]
+    ///
+    /// [`ObjectGenerator`]: ../generator/structure/struct.ObjectGenerator.html
+    Object,
+    /// Type for [`ArrayGenerator`]
+    ///
+    /// [`ArrayGenerator`]: ../generator/structure/struct.ArrayGenerator.html
+    Array,
 }
 
 impl std::fmt::Display for GeneratorType {
@@ -0,0 +1,219 @@
+//! Module for resolving the references to the definitions in the schema
+//!
+//! A map with `$ref` key is replaced with the value referenced by `$ref`, and the other keys in the map override the keys of the referenced value.
+//! The reference is "<file path>#<JSON pointer>" such as "common.yaml#/definitions/address".
+//! The file path is interpreted starting from the file containing the reference, and the file path is omitted for the reference to the same file such as "#/definitions/address".
+//! The file path at `filepath` key in the other file is also rewritten to start from the file containing it.
+
+use crate::error::BuildError;
+use crate::file::resolve_file_path;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// Key of the reference in a map
+const REFERENCE_KEY: &str = "$ref";
+
+/// Key of the map of the definitions in the root of the schema
+const DEFINITIONS_KEY: &str = "definitions";
+
+/// Key of the file path of the values in a generator builder
+const FILEPATH_KEY: &str = "filepath";
+
+/// Check that the schema has some references or the definitions to resolve
+pub(crate) fn has_references(schema: &Value) -> bool {
+    fn has_reference(value: &Value) -> bool {
+        match value {
+            Value::Object(map) => {
+                map.contains_key(REFERENCE_KEY) || map.values().any(has_reference)
+            }
+            Value::Array(values) => values.iter().any(has_reference),
+            _ => false,
+        }
+    }
+
+    let has_definitions = match schema {
+        Value::Object(map) => map.contains_key(DEFINITIONS_KEY),
+        _ => false,
+    };
+
+    has_definitions || has_reference(schema)
+}
+
+/// Resolve all references in the schema read from the file at the filepath.
+/// The definitions in the root of the schema are removed because they are used only through the references.
+pub(crate) fn resolve_references(
+    schema: Value,
+    filepath: Option<PathBuf>,
+) -> Result<Value, BuildError> {
+    let filepath = filepath.map(|path| path.canonicalize().unwrap_or(path));
+    let mut resolver = Resolver {
+        root_filepath: filepath.clone(),
+        documents: HashMap::new(),
+        resolving: Vec::new(),
+    };
+    resolver.documents.insert(filepath.clone(), schema.clone());
+
+    let schema = match schema {
+        Value::Object(mut map) => {
+            map.remove(DEFINITIONS_KEY);
+            Value::Object(map)
+        }
+        _ => schema,
+    };
+
+    resolver.resolve(schema, &filepath)
+}
+
+/// Resolver of the references with the read documents
+struct Resolver {
+    /// Filepath of the schema. The key of the schema which is not read from a file is `None`.
+    root_filepath: Option<PathBuf>,
+    /// Read documents keyed by the filepath. The key of the schema which is not read from a file is `None`.
+    documents: HashMap<Option<PathBuf>, Value>,
+    /// References which are being resolved as the pair of the filepath and the JSON pointer
+    resolving: Vec<(Option<PathBuf>, String)>,
+}
+
+impl Resolver {
+    /// Resolve the references in the value contained in the document at the filepath
+    fn resolve(&mut self, value: Value, filepath: &Option<PathBuf>) -> Result<Value, BuildError> {
+        match value {
+            Value::Object(mut map) => match map.remove(REFERENCE_KEY) {
+                None => map
+                    .into_iter()
+                    .map(|(k, v)| self.resolve_entry(k, v, filepath))
+                    .collect::<Result<Map<String, Value>, BuildError>>()
+                    .map(Value::Object),
+                Some(Value::String(reference)) => {
+                    let referenced = self.resolve_reference(&reference, filepath)?;
+                    if map.is_empty() {
+                        return Ok(referenced);
+                    }
+
+                    let mut merged = match referenced {
+                        Value::Object(referenced_map) => referenced_map,
+                        _ => {
+                            return Err(BuildError::InvalidValue(format!(
+                                "{} referenced by \"{}\" is not a map to override",
+                                referenced, reference
+                            )))
+                        }
+                    };
+                    for (k, v) in map.into_iter() {
+                        let (k, v) = self.resolve_entry(k, v, filepath)?;
+                        merged.insert(k, v);
+                    }
+
+                    Ok(Value::Object(merged))
+                }
+                Some(reference) => Err(BuildError::InvalidValue(format!(
+                    "{} at the key {} is not a string",
+                    reference, REFERENCE_KEY
+                ))),
+            },
+            Value::Array(values) => values
+                .into_iter()
+                .map(|v| self.resolve(v, filepath))
+                .collect::<Result<Vec<Value>, BuildError>>()
+                .map(Value::Array),
+            _ => Ok(value),
+        }
+    }
+
+    /// Resolve the references in the value at the key in a map contained in the document at the filepath.
+    /// The file path at `filepath` key in the other document than the schema is rewritten to start from the document.
+    fn resolve_entry(
+        &mut self,
+        key: String,
+        value: Value,
+        filepath: &Option<PathBuf>,
+    ) -> Result<(String, Value), BuildError> {
+        if key == FILEPATH_KEY && filepath != &self.root_filepath {
+            if let (Value::String(path), Some(document_filepath)) = (&value, filepath) {
+                let path = resolve_file_path(Path::new(path), Some(document_filepath));
+                return Ok((key, Value::String(path.to_string_lossy().into_owned())));
+            }
+        }
+
+        self.resolve(value, filepath).map(|value| (key, value))
+    }
+
+    /// Get the value referenced by the reference in the document at the filepath, and resolve the references in it
+    fn resolve_reference(
+        &mut self,
+        reference: &str,
+        filepath: &Option<PathBuf>,
+    ) -> Result<Value, BuildError> {
+        let (referenced_path, pointer) = match reference.split_once('#') {
+            None => (reference, ""),
+            Some((referenced_path, pointer)) => (referenced_path, pointer),
+        };
+        let referenced_filepath = if referenced_path.is_empty() {
+            filepath.clone()
+        } else {
+            let path = resolve_file_path(Path::new(referenced_path), filepath.as_deref());
+            Some(path.canonicalize().unwrap_or(path))
+        };
+
+        let key = (referenced_filepath.clone(), pointer.to_string());
+        if let Some(index) = self
+            .resolving
+            .iter()
+            .position(|resolving| resolving == &key)
+        {
+            let mut cycle: Vec<String> = self.resolving[index..]
+                .iter()
+                .map(|(path, pointer)| display_reference(path, pointer))
+                .collect();
+            cycle.push(display_reference(&referenced_filepath, pointer));
+            return Err(BuildError::CyclicSchemaReference(cycle));
+        }
+
+        let referenced = self
+            .load_document(&referenced_filepath)?
+            .pointer(pointer)
+            .cloned()
+            .ok_or_else(|| BuildError::NotExistReference(reference.to_string()))?;
+
+        self.resolving.push(key);
+        let resolved = self.resolve(referenced, &referenced_filepath);
+        self.resolving.pop();
+
+        resolved
+    }
+
+    /// Get the document at the filepath. The document which is not read yet is read from the file.
+    fn load_document(&mut self, filepath: &Option<PathBuf>) -> Result<&Value, BuildError> {
+        if !self.documents.contains_key(filepath) {
+            let path = filepath
+                .as_ref()
+                .expect("The schema which is not read from a file is always loaded");
+            let file = File::open(path).map_err(|e| BuildError::FileError(e, path.clone()))?;
+            let is_json = path
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+            let document: Value = if is_json {
+                serde_json::from_reader(file).map_err(|e| e.to_string())
+            } else {
+                serde_yaml::from_reader(file).map_err(|e| e.to_string())
+            }
+            .map_err(|e| {
+                BuildError::FailParseValue(path.display().to_string(), "Schema".to_string(), e)
+            })?;
+
+            self.documents.insert(filepath.clone(), document);
+        }
+
+        Ok(&self.documents[filepath])
+    }
+}
+
+/// Display the reference as "<file path>#<JSON pointer>"
+fn display_reference(filepath: &Option<PathBuf>, pointer: &str) -> String {
+    match filepath {
+        None => format!("#{}", pointer),
+        Some(filepath) => format!("{}#{}", filepath.display(), pointer),
+    }
+}
@@ -0,0 +1,526 @@
+#![deny(missing_debug_implementations)]
+//! Module for inferring a schema from sample data
+//!
+//! Each column of the sample data is inferred as a generator as follows.
+//! * The column consisting of only the null values is inferred as [`GeneratorType::AlwaysNull`].
+//! * The column of the strictly increasing integers without null is inferred as [`GeneratorType::IncrementId`].
+//! * The column whose distinct values are within the select limit and repeated is inferred as [`GeneratorType::SelectInt`], [`GeneratorType::SelectReal`] or [`GeneratorType::SelectString`].
+//! * The column of the booleans is inferred as [`GeneratorType::Bool`].
+//! * The column of the integers, the reals, the date-times, the dates or the times is inferred as the generator with the observed range.
+//!   The date-time, date and time must be written in the default formats such as [`DATE_DEFAULT_FORMAT`].
+//! * The other column is inferred as [`GeneratorType::Regex`] with the observed characters and lengths.
+//!
+//! The column having the null values is nullable with the observed ratio of the null values.
+//!
+//! # Examples
+//! ```
+//! fn main() {
+//!     use sbrd_gen::builder::{GeneratorBuilder, ValueStep};
+//!     use sbrd_gen::infer::{SampleFormat, SchemaInferrer};
+//!     use sbrd_gen::SchemaBuilder;
+//!
+//!     let sample = "id,rank,score\n1,A,10\n2,B,\n3,A,30\n4,B,20\n";
+//!     let schema_builder = SchemaInferrer::new(SampleFormat::Csv)
+//!         .infer_from_str(sample)
+//!         .unwrap();
+//!
+//!     assert_eq!(
+//!         SchemaBuilder::new(
+//!             vec!["id".to_string(), "rank".to_string(), "score".to_string()],
+//!             vec![
+//!                 GeneratorBuilder::new_increment_id(Some(ValueStep::new(1, None))).into_parent("id"),
+//!                 GeneratorBuilder::new_select_string(
+//!                     None,
+//!                     Some(vec!["A".to_string(), "B".to_string()]),
+//!                     None
+//!                 )
+//!                 .into_parent("rank"),
+//!                 GeneratorBuilder::new_int(Some((10..=30).into()))
+//!                     .nullable_with_probability(0.25)
+//!                     .into_parent("score"),
+//!             ],
+//!         ),
+//!         schema_builder
+//!     );
+//! }
+//! ```
+//!
+//! [`GeneratorType::AlwaysNull`]: ../enum.GeneratorType.html#variant.AlwaysNull
+//! [`GeneratorType::IncrementId`]: ../enum.GeneratorType.html#variant.IncrementId
+//! [`GeneratorType::SelectInt`]: ../enum.GeneratorType.html#variant.SelectInt
+//! [`GeneratorType::SelectReal`]: ../enum.GeneratorType.html#variant.SelectReal
+//! [`GeneratorType::SelectString`]: ../enum.GeneratorType.html#variant.SelectString
+//! [`GeneratorType::Bool`]: ../enum.GeneratorType.html#variant.Bool
+//! [`GeneratorType::Regex`]: ../enum.GeneratorType.html#variant.Regex
+//! [`DATE_DEFAULT_FORMAT`]: ../value/constant.DATE_DEFAULT_FORMAT.html
+
+use crate::builder::{GeneratorBuilder, ValueBound, ValueStep};
+use crate::error::{BuildError, IntoSbrdError, SchemaErrorKind, SchemaResult};
+use crate::value::{
+    SbrdDate, SbrdDateTime, SbrdInt, SbrdReal, SbrdTime, DATE_DEFAULT_FORMAT,
+    DATE_TIME_DEFAULT_FORMAT, TIME_DEFAULT_FORMAT,
+};
+use crate::SchemaBuilder;
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Read};
+
+/// Default limit of the count of the distinct values for the column inferred as select-* generator
+pub const DEFAULT_SELECT_LIMIT: usize = 10;
+
+/// Format of the sample data
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SampleFormat {
+    /// CSV with the header of the keys
+    Csv,
+    /// TSV with the header of the keys
+    Tsv,
+    /// JSON object per line keyed by the keys
+    JsonLines,
+}
+
+/// Inferrer of [`SchemaBuilder`] from the sample data
+///
+/// [`SchemaBuilder`]: ../struct.SchemaBuilder.html
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct SchemaInferrer {
+    format: SampleFormat,
+    select_limit: usize,
+}
+
+impl SchemaInferrer {
+    /// Create the inferrer for the sample data in the format
+    pub fn new(format: SampleFormat) -> Self {
+        Self {
+            format,
+            select_limit: DEFAULT_SELECT_LIMIT,
+        }
+    }
+
+    /// Set limit of the count of the distinct values for the column inferred as select-* generator.
+    ///
+    /// If not set, use [`DEFAULT_SELECT_LIMIT`].
+    ///
+    /// [`DEFAULT_SELECT_LIMIT`]: ./constant.DEFAULT_SELECT_LIMIT.html
+    pub fn select_limit(mut self, limit: usize) -> Self {
+        self.select_limit = limit;
+        self
+    }
+
+    /// Infer from [`&str`]
+    ///
+    /// [`&str`]: https://doc.rust-lang.org/stable/std/str/
+    pub fn infer_from_str(&self, input: &str) -> SchemaResult<SchemaBuilder> {
+        self.infer_from_reader(input.as_bytes())
+    }
+
+    /// Infer from [`Read`]
+    ///
+    /// [`Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+    pub fn infer_from_reader<R: Read>(&self, rdr: R) -> SchemaResult<SchemaBuilder> {
+        let columns = match self.format {
+            SampleFormat::Csv => read_xsv(rdr, b','),
+            SampleFormat::Tsv => read_xsv(rdr, b'\t'),
+            SampleFormat::JsonLines => read_json_lines(rdr),
+        }?;
+
+        let mut keys = Vec::new();
+        let mut builders = Vec::new();
+        for column in columns.into_iter() {
+            builders.push(column.infer(self.select_limit).into_parent(&column.key));
+            keys.push(column.key);
+        }
+
+        Ok(SchemaBuilder::new(keys, builders))
+    }
+}
+
+/// Read the columns from CSV or TSV with the header
+fn read_xsv<R: Read>(rdr: R, delimiter: u8) -> SchemaResult<Vec<Column>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .from_reader(rdr);
+    let mut columns: Vec<Column> = reader
+        .headers()
+        .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))?
+        .iter()
+        .map(Column::new)
+        .collect();
+
+    for record in reader.records() {
+        let record = record.map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))?;
+        for (column, cell) in columns.iter_mut().zip(record.iter()) {
+            column.push(if cell.is_empty() {
+                None
+            } else {
+                Some(Sample::parse(cell))
+            });
+        }
+    }
+
+    Ok(columns)
+}
+
+/// Read the columns from the JSON objects per line. The key missing in a line is treated as null.
+fn read_json_lines<R: Read>(rdr: R) -> SchemaResult<Vec<Column>> {
+    let mut columns: Vec<Column> = Vec::new();
+    let mut row_count = 0;
+    for line in BufReader::new(rdr).lines() {
+        let line = line.map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let OrderedObject(object) = serde_json::from_str(&line).map_err(|e| {
+            BuildError::FailParseValue(line.clone(), "Object".to_string(), e.to_string())
+                .into_sbrd_gen_error(SchemaErrorKind::ParseError)
+        })?;
+
+        for (key, _) in object.iter() {
+            if !columns.iter().any(|column| &column.key == key) {
+                let mut column = Column::new(key);
+                column.null_count = row_count;
+                columns.push(column);
+            }
+        }
+        for column in columns.iter_mut() {
+            column.push(
+                object
+                    .iter()
+                    .find(|(key, _)| key == &column.key)
+                    .and_then(|(_, value)| Sample::from_json(value)),
+            );
+        }
+        row_count += 1;
+    }
+
+    Ok(columns)
+}
+
+/// JSON object keeping the order of the keys
+struct OrderedObject(Vec<(String, Value)>);
+
+impl<'de> Deserialize<'de> for OrderedObject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct OrderedObjectVisitor;
+
+        impl<'de> Visitor<'de> for OrderedObjectVisitor {
+            type Value = OrderedObject;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a JSON object")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+
+                Ok(OrderedObject(entries))
+            }
+        }
+
+        deserializer.deserialize_map(OrderedObjectVisitor)
+    }
+}
+
+/// Observed value in the sample data
+#[derive(Debug, PartialEq, Clone)]
+enum Sample {
+    Bool(bool),
+    Int(SbrdInt),
+    Real(SbrdReal),
+    DateTime(SbrdDateTime),
+    Date(SbrdDate),
+    Time(SbrdTime),
+    String(String),
+}
+
+impl Sample {
+    /// Parse the text as the most specific value
+    fn parse(text: &str) -> Sample {
+        match text {
+            "true" => return Sample::Bool(true),
+            "false" => return Sample::Bool(false),
+            _ => {}
+        }
+        if let Ok(v) = text.parse::<SbrdInt>() {
+            return Sample::Int(v);
+        }
+        if let Ok(v) = text.parse::<SbrdReal>() {
+            if v.is_finite() {
+                return Sample::Real(v);
+            }
+        }
+
+        Self::parse_date_time(text)
+    }
+
+    /// Parse the text as the date-time, date or time in the default format
+    fn parse_date_time(text: &str) -> Sample {
+        if let Ok(v) = SbrdDateTime::parse_from_str(text, DATE_TIME_DEFAULT_FORMAT) {
+            return Sample::DateTime(v);
+        }
+        if let Ok(v) = SbrdDate::parse_from_str(text, DATE_DEFAULT_FORMAT) {
+            return Sample::Date(v);
+        }
+        if let Ok(v) = SbrdTime::parse_from_str(text, TIME_DEFAULT_FORMAT) {
+            return Sample::Time(v);
+        }
+
+        Sample::String(text.to_string())
+    }
+
+    /// Convert from the JSON value. The string is not parsed as the number and the boolean.
+    fn from_json(value: &Value) -> Option<Sample> {
+        match value {
+            Value::Null => None,
+            Value::Bool(v) => Some(Sample::Bool(*v)),
+            Value::Number(v) => Some(match v.as_i64() {
+                Some(v) => Sample::Int(v),
+                None => Sample::Real(v.as_f64().unwrap_or_default()),
+            }),
+            Value::String(v) => Some(Self::parse_date_time(v)),
+            Value::Array(_) | Value::Object(_) => Some(Sample::String(value.to_string())),
+        }
+    }
+
+    /// Convert to the text as written in the sample data
+    fn to_text(&self) -> String {
+        match self {
+            Sample::Bool(v) => v.to_string(),
+            Sample::Int(v) => v.to_string(),
+            Sample::Real(v) => v.to_string(),
+            Sample::DateTime(v) => v.format(DATE_TIME_DEFAULT_FORMAT).to_string(),
+            Sample::Date(v) => v.format(DATE_DEFAULT_FORMAT).to_string(),
+            Sample::Time(v) => v.format(TIME_DEFAULT_FORMAT).to_string(),
+            Sample::String(v) => v.clone(),
+        }
+    }
+}
+
+/// Observed values at a key
+#[derive(Debug, PartialEq, Clone)]
+struct Column {
+    key: String,
+    null_count: usize,
+    samples: Vec<Sample>,
+}
+
+impl Column {
+    fn new<S: Into<String>>(key: S) -> Self {
+        Self {
+            key: key.into(),
+            null_count: 0,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Push the observed value. `None` is the null value.
+    fn push(&mut self, sample: Option<Sample>) {
+        match sample {
+            None => self.null_count += 1,
+            Some(sample) => self.samples.push(sample),
+        }
+    }
+
+    /// Infer the builder from the observed values
+    fn infer(&self, select_limit: usize) -> GeneratorBuilder {
+        if self.samples.is_empty() {
+            return GeneratorBuilder::new_always_null();
+        }
+
+        let builder = self.infer_without_null(select_limit);
+        if self.null_count == 0 {
+            return builder;
+        }
+
+        // round the ratio not to be zero
+        let ratio = self.null_count as f64 / (self.null_count + self.samples.len()) as f64;
+        builder.nullable_with_probability(((ratio * 100.0).round() / 100.0).max(0.01))
+    }
+
+    fn infer_without_null(&self, select_limit: usize) -> GeneratorBuilder {
+        if self.samples.iter().all(|s| matches!(s, Sample::Bool(_))) {
+            return GeneratorBuilder::new_bool();
+        }
+
+        if let Some(values) = self.collect(|s| match s {
+            Sample::Int(v) => Some(*v),
+            _ => None,
+        }) {
+            if self.null_count == 0 && values.len() > 1 && values.windows(2).all(|w| w[0] < w[1]) {
+                let step = values[1] - values[0];
+                let is_constant_step = values.windows(2).all(|w| w[1] - w[0] == step);
+                return GeneratorBuilder::new_increment_id(Some(ValueStep::new(
+                    values[0],
+                    if is_constant_step && step != 1 {
+                        Some(step)
+                    } else {
+                        None
+                    },
+                )));
+            }
+            if let Some(distinct) = select_values(&values, select_limit) {
+                return GeneratorBuilder::new_select_int(None, Some(distinct), None);
+            }
+
+            let (min, max) = min_max(&values);
+            return GeneratorBuilder::new_int(Some(ValueBound::new(Some(min), Some((true, max)))));
+        }
+
+        if let Some(values) = self.collect(|s| match s {
+            Sample::Int(v) => Some(*v as SbrdReal),
+            Sample::Real(v) => Some(*v),
+            _ => None,
+        }) {
+            if let Some(distinct) = select_values(&values, select_limit) {
+                return GeneratorBuilder::new_select_real(None, Some(distinct), None);
+            }
+
+            let (min, max) = min_max(&values);
+            return GeneratorBuilder::new_real(Some(ValueBound::new(Some(min), Some((true, max)))));
+        }
+
+        if let Some(values) = self.collect(|s| match s {
+            Sample::DateTime(v) => Some(*v),
+            _ => None,
+        }) {
+            let (min, max) = min_max(&values);
+            return GeneratorBuilder::new_date_time(
+                Some(ValueBound::new(Some(min), Some((true, max)))),
+                None,
+            );
+        }
+
+        if let Some(values) = self.collect(|s| match s {
+            Sample::Date(v) => Some(*v),
+            _ => None,
+        }) {
+            let (min, max) = min_max(&values);
+            return GeneratorBuilder::new_date(
+                Some(ValueBound::new(Some(min), Some((true, max)))),
+                None,
+            );
+        }
+
+        if let Some(values) = self.collect(|s| match s {
+            Sample::Time(v) => Some(*v),
+            _ => None,
+        }) {
+            let (min, max) = min_max(&values);
+            return GeneratorBuilder::new_time(
+                Some(ValueBound::new(Some(min), Some((true, max)))),
+                None,
+            );
+        }
+
+        let values: Vec<String> = self.samples.iter().map(Sample::to_text).collect();
+        if let Some(distinct) = select_values(&values, select_limit) {
+            return GeneratorBuilder::new_select_string(None, Some(distinct), None);
+        }
+
+        GeneratorBuilder::new_regex(infer_pattern(&values), None)
+    }
+
+    /// Collect the observed values if all values are converted
+    fn collect<T, F>(&self, convert: F) -> Option<Vec<T>>
+    where
+        F: FnMut(&Sample) -> Option<T>,
+    {
+        self.samples.iter().map(convert).collect()
+    }
+}
+
+/// Get the distinct values in the observed order if the values are repeated and the count of them is within the limit
+fn select_values<T: PartialEq + Clone>(values: &[T], select_limit: usize) -> Option<Vec<T>> {
+    let mut distinct: Vec<T> = Vec::new();
+    for value in values.iter() {
+        if !distinct.contains(value) {
+            if distinct.len() == select_limit {
+                return None;
+            }
+            distinct.push(value.clone());
+        }
+    }
+
+    if distinct.len() < values.len() {
+        Some(distinct)
+    } else {
+        None
+    }
+}
+
+/// Get the minimum and the maximum of the not empty values
+fn min_max<T: PartialOrd + Copy>(values: &[T]) -> (T, T) {
+    let mut min = values[0];
+    let mut max = values[0];
+    for value in values.iter() {
+        if value < &min {
+            min = *value;
+        }
+        if value > &max {
+            max = *value;
+        }
+    }
+
+    (min, max)
+}
+
+/// Infer the pattern as a class of the observed characters repeated within the observed lengths
+fn infer_pattern(values: &[String]) -> String {
+    let mut has_lower = false;
+    let mut has_upper = false;
+    let mut has_digit = false;
+    let mut others: Vec<char> = Vec::new();
+    let mut min_length = usize::MAX;
+    let mut max_length = 0;
+    for value in values.iter() {
+        for c in value.chars() {
+            match c {
+                'a'..='z' => has_lower = true,
+                'A'..='Z' => has_upper = true,
+                '0'..='9' => has_digit = true,
+                _ => {
+                    if !others.contains(&c) {
+                        others.push(c);
+                    }
+                }
+            }
+        }
+        let length = value.chars().count();
+        min_length = min_length.min(length);
+        max_length = max_length.max(length);
+    }
+
+    let mut class = String::new();
+    if has_lower {
+        class.push_str("a-z");
+    }
+    if has_upper {
+        class.push_str("A-Z");
+    }
+    if has_digit {
+        class.push_str("0-9");
+    }
+    for c in others.iter() {
+        class.push_str(&regex_syntax::escape(&c.to_string()));
+    }
+    if class.is_empty() {
+        return String::new();
+    }
+
+    if min_length == max_length {
+        format!("[{}]{{{}}}", class, min_length)
+    } else {
+        format!("[{}]{{{},{}}}", class, min_length, max_length)
+    }
+}
@@ -0,0 +1,361 @@
+//! Module for describing the generated values as JSON Schema (draft 2020-12)
+//!
+//! Each values set is described as an object whose properties are the specified keys.
+//! The date-time, date and time values are described according to [`DateTimeRepresentation`] used for writing.
+//!
+//! [`DateTimeRepresentation`]: ../value/enum.DateTimeRepresentation.html
+
+use crate::builder::{GeneratorBuilder, IdEncoding, ParentGeneratorBuilder, ValueBound};
+use crate::error::BuildError;
+use crate::generator::random_values::{ForSelectGeneratorType, SelectGenerator};
+use crate::generator::{SeededRandomizer, ValueGeneratorBase};
+use crate::value::{DataValue, DateTimeRepresentation, SbrdInt, SbrdReal, SbrdString};
+use crate::GeneratorType;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+/// URI of the dialect of the exported JSON Schema
+pub(crate) const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Add the dialect to the described values to make the root of JSON Schema
+pub(crate) fn with_dialect(mut described: Value) -> Value {
+    if let Value::Object(map) = &mut described {
+        map.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+    }
+
+    described
+}
+
+/// Describer of the generated values with the representation of the date-time, date and time values
+pub(crate) struct Describer<'a> {
+    representation: &'a DateTimeRepresentation,
+    /// Specified keys for each table. A reference to the value at the specified key is described as `$ref` to the table's definition.
+    table_keys: HashMap<&'a str, &'a [String]>,
+}
+
+impl<'a> Describer<'a> {
+    /// Create the describer for a schema without tables
+    pub(crate) fn new(representation: &'a DateTimeRepresentation) -> Self {
+        Self {
+            representation,
+            table_keys: HashMap::new(),
+        }
+    }
+
+    /// Create the describer for the tables whose definitions are put under `$defs` with the name of the table
+    pub(crate) fn with_tables(
+        representation: &'a DateTimeRepresentation,
+        table_keys: HashMap<&'a str, &'a [String]>,
+    ) -> Self {
+        Self {
+            representation,
+            table_keys,
+        }
+    }
+
+    /// Describe a values set consisting of the values at the specified keys
+    pub(crate) fn describe_values(
+        &self,
+        keys: &[String],
+        builders: &[ParentGeneratorBuilder],
+    ) -> Result<Value, BuildError> {
+        let mut properties = Map::new();
+        for key in keys.iter() {
+            if let Some(parent_builder) = builders.iter().find(|b| &b.key == key) {
+                properties.insert(key.clone(), self.describe(&parent_builder.builder)?);
+            }
+        }
+
+        Ok(json!({
+            "type": "object",
+            "properties": properties,
+            "required": keys,
+            "additionalProperties": false,
+        }))
+    }
+
+    /// Describe the value generated by the builder
+    fn describe(&self, builder: &GeneratorBuilder) -> Result<Value, BuildError> {
+        let mut described = match builder.generator_type {
+            GeneratorType::DuplicatePermutation
+            | GeneratorType::Format
+            | GeneratorType::Regex
+            | GeneratorType::EvalString
+            | GeneratorType::PersonName
+            | GeneratorType::CompanyName
+            | GeneratorType::StreetAddress
+            | GeneratorType::City
+            | GeneratorType::PostalCode
+            | GeneratorType::PhoneNumber
+            | GeneratorType::Lorem => json!({"type": "string"}),
+            GeneratorType::Email => json!({"type": "string", "format": "email"}),
+            GeneratorType::Url => json!({"type": "string", "format": "uri"}),
+
+            GeneratorType::Int
+            | GeneratorType::DistPoisson
+            | GeneratorType::DistBinomial
+            | GeneratorType::DistGeometric
+            | GeneratorType::DistZipf
+            | GeneratorType::DistBernoulli => {
+                with_range(json!({"type": "integer"}), &builder.range)
+            }
+            GeneratorType::Real
+            | GeneratorType::DistNormal
+            | GeneratorType::DistUniform
+            | GeneratorType::DistLogNormal
+            | GeneratorType::DistExponential
+            | GeneratorType::DistGamma
+            | GeneratorType::DistBeta
+            | GeneratorType::DistWeibull
+            | GeneratorType::DistPareto => with_range(json!({"type": "number"}), &builder.range),
+            GeneratorType::EvalInt => json!({"type": "integer"}),
+            GeneratorType::EvalReal => json!({"type": "number"}),
+            GeneratorType::Bool | GeneratorType::EvalBool => json!({"type": "boolean"}),
+            GeneratorType::IncrementId => json!({"type": "integer"}),
+            GeneratorType::GetValueIndex => json!({"type": "integer", "minimum": 0}),
+            GeneratorType::AlwaysNull => json!({"type": "null"}),
+
+            GeneratorType::DateTime | GeneratorType::EvalDateTime => {
+                self.describe_date_time(builder, "date-time")
+            }
+            GeneratorType::Date | GeneratorType::EvalDate => {
+                self.describe_date_time(builder, "date")
+            }
+            GeneratorType::Time | GeneratorType::EvalTime => {
+                self.describe_date_time(builder, "time")
+            }
+
+            GeneratorType::UuidV4 | GeneratorType::UuidV7 => {
+                match builder.encoding.unwrap_or(IdEncoding::Hyphenated) {
+                    IdEncoding::Hyphenated | IdEncoding::Uppercase => {
+                        json!({"type": "string", "format": "uuid"})
+                    }
+                    IdEncoding::Integer => json!({"type": "integer"}),
+                    _ => json!({"type": "string"}),
+                }
+            }
+            GeneratorType::Ulid => match builder.encoding.unwrap_or(IdEncoding::Base32) {
+                IdEncoding::Integer => json!({"type": "integer"}),
+                _ => json!({"type": "string"}),
+            },
+            GeneratorType::Snowflake => match builder.encoding.unwrap_or(IdEncoding::Integer) {
+                IdEncoding::Integer => json!({"type": "integer", "minimum": 0}),
+                _ => json!({"type": "string"}),
+            },
+
+            GeneratorType::SelectInt | GeneratorType::GetIntValueAt => {
+                json!({"type": "integer", "enum": selectable_values::<SbrdInt>(builder)?})
+            }
+            GeneratorType::SelectReal | GeneratorType::GetRealValueAt => {
+                json!({"type": "number", "enum": selectable_values::<SbrdReal>(builder)?})
+            }
+            GeneratorType::SelectString | GeneratorType::GetStringValueAt => {
+                json!({"type": "string", "enum": selectable_values::<SbrdString>(builder)?})
+            }
+            GeneratorType::Reference => self.describe_reference(builder),
+
+            GeneratorType::CaseWhen | GeneratorType::RandomChild => {
+                json!({"anyOf": self.describe_children(builder)?})
+            }
+            GeneratorType::Object => {
+                let mut properties = Map::new();
+                let mut required = Vec::new();
+                for child in builder.children.iter().flatten() {
+                    if let Some(key) = &child.key {
+                        properties.insert(key.clone(), self.describe(&child.builder)?);
+                        required.push(key.clone());
+                    }
+                }
+
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": required,
+                    "additionalProperties": false,
+                })
+            }
+            GeneratorType::Array => {
+                let mut described = json!({
+                    "type": "array",
+                    "items": {"anyOf": self.describe_children(builder)?},
+                });
+                if let Some(range) = &builder.range {
+                    if let Some(min_items) = range.get_start().as_ref().and_then(as_number) {
+                        described["minItems"] = min_items;
+                    }
+                    if let Some(end) = range.get_end().as_ref().and_then(as_number) {
+                        described["maxItems"] = match (range.is_include_end(), end.as_i64()) {
+                            (false, Some(end)) => json!(end - 1),
+                            _ => end,
+                        };
+                    }
+                }
+
+                described
+            }
+        };
+
+        if builder.nullable.is_nullable() {
+            allow_null(&mut described);
+        }
+
+        Ok(described)
+    }
+
+    /// Describe the date-time, date or time value as the string in the format of JSON Schema
+    /// if it is written in the default format or ISO-8601.
+    fn describe_date_time(&self, builder: &GeneratorBuilder, format: &str) -> Value {
+        match self.representation {
+            DateTimeRepresentation::EpochSeconds | DateTimeRepresentation::EpochMillis => {
+                json!({"type": "integer"})
+            }
+            DateTimeRepresentation::Format if builder.format.is_none() => {
+                json!({"type": "string", "format": format})
+            }
+            DateTimeRepresentation::Iso8601 => json!({"type": "string", "format": format}),
+            DateTimeRepresentation::Format | DateTimeRepresentation::Custom(_) => {
+                json!({"type": "string"})
+            }
+        }
+    }
+
+    /// Describe the value referenced in the other table as the reference to the table's definition
+    fn describe_reference(&self, builder: &GeneratorBuilder) -> Value {
+        let reference = match &builder.reference {
+            None => return json!({}),
+            Some(reference) => reference,
+        };
+        let is_specified = self
+            .table_keys
+            .get(reference.get_table())
+            .is_some_and(|keys| keys.iter().any(|key| key == reference.get_key()));
+        if !is_specified {
+            return json!({});
+        }
+
+        json!({
+            "$ref": format!(
+                "#/$defs/{}/properties/{}",
+                escape_pointer(reference.get_table()),
+                escape_pointer(reference.get_key())
+            )
+        })
+    }
+
+    /// Describe each child without duplication
+    fn describe_children(&self, builder: &GeneratorBuilder) -> Result<Vec<Value>, BuildError> {
+        let mut described_children: Vec<Value> = Vec::new();
+        for child in builder.children.iter().flatten() {
+            let described = self.describe(&child.builder)?;
+            if !described_children.contains(&described) {
+                described_children.push(described);
+            }
+        }
+
+        Ok(described_children)
+    }
+}
+
+/// Get the selectable values from `chars`, `values` and `filepath` as the type.
+/// The values with the weight 0 are never picked out by the select-* generators.
+fn selectable_values<T: ForSelectGeneratorType>(
+    builder: &GeneratorBuilder,
+) -> Result<Vec<Value>, BuildError> {
+    let is_weighted = matches!(
+        builder.generator_type,
+        GeneratorType::SelectInt | GeneratorType::SelectReal | GeneratorType::SelectString
+    );
+    let chars = builder.chars.clone();
+    let values = builder.values.clone();
+    let filepath = builder.filepath.clone();
+    let values: Vec<T> = if is_weighted {
+        <SelectGenerator<T> as ValueGeneratorBase<SeededRandomizer, T>>::build_weighted_selectable(
+            chars,
+            values,
+            filepath,
+            builder.weighted,
+        )?
+        .into_iter()
+        .filter(|item| item.0 != 0)
+        .map(|item| item.1)
+        .collect()
+    } else {
+        <SelectGenerator<T> as ValueGeneratorBase<SeededRandomizer, T>>::build_selectable(
+            chars, values, filepath,
+        )?
+    };
+
+    let mut selectable: Vec<Value> = Vec::new();
+    for value in values.iter() {
+        let value = serde_json::to_value(value.to_data_value())
+            .map_err(|e| BuildError::InvalidValue(e.to_string()))?;
+        if !selectable.contains(&value) {
+            selectable.push(value);
+        }
+    }
+
+    Ok(selectable)
+}
+
+/// Add `minimum` and `maximum` from the bounds of the range which are not scripts
+fn with_range(mut described: Value, range: &Option<ValueBound<DataValue>>) -> Value {
+    let range = match range {
+        None => return described,
+        Some(range) => range,
+    };
+
+    if let Some(start) = range.get_start().as_ref().and_then(as_number) {
+        described["minimum"] = start;
+    }
+    if let Some(end) = range.get_end().as_ref().and_then(as_number) {
+        if range.is_include_end() {
+            described["maximum"] = end;
+        } else {
+            described["exclusiveMaximum"] = end;
+        }
+    }
+
+    described
+}
+
+/// Convert the bound of the range into the number. The script bound is not converted.
+fn as_number(bound: &DataValue) -> Option<Value> {
+    match bound {
+        DataValue::Int(v) => Some(json!(v)),
+        DataValue::Real(v) => Some(json!(v)),
+        DataValue::String(s) if !s.contains('{') => s
+            .trim()
+            .parse::<SbrdInt>()
+            .map(|v| json!(v))
+            .or_else(|_| s.trim().parse::<SbrdReal>().map(|v| json!(v)))
+            .ok(),
+        _ => None,
+    }
+}
+
+/// Allow null in addition to the described value
+fn allow_null(described: &mut Value) {
+    if let Some(Value::Array(values)) = described.get_mut("enum") {
+        values.push(Value::Null);
+    }
+
+    match described.get_mut("type") {
+        Some(Value::String(t)) if t == "null" => {}
+        Some(t @ Value::String(_)) => *t = json!([t.clone(), "null"]),
+        Some(_) => {}
+        None => {
+            if let Some(Value::Array(any_of)) = described.get_mut("anyOf") {
+                if !any_of.contains(&json!({"type": "null"})) {
+                    any_of.push(json!({"type": "null"}));
+                }
+            } else if described.as_object().is_some_and(|map| !map.is_empty()) {
+                *described = json!({"anyOf": [described.clone(), {"type": "null"}]});
+            }
+        }
+    }
+}
+
+/// Escape the token of JSON pointer
+fn escape_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
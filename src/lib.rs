@@ -7,18 +7,25 @@
     dead_code,
     path_statements
 )]
+#![allow(clippy::needless_doctest_main)]
 //! Library Crate for Schema Based Random GENerator.
 
 pub use generator_type::*;
 pub use schema::*;
+pub use tables::*;
 
 pub mod builder;
+pub mod check;
 pub mod error;
 pub mod eval;
 pub mod file;
 pub mod generator;
 mod generator_type;
+mod include;
+pub mod infer;
+mod json_schema;
 pub mod parser;
 mod schema;
+mod tables;
 pub mod value;
 pub mod writer;
@@ -7,7 +7,11 @@ use clap::Parser;
 use human_panic::setup_panic;
 
 fn main() {
-    setup_panic!();
+    // `human-panic` still refers to the deprecated `PanicInfo` alias
+    #[allow(deprecated)]
+    {
+        setup_panic!();
+    }
 
     let app: SbrdGenApp = SbrdGenApp::parse();
     app.run();
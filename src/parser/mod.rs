@@ -0,0 +1,177 @@
+#![deny(missing_debug_implementations)]
+//! Module for parser
+//!
+//! The parsers resolve `$ref` in the schema before parsing it.
+//! A map with `$ref` key is replaced with the value referenced by `$ref` such as "#/definitions/address" and "common.yaml#/definitions/address",
+//! and the other keys in the map override the keys of the referenced value.
+//! The referenced file is interpreted starting from the file containing the reference, or from the schema file path remembered by [`set_schema_file_path`] for the schema.
+//! The map of the reusable values at `definitions` key in the schema is used only through `$ref`.
+//! The schema without `$ref` and `definitions` is parsed directly, so the parse error keeps the location in the schema.
+//!
+//! # Examples
+//! ```
+//! fn main() {
+//!     use sbrd_gen::parser::{SchemaParser, YamlParser};
+//!     use sbrd_gen::SchemaBuilder;
+//!     use sbrd_gen::builder::GeneratorBuilder;
+//!
+//!     let schema = r##"
+//! definitions:
+//!   age:
+//!     type: int
+//!     range: {start: 0, end: 100}
+//! keys: [adult_age]
+//! generators:
+//!   - key: adult_age
+//!     $ref: "#/definitions/age"
+//!     range: {start: 20, end: 100}
+//! "##;
+//!
+//!     assert_eq!(
+//!         SchemaBuilder::new(
+//!             vec!["adult_age".to_string()],
+//!             vec![GeneratorBuilder::new_int(Some((20..=100).into())).into_parent("adult_age")],
+//!         ),
+//!         YamlParser::parse_from_str(schema).unwrap()
+//!     );
+//! }
+//! ```
+//!
+//! [`set_schema_file_path`]: ../file/fn.set_schema_file_path.html
+
+pub use openapi::*;
+pub use sql::*;
+
+mod openapi;
+mod sql;
+
+use crate::error::{IntoSbrdError, SchemaErrorKind, SchemaResult};
+use crate::file::get_schema_file_path;
+use crate::include::{has_references, resolve_references};
+use crate::{SchemaBuilder, TablesSchemaBuilder};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Trait of parser for a schema
+pub trait SchemaParser {
+    /// Parse from [`&str`]
+    ///
+    /// [`&str`]: https://doc.rust-lang.org/stable/std/str/
+    fn parse_from_str(input: &str) -> SchemaResult<SchemaBuilder>;
+
+    /// Parse from [`Read`]
+    ///
+    /// [`Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+    fn parse_from_reader<R: std::io::Read>(rdr: R) -> SchemaResult<SchemaBuilder>;
+
+    /// Parse the schema for tables from [`&str`]
+    ///
+    /// [`&str`]: https://doc.rust-lang.org/stable/std/str/
+    fn parse_tables_from_str(input: &str) -> SchemaResult<TablesSchemaBuilder>;
+
+    /// Parse the schema for tables from [`Read`]
+    ///
+    /// [`Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+    fn parse_tables_from_reader<R: std::io::Read>(rdr: R) -> SchemaResult<TablesSchemaBuilder>;
+}
+
+/// Resolve the references in the parsed schema and then convert it to the builder
+fn from_resolved_value<T: DeserializeOwned>(schema: Value) -> SchemaResult<T> {
+    let resolved = resolve_references(schema, get_schema_file_path())
+        .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))?;
+
+    serde_json::from_value(resolved).map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))
+}
+
+/// Read all input from [`Read`] for parsing it
+///
+/// [`Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+fn read_input<R: std::io::Read>(mut rdr: R) -> SchemaResult<String> {
+    let mut input = String::new();
+    rdr.read_to_string(&mut input)
+        .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))?;
+
+    Ok(input)
+}
+
+/// Parser for a schema written as Yaml
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct YamlParser;
+impl YamlParser {
+    /// Parse from [`&str`] resolving the references.
+    /// The schema without references is parsed directly for keeping the location in the error.
+    ///
+    /// [`&str`]: https://doc.rust-lang.org/stable/std/str/
+    fn parse_input<T: DeserializeOwned>(input: &str) -> SchemaResult<T> {
+        let schema: Value = serde_yaml::from_str(input)
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))?;
+        if !has_references(&schema) {
+            return serde_yaml::from_str(input)
+                .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError));
+        }
+
+        from_resolved_value(schema)
+    }
+}
+
+impl SchemaParser for YamlParser {
+    fn parse_from_str(input: &str) -> SchemaResult<SchemaBuilder>
+    where
+        Self: Sized,
+    {
+        Self::parse_input(input)
+    }
+
+    fn parse_from_reader<R: std::io::Read>(rdr: R) -> SchemaResult<SchemaBuilder> {
+        Self::parse_input(&read_input(rdr)?)
+    }
+
+    fn parse_tables_from_str(input: &str) -> SchemaResult<TablesSchemaBuilder> {
+        Self::parse_input(input)
+    }
+
+    fn parse_tables_from_reader<R: std::io::Read>(rdr: R) -> SchemaResult<TablesSchemaBuilder> {
+        Self::parse_input(&read_input(rdr)?)
+    }
+}
+
+/// Parser for a schema written as Json
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct JsonParser;
+impl JsonParser {
+    /// Parse from [`&str`] resolving the references.
+    /// The schema without references is parsed directly for keeping the location in the error.
+    ///
+    /// [`&str`]: https://doc.rust-lang.org/stable/std/str/
+    fn parse_input<T: DeserializeOwned>(input: &str) -> SchemaResult<T> {
+        let schema: Value = serde_json::from_str(input)
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))?;
+        if !has_references(&schema) {
+            return serde_json::from_str(input)
+                .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError));
+        }
+
+        from_resolved_value(schema)
+    }
+}
+
+impl SchemaParser for JsonParser {
+    fn parse_from_str(input: &str) -> SchemaResult<SchemaBuilder>
+    where
+        Self: Sized,
+    {
+        Self::parse_input(input)
+    }
+
+    fn parse_from_reader<R: std::io::Read>(rdr: R) -> SchemaResult<SchemaBuilder> {
+        Self::parse_input(&read_input(rdr)?)
+    }
+
+    fn parse_tables_from_str(input: &str) -> SchemaResult<TablesSchemaBuilder> {
+        Self::parse_input(input)
+    }
+
+    fn parse_tables_from_reader<R: std::io::Read>(rdr: R) -> SchemaResult<TablesSchemaBuilder> {
+        Self::parse_input(&read_input(rdr)?)
+    }
+}
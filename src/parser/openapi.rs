@@ -0,0 +1,732 @@
+//! Module for parser of the component schemas in OpenAPI 3
+//!
+//! The parser reads an OpenAPI 3.0 or 3.1 document written as Yaml or Json, and converts the schema at `components.schemas.<component>` to the schema.
+//! The component schema must be an object, and its properties become the keys to be output.
+//! Each schema is converted to the builder of the generator as follows.
+//! * `enum` and `const` become [`GeneratorType::SelectInt`] for `integer`, [`GeneratorType::SelectReal`] for `number` and [`GeneratorType::SelectString`] for the others.
+//! * `integer` becomes [`GeneratorType::Int`] and `number` becomes [`GeneratorType::Real`] with the range from `minimum`, `maximum`, `exclusiveMinimum` and `exclusiveMaximum`.
+//! * `boolean` becomes [`GeneratorType::Bool`] and `null` becomes [`GeneratorType::AlwaysNull`].
+//! * `string` becomes [`GeneratorType::DateTime`], [`GeneratorType::Date`], [`GeneratorType::Time`], [`GeneratorType::Email`], [`GeneratorType::Url`] and [`GeneratorType::UuidV4`]
+//!   for the formats `date-time`, `date`, `time`, `email`, `uri` and `uuid`.
+//!   The other string becomes [`GeneratorType::Regex`] with `pattern` or with the length from `minLength` and `maxLength`, otherwise [`GeneratorType::Lorem`].
+//! * `object` becomes [`GeneratorType::Object`] with `properties`, and `array` becomes [`GeneratorType::Array`] with `items` and the count from `minItems` and `maxItems`.
+//! * `oneOf` and `anyOf` become [`GeneratorType::RandomChild`], and the schemas in `allOf` are merged.
+//!
+//! The value is nullable if `nullable` is true, `type` contains `null`, `enum` contains null or the property is not `required`.
+//! `$ref` is resolved only for the reference in the same document such as "#/components/schemas/Address".
+//!
+//! # Examples
+//! ```
+//! fn main() {
+//!     use sbrd_gen::builder::GeneratorBuilder;
+//!     use sbrd_gen::parser::OpenApiParser;
+//!     use sbrd_gen::SchemaBuilder;
+//!
+//!     let document = r#"
+//! openapi: 3.0.3
+//! components:
+//!   schemas:
+//!     User:
+//!       type: object
+//!       required: [id, role]
+//!       properties:
+//!         id:
+//!           type: integer
+//!           minimum: 1
+//!           maximum: 1000
+//!         role:
+//!           type: string
+//!           enum: [admin, member]
+//!         tags:
+//!           type: array
+//!           maxItems: 3
+//!           items:
+//!             type: string
+//!             pattern: "[a-z]{4}"
+//! "#;
+//!
+//!     assert_eq!(
+//!         SchemaBuilder::new(
+//!             vec!["id".to_string(), "role".to_string(), "tags".to_string()],
+//!             vec![
+//!                 GeneratorBuilder::new_int(Some((1..=1000).into())).into_parent("id"),
+//!                 GeneratorBuilder::new_select_string(None, Some(vec!["admin".to_string(), "member".to_string()]), None)
+//!                     .into_parent("role"),
+//!                 GeneratorBuilder::new_array(
+//!                     Some((1..=3).into()),
+//!                     vec![GeneratorBuilder::new_regex("[a-z]{4}", None).into_child()],
+//!                 )
+//!                 .nullable()
+//!                 .into_parent("tags"),
+//!             ],
+//!         ),
+//!         OpenApiParser::parse_component_from_str(document, "User").unwrap()
+//!     );
+//! }
+//! ```
+//!
+//! [`GeneratorType::SelectInt`]: ../enum.GeneratorType.html#variant.SelectInt
+//! [`GeneratorType::SelectReal`]: ../enum.GeneratorType.html#variant.SelectReal
+//! [`GeneratorType::SelectString`]: ../enum.GeneratorType.html#variant.SelectString
+//! [`GeneratorType::Int`]: ../enum.GeneratorType.html#variant.Int
+//! [`GeneratorType::Real`]: ../enum.GeneratorType.html#variant.Real
+//! [`GeneratorType::Bool`]: ../enum.GeneratorType.html#variant.Bool
+//! [`GeneratorType::AlwaysNull`]: ../enum.GeneratorType.html#variant.AlwaysNull
+//! [`GeneratorType::DateTime`]: ../enum.GeneratorType.html#variant.DateTime
+//! [`GeneratorType::Date`]: ../enum.GeneratorType.html#variant.Date
+//! [`GeneratorType::Time`]: ../enum.GeneratorType.html#variant.Time
+//! [`GeneratorType::Email`]: ../enum.GeneratorType.html#variant.Email
+//! [`GeneratorType::Url`]: ../enum.GeneratorType.html#variant.Url
+//! [`GeneratorType::UuidV4`]: ../enum.GeneratorType.html#variant.UuidV4
+//! [`GeneratorType::Regex`]: ../enum.GeneratorType.html#variant.Regex
+//! [`GeneratorType::Lorem`]: ../enum.GeneratorType.html#variant.Lorem
+//! [`GeneratorType::Object`]: ../enum.GeneratorType.html#variant.Object
+//! [`GeneratorType::Array`]: ../enum.GeneratorType.html#variant.Array
+//! [`GeneratorType::RandomChild`]: ../enum.GeneratorType.html#variant.RandomChild
+
+use crate::builder::{GeneratorBuilder, ValueBound};
+use crate::error::{BuildError, IntoSbrdError, SchemaErrorKind, SchemaResult};
+use crate::parser::SchemaParser;
+use crate::value::{SbrdInt, SbrdReal, SbrdString};
+use crate::{SchemaBuilder, TableSchemaBuilder, TablesSchemaBuilder};
+use serde_yaml::{Mapping, Value};
+use std::env;
+
+/// Constant value's key of the component to be parsed by [`OpenApiParser`]
+///
+/// [`OpenApiParser`]: ./struct.OpenApiParser.html
+const OPENAPI_COMPONENT_KEY: &str = "SBRD_OPENAPI_COMPONENT";
+
+/// Count of the generated values sets for each component parsed as the tables by [`OpenApiParser`]
+///
+/// [`OpenApiParser`]: ./struct.OpenApiParser.html
+pub const DEFAULT_OPENAPI_TABLE_COUNT: u64 = 10;
+
+/// Default count range of the items in the array without `minItems` and `maxItems`
+const DEFAULT_ITEMS_RANGE: (SbrdInt, SbrdInt) = (1, 15);
+
+/// Make the environment remember the name of the component to be parsed by [`SchemaParser::parse_from_str`] of [`OpenApiParser`].
+/// Normally, the component is remembered when you run a CLI command with the component.
+///
+/// [`SchemaParser::parse_from_str`]: ./trait.SchemaParser.html#tymethod.parse_from_str
+/// [`OpenApiParser`]: ./struct.OpenApiParser.html
+pub fn set_openapi_component<S>(component: S)
+where
+    S: Into<String>,
+{
+    env::set_var(OPENAPI_COMPONENT_KEY, component.into());
+}
+
+/// Get the name of the stored component if it exists
+fn get_openapi_component() -> Option<String> {
+    env::var(OPENAPI_COMPONENT_KEY).ok()
+}
+
+/// Parser for a schema written as the component schemas of OpenAPI 3
+///
+/// [`SchemaParser::parse_from_str`] parses the component remembered by [`set_openapi_component`],
+/// or the only object component if the component is not remembered.
+/// [`SchemaParser::parse_tables_from_str`] parses each object component as the table generated [`DEFAULT_OPENAPI_TABLE_COUNT`] times.
+///
+/// [`SchemaParser::parse_from_str`]: ./trait.SchemaParser.html#tymethod.parse_from_str
+/// [`SchemaParser::parse_tables_from_str`]: ./trait.SchemaParser.html#tymethod.parse_tables_from_str
+/// [`set_openapi_component`]: ./fn.set_openapi_component.html
+/// [`DEFAULT_OPENAPI_TABLE_COUNT`]: ./constant.DEFAULT_OPENAPI_TABLE_COUNT.html
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct OpenApiParser;
+impl OpenApiParser {
+    /// Parse the component from [`&str`]
+    ///
+    /// [`&str`]: https://doc.rust-lang.org/stable/std/str/
+    pub fn parse_component_from_str(input: &str, component: &str) -> SchemaResult<SchemaBuilder> {
+        Self::parse_component(&Self::parse_document_from_str(input)?, component)
+    }
+
+    /// Parse the component from [`Read`]
+    ///
+    /// [`Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+    pub fn parse_component_from_reader<R: std::io::Read>(
+        rdr: R,
+        component: &str,
+    ) -> SchemaResult<SchemaBuilder> {
+        Self::parse_component(&Self::parse_document_from_reader(rdr)?, component)
+    }
+
+    /// Parse from [`&str`] as the document keeping the order of the keys.
+    /// The document written as Json is also parsed because Yaml is a superset of Json.
+    ///
+    /// [`&str`]: https://doc.rust-lang.org/stable/std/str/
+    fn parse_document_from_str(input: &str) -> SchemaResult<Value> {
+        serde_yaml::from_str(input).map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))
+    }
+
+    /// Parse from [`Read`] as the document keeping the order of the keys
+    ///
+    /// [`Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+    fn parse_document_from_reader<R: std::io::Read>(rdr: R) -> SchemaResult<Value> {
+        serde_yaml::from_reader(rdr).map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))
+    }
+
+    /// Parse the component in the document
+    fn parse_component(document: &Value, component: &str) -> SchemaResult<SchemaBuilder> {
+        let schema = component_schemas(document)
+            .and_then(|schemas| {
+                schemas
+                    .get(&Value::String(component.to_string()))
+                    .ok_or_else(|| {
+                        BuildError::NotExistReference(format!(
+                            "#/components/schemas/{}",
+                            escape_pointer(component)
+                        ))
+                    })
+            })
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))?;
+
+        Converter::new(document)
+            .convert_component(component, schema)
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))
+    }
+
+    /// Get the names of all object components in the document
+    fn object_component_names(document: &Value) -> SchemaResult<Vec<String>> {
+        let schemas = component_schemas(document)
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))?;
+
+        let mut names = Vec::new();
+        for (name, schema) in schemas.iter() {
+            let name = match name.as_str() {
+                None => continue,
+                Some(name) => name,
+            };
+            let is_object = Converter::new(document)
+                .flatten(schema)
+                .map(|flattened| is_object(&flattened))
+                .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))?;
+            if is_object {
+                names.push(name.to_string());
+            }
+        }
+
+        Ok(names)
+    }
+
+    /// Parse the remembered component, or the only object component if the component is not remembered
+    fn parse_default_component(document: &Value) -> SchemaResult<SchemaBuilder> {
+        if let Some(component) = get_openapi_component() {
+            return Self::parse_component(document, &component);
+        }
+
+        let names = Self::object_component_names(document)?;
+        if names.len() != 1 {
+            return Err(BuildError::FailParseValue(
+                format!("{} components", names.len()),
+                "Schema".to_string(),
+                "The document must have only one object component. Specify the component to parse."
+                    .to_string(),
+            )
+            .into_sbrd_gen_error(SchemaErrorKind::ParseError));
+        }
+
+        Self::parse_component(document, &names[0])
+    }
+
+    /// Parse all object components as the tables
+    fn parse_tables(document: &Value) -> SchemaResult<TablesSchemaBuilder> {
+        let mut tables = Vec::new();
+        for name in Self::object_component_names(document)?.into_iter() {
+            let schema = Self::parse_component(document, &name)?;
+            tables.push(TableSchemaBuilder::new(
+                name,
+                DEFAULT_OPENAPI_TABLE_COUNT,
+                schema,
+            ));
+        }
+
+        Ok(TablesSchemaBuilder::new(tables))
+    }
+}
+
+impl SchemaParser for OpenApiParser {
+    fn parse_from_str(input: &str) -> SchemaResult<SchemaBuilder>
+    where
+        Self: Sized,
+    {
+        Self::parse_default_component(&Self::parse_document_from_str(input)?)
+    }
+
+    fn parse_from_reader<R: std::io::Read>(rdr: R) -> SchemaResult<SchemaBuilder> {
+        Self::parse_default_component(&Self::parse_document_from_reader(rdr)?)
+    }
+
+    fn parse_tables_from_str(input: &str) -> SchemaResult<TablesSchemaBuilder> {
+        Self::parse_tables(&Self::parse_document_from_str(input)?)
+    }
+
+    fn parse_tables_from_reader<R: std::io::Read>(rdr: R) -> SchemaResult<TablesSchemaBuilder> {
+        Self::parse_tables(&Self::parse_document_from_reader(rdr)?)
+    }
+}
+
+/// Get the map of the schemas at `components.schemas`
+fn component_schemas(document: &Value) -> Result<&Mapping, BuildError> {
+    document
+        .get("components")
+        .and_then(|components| components.get("schemas"))
+        .and_then(Value::as_mapping)
+        .ok_or_else(|| {
+            BuildError::FailParseValue(
+                "components.schemas".to_string(),
+                "OpenAPI".to_string(),
+                "Not found the map of the component schemas".to_string(),
+            )
+        })
+}
+
+/// Converter of the schemas in the document to the builders
+struct Converter<'a> {
+    document: &'a Value,
+    /// References which are being converted
+    resolving: Vec<String>,
+}
+
+impl<'a> Converter<'a> {
+    fn new(document: &'a Value) -> Self {
+        Self {
+            document,
+            resolving: Vec::new(),
+        }
+    }
+
+    /// Convert the object component to the schema whose keys are the properties
+    fn convert_component(
+        &mut self,
+        name: &str,
+        schema: &Value,
+    ) -> Result<SchemaBuilder, BuildError> {
+        let depth = self.resolving.len();
+        let result = self.flatten(schema).and_then(|flattened| {
+            if !is_object(&flattened) {
+                return Err(BuildError::FailParseValue(
+                    name.to_string(),
+                    "Object".to_string(),
+                    "The component schema is not an object".to_string(),
+                ));
+            }
+
+            let mut keys = Vec::new();
+            let mut builders = Vec::new();
+            for (key, builder) in self.convert_properties(&flattened)? {
+                builders.push(builder.into_parent(&key));
+                keys.push(key);
+            }
+
+            Ok(SchemaBuilder::new(keys, builders))
+        });
+        self.resolving.truncate(depth);
+
+        result
+    }
+
+    /// Convert the schema to the builder
+    fn convert(&mut self, schema: &Value) -> Result<GeneratorBuilder, BuildError> {
+        let depth = self.resolving.len();
+        let result = self
+            .flatten(schema)
+            .and_then(|flattened| self.convert_flattened(&flattened));
+        self.resolving.truncate(depth);
+
+        result
+    }
+
+    /// Resolve `$ref` and merge the schemas in `allOf`.
+    /// The resolved references remain in the converting references until the caller truncates them.
+    fn flatten(&mut self, schema: &Value) -> Result<Mapping, BuildError> {
+        let mut schema = schema.as_mapping().cloned().unwrap_or_default();
+
+        if let Some(reference) = schema.remove(&key("$ref")) {
+            let reference = reference.as_str().ok_or_else(|| {
+                BuildError::InvalidValue(format!("{:?} at the key $ref is not a string", reference))
+            })?;
+            if let Some(index) = self.resolving.iter().position(|r| r == reference) {
+                let mut cycle = self.resolving[index..].to_vec();
+                cycle.push(reference.to_string());
+                return Err(BuildError::CyclicSchemaReference(cycle));
+            }
+
+            let referenced = self.lookup(reference)?;
+            self.resolving.push(reference.to_string());
+            let mut flattened = self.flatten(&referenced)?;
+            merge_schema(&mut flattened, schema);
+
+            return Ok(flattened);
+        }
+
+        if let Some(all_of) = schema.remove(&key("allOf")) {
+            let mut merged = Mapping::new();
+            for part in all_of.as_sequence().into_iter().flatten() {
+                let depth = self.resolving.len();
+                let part = self.flatten(part);
+                self.resolving.truncate(depth);
+                merge_schema(&mut merged, part?);
+            }
+            merge_schema(&mut merged, schema);
+
+            return Ok(merged);
+        }
+
+        Ok(schema)
+    }
+
+    /// Get the value referenced by the reference in the same document
+    fn lookup(&self, reference: &str) -> Result<Value, BuildError> {
+        let pointer = reference
+            .strip_prefix('#')
+            .ok_or_else(|| BuildError::NotExistReference(reference.to_string()))?;
+
+        let mut value = self.document;
+        for token in pointer.split('/').skip(1) {
+            let token = token.replace("~1", "/").replace("~0", "~");
+            value = match value {
+                Value::Mapping(map) => map.get(&Value::String(token)),
+                Value::Sequence(values) => token.parse::<usize>().ok().and_then(|i| values.get(i)),
+                _ => None,
+            }
+            .ok_or_else(|| BuildError::NotExistReference(reference.to_string()))?;
+        }
+
+        Ok(value.clone())
+    }
+
+    /// Convert the schema without `$ref` and `allOf` to the builder
+    fn convert_flattened(&mut self, schema: &Mapping) -> Result<GeneratorBuilder, BuildError> {
+        let mut nullable = schema.get(&key("nullable")).and_then(Value::as_bool) == Some(true);
+        let schema_type = match schema.get(&key("type")) {
+            Some(Value::Sequence(types)) => {
+                nullable |= types.iter().any(|t| t.as_str() == Some("null"));
+                types
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .find(|t| t != &"null")
+                    .or(Some("null"))
+            }
+            Some(t) => t.as_str(),
+            None => None,
+        };
+        let selectable = match (schema.get(&key("enum")), schema.get(&key("const"))) {
+            (Some(Value::Sequence(values)), _) => Some(values.clone()),
+            (_, Some(value)) => Some(vec![value.clone()]),
+            _ => None,
+        };
+
+        let builder = if let Some(children) = schema
+            .get(&key("oneOf"))
+            .or_else(|| schema.get(&key("anyOf")))
+            .and_then(Value::as_sequence)
+        {
+            let mut converted = Vec::new();
+            for child in children.iter() {
+                converted.push(self.convert(child)?.into_child());
+            }
+
+            GeneratorBuilder::new_random_child(converted)
+        } else if let Some(mut values) = selectable {
+            if values.iter().any(Value::is_null) {
+                nullable = true;
+                values.retain(|v| !v.is_null());
+            }
+
+            select_builder(schema_type, &values)?
+        } else {
+            match schema_type {
+                Some("integer") => GeneratorBuilder::new_int(int_range(schema)),
+                Some("number") => GeneratorBuilder::new_real(real_range(schema)),
+                Some("boolean") => GeneratorBuilder::new_bool(),
+                Some("null") => GeneratorBuilder::new_always_null(),
+                Some("string") => string_builder(schema),
+                Some("object") => self.convert_object(schema)?,
+                Some("array") => self.convert_array(schema)?,
+                None if is_object(schema) => self.convert_object(schema)?,
+                None if schema.contains_key(&key("items")) => self.convert_array(schema)?,
+                None => string_builder(schema),
+                Some(other) => {
+                    return Err(BuildError::FailParseValue(
+                        other.to_string(),
+                        "OpenAPI type".to_string(),
+                        "Not supported type".to_string(),
+                    ))
+                }
+            }
+        };
+
+        Ok(if nullable {
+            builder.nullable()
+        } else {
+            builder
+        })
+    }
+
+    /// Convert the properties to the pairs of the key and the builder. The property which is not required is nullable.
+    fn convert_properties(
+        &mut self,
+        schema: &Mapping,
+    ) -> Result<Vec<(String, GeneratorBuilder)>, BuildError> {
+        let required: Vec<&str> = schema
+            .get(&key("required"))
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+
+        let mut properties = Vec::new();
+        for (property_key, property) in schema
+            .get(&key("properties"))
+            .and_then(Value::as_mapping)
+            .into_iter()
+            .flatten()
+        {
+            let property_key = match property_key.as_str() {
+                None => continue,
+                Some(property_key) => property_key,
+            };
+
+            let mut builder = self.convert(property)?;
+            if !required.contains(&property_key) {
+                builder = builder.nullable();
+            }
+            properties.push((property_key.to_string(), builder));
+        }
+
+        Ok(properties)
+    }
+
+    /// Convert `object` with `properties`
+    fn convert_object(&mut self, schema: &Mapping) -> Result<GeneratorBuilder, BuildError> {
+        let children = self
+            .convert_properties(schema)?
+            .into_iter()
+            .map(|(key, builder)| builder.into_child().key(key))
+            .collect();
+
+        Ok(GeneratorBuilder::new_object(children))
+    }
+
+    /// Convert `array` with `items`, `minItems` and `maxItems`
+    fn convert_array(&mut self, schema: &Mapping) -> Result<GeneratorBuilder, BuildError> {
+        let item = match schema.get(&key("items")) {
+            None => string_builder(&Mapping::new()),
+            Some(items) => self.convert(items)?,
+        };
+
+        let min_items = schema.get(&key("minItems")).and_then(Value::as_i64);
+        let max_items = schema.get(&key("maxItems")).and_then(Value::as_i64);
+        let (default_min, default_max) = DEFAULT_ITEMS_RANGE;
+        let start = min_items.unwrap_or_else(|| default_min.min(max_items.unwrap_or(default_min)));
+        let end = max_items.unwrap_or_else(|| default_max.max(start));
+
+        Ok(GeneratorBuilder::new_array(
+            Some(ValueBound::new(Some(start), Some((true, end)))),
+            vec![item.into_child()],
+        ))
+    }
+}
+
+/// Key of the schema
+fn key(key: &str) -> Value {
+    Value::String(key.to_string())
+}
+
+/// Check the schema is an object with `properties`
+fn is_object(schema: &Mapping) -> bool {
+    match schema.get(&key("type")) {
+        Some(Value::String(t)) => t == "object",
+        Some(Value::Sequence(types)) => types.iter().any(|t| t.as_str() == Some("object")),
+        _ => schema.contains_key(&key("properties")),
+    }
+}
+
+/// Merge the source schema into the target schema. `properties` and `required` are joined and the others are overridden.
+fn merge_schema(target: &mut Mapping, source: Mapping) {
+    for (k, v) in source.into_iter() {
+        match (target.get_mut(&k), v) {
+            (Some(Value::Mapping(target_properties)), Value::Mapping(properties))
+                if k.as_str() == Some("properties") =>
+            {
+                for (property_key, property) in properties.into_iter() {
+                    target_properties.insert(property_key, property);
+                }
+            }
+            (Some(Value::Sequence(target_required)), Value::Sequence(required))
+                if k.as_str() == Some("required") =>
+            {
+                target_required.extend(required);
+            }
+            (_, v) => {
+                target.insert(k, v);
+            }
+        }
+    }
+}
+
+/// Get the number at the key of the schema
+fn get_number(schema: &Mapping, key_name: &str) -> Option<SbrdReal> {
+    schema.get(&key(key_name)).and_then(Value::as_f64)
+}
+
+/// Get the lower bound and whether it is exclusive from `minimum` and `exclusiveMinimum`.
+/// `exclusiveMinimum` is a flag in OpenAPI 3.0 and a number in OpenAPI 3.1.
+fn get_minimum(schema: &Mapping) -> Option<(SbrdReal, bool)> {
+    match (
+        get_number(schema, "minimum"),
+        schema.get(&key("exclusiveMinimum")),
+    ) {
+        (_, Some(Value::Number(exclusive))) => exclusive.as_f64().map(|v| (v, true)),
+        (Some(minimum), Some(Value::Bool(exclusive))) => Some((minimum, *exclusive)),
+        (Some(minimum), _) => Some((minimum, false)),
+        (None, _) => None,
+    }
+}
+
+/// Get the upper bound and whether it is exclusive from `maximum` and `exclusiveMaximum`.
+/// `exclusiveMaximum` is a flag in OpenAPI 3.0 and a number in OpenAPI 3.1.
+fn get_maximum(schema: &Mapping) -> Option<(SbrdReal, bool)> {
+    match (
+        get_number(schema, "maximum"),
+        schema.get(&key("exclusiveMaximum")),
+    ) {
+        (_, Some(Value::Number(exclusive))) => exclusive.as_f64().map(|v| (v, true)),
+        (Some(maximum), Some(Value::Bool(exclusive))) => Some((maximum, *exclusive)),
+        (Some(maximum), _) => Some((maximum, false)),
+        (None, _) => None,
+    }
+}
+
+/// Get the range of the integer
+fn int_range(schema: &Mapping) -> Option<ValueBound<SbrdInt>> {
+    let start = get_minimum(schema).map(|(minimum, exclusive)| {
+        if exclusive {
+            minimum.floor() as SbrdInt + 1
+        } else {
+            minimum.ceil() as SbrdInt
+        }
+    });
+    let end = get_maximum(schema).map(|(maximum, exclusive)| {
+        if exclusive {
+            (false, maximum.ceil() as SbrdInt)
+        } else {
+            (true, maximum.floor() as SbrdInt)
+        }
+    });
+    if start.is_none() && end.is_none() {
+        return None;
+    }
+
+    Some(ValueBound::new(start, end))
+}
+
+/// Get the range of the number. The exclusive lower bound is treated as inclusive.
+fn real_range(schema: &Mapping) -> Option<ValueBound<SbrdReal>> {
+    let start = get_minimum(schema).map(|(minimum, _)| minimum);
+    let end = get_maximum(schema).map(|(maximum, exclusive)| (!exclusive, maximum));
+    if start.is_none() && end.is_none() {
+        return None;
+    }
+
+    Some(ValueBound::new(start, end))
+}
+
+/// Convert `string` with `format`, `pattern`, `minLength` and `maxLength`
+fn string_builder(schema: &Mapping) -> GeneratorBuilder {
+    match schema.get(&key("format")).and_then(Value::as_str) {
+        Some("date-time") => return GeneratorBuilder::new_date_time(None, None),
+        Some("date") => return GeneratorBuilder::new_date(None, None),
+        Some("time") => return GeneratorBuilder::new_time(None, None),
+        Some("email") => return GeneratorBuilder::new_email(None, None),
+        Some("uri") | Some("url") => return GeneratorBuilder::new_url(None, None),
+        Some("uuid") => return GeneratorBuilder::new_uuid_v4(None),
+        _ => {}
+    }
+
+    if let Some(pattern) = schema.get(&key("pattern")).and_then(Value::as_str) {
+        return GeneratorBuilder::new_regex(pattern, None);
+    }
+
+    let min_length = schema.get(&key("minLength")).and_then(Value::as_u64);
+    let max_length = schema.get(&key("maxLength")).and_then(Value::as_u64);
+    match (min_length, max_length) {
+        (None, None) => GeneratorBuilder::new_lorem(None, None),
+        (min_length, Some(max_length)) => {
+            let min_length = min_length.unwrap_or_else(|| max_length.min(1));
+            GeneratorBuilder::new_regex(
+                format!("[a-zA-Z0-9]{{{},{}}}", min_length, max_length),
+                None,
+            )
+        }
+        (Some(min_length), None) => {
+            GeneratorBuilder::new_regex(format!("[a-zA-Z0-9]{{{},}}", min_length), None)
+        }
+    }
+}
+
+/// Convert `enum` as the type to the builder selecting the values
+fn select_builder(
+    schema_type: Option<&str>,
+    values: &[Value],
+) -> Result<GeneratorBuilder, BuildError> {
+    let invalid_value = |value: &Value, type_name: &str| {
+        BuildError::FailParseValue(
+            format!("{:?}", value),
+            type_name.to_string(),
+            "Invalid value in enum".to_string(),
+        )
+    };
+
+    match schema_type {
+        Some("integer") => {
+            let mut selectable: Vec<SbrdInt> = Vec::new();
+            for value in values.iter() {
+                selectable.push(value.as_i64().ok_or_else(|| invalid_value(value, "Int"))?);
+            }
+
+            Ok(GeneratorBuilder::new_select_int(
+                None,
+                Some(selectable),
+                None,
+            ))
+        }
+        Some("number") => {
+            let mut selectable: Vec<SbrdReal> = Vec::new();
+            for value in values.iter() {
+                selectable.push(value.as_f64().ok_or_else(|| invalid_value(value, "Real"))?);
+            }
+
+            Ok(GeneratorBuilder::new_select_real(
+                None,
+                Some(selectable),
+                None,
+            ))
+        }
+        _ => {
+            let mut selectable: Vec<SbrdString> = Vec::new();
+            for value in values.iter() {
+                selectable.push(match value {
+                    Value::String(s) => s.clone(),
+                    Value::Number(n) => n.to_string(),
+                    Value::Bool(b) => b.to_string(),
+                    _ => return Err(invalid_value(value, "String")),
+                });
+            }
+
+            Ok(GeneratorBuilder::new_select_string(
+                None,
+                Some(selectable),
+                None,
+            ))
+        }
+    }
+}
+
+/// Escape the token of JSON pointer
+fn escape_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
@@ -0,0 +1,909 @@
+//! Module for parser of SQL DDL
+//!
+//! The parser reads `CREATE TABLE` statements in the subsets of PostgreSQL, MySQL and SQLite, and the other statements are ignored.
+//! Each column is converted to the builder of the generator as follows.
+//! * `SERIAL`, `BIGSERIAL`, `SMALLSERIAL`, `AUTO_INCREMENT`, `AUTOINCREMENT` and `GENERATED ... AS IDENTITY` become [`GeneratorType::IncrementId`].
+//! * The integer types become [`GeneratorType::Int`], and the floating point and the fixed point types become [`GeneratorType::Real`].
+//!   The fixed point type with the precision and the scale such as `NUMERIC(p, s)` becomes [`GeneratorType::EvalReal`]
+//!   generating the real number within the precision and rounded to the scale.
+//! * `BOOLEAN`, `BOOL` and `TINYINT(1)` become [`GeneratorType::Bool`].
+//! * The timestamp types become [`GeneratorType::DateTime`], `DATE` becomes [`GeneratorType::Date`] and `TIME` becomes [`GeneratorType::Time`].
+//! * `UUID` becomes [`GeneratorType::UuidV4`].
+//! * `ENUM(...)` of MySQL and the type created by `CREATE TYPE ... AS ENUM (...)` of PostgreSQL become [`GeneratorType::SelectString`].
+//! * The character types with the length such as `VARCHAR(n)` and `CHAR(n)` become [`GeneratorType::Regex`] with the bounded length
+//!   including the empty string for the length 0, and the other types such as `TEXT` become [`GeneratorType::Lorem`].
+//!
+//! The column without `NOT NULL` and `PRIMARY KEY` is nullable, and the column with `UNIQUE` or `PRIMARY KEY` is unique.
+//! `CHECK` constraints joined with `AND` such as `x BETWEEN a AND b`, `x >= a` and `x IN (a, b)` become the range and the selectable values.
+//!
+//! # Examples
+//! ```
+//! fn main() {
+//!     use sbrd_gen::builder::GeneratorBuilder;
+//!     use sbrd_gen::parser::{SchemaParser, SqlParser};
+//!     use sbrd_gen::SchemaBuilder;
+//!
+//!     let ddl = r#"
+//! CREATE TABLE users (
+//!     id SERIAL PRIMARY KEY,
+//!     age INTEGER NOT NULL CHECK (age BETWEEN 0 AND 120),
+//!     rank VARCHAR(1) NOT NULL CHECK (rank IN ('A', 'B')),
+//!     score REAL
+//! );
+//! "#;
+//!
+//!     assert_eq!(
+//!         SchemaBuilder::new(
+//!             vec!["id".to_string(), "age".to_string(), "rank".to_string(), "score".to_string()],
+//!             vec![
+//!                 GeneratorBuilder::new_increment_id(None).into_parent("id"),
+//!                 GeneratorBuilder::new_int(Some((0..=120).into())).into_parent("age"),
+//!                 GeneratorBuilder::new_select_string(None, Some(vec!["A".to_string(), "B".to_string()]), None)
+//!                     .into_parent("rank"),
+//!                 GeneratorBuilder::new_real(None).nullable().into_parent("score"),
+//!             ],
+//!         ),
+//!         SqlParser::parse_from_str(ddl).unwrap()
+//!     );
+//! }
+//! ```
+//!
+//! [`GeneratorType::IncrementId`]: ../enum.GeneratorType.html#variant.IncrementId
+//! [`GeneratorType::Int`]: ../enum.GeneratorType.html#variant.Int
+//! [`GeneratorType::Real`]: ../enum.GeneratorType.html#variant.Real
+//! [`GeneratorType::Bool`]: ../enum.GeneratorType.html#variant.Bool
+//! [`GeneratorType::DateTime`]: ../enum.GeneratorType.html#variant.DateTime
+//! [`GeneratorType::Date`]: ../enum.GeneratorType.html#variant.Date
+//! [`GeneratorType::Time`]: ../enum.GeneratorType.html#variant.Time
+//! [`GeneratorType::UuidV4`]: ../enum.GeneratorType.html#variant.UuidV4
+//! [`GeneratorType::SelectString`]: ../enum.GeneratorType.html#variant.SelectString
+//! [`GeneratorType::Regex`]: ../enum.GeneratorType.html#variant.Regex
+//! [`GeneratorType::Lorem`]: ../enum.GeneratorType.html#variant.Lorem
+
+use crate::builder::{GeneratorBuilder, ValueBound};
+use crate::error::{BuildError, IntoSbrdError, SchemaErrorKind, SchemaResult};
+use crate::parser::SchemaParser;
+use crate::value::{DataValue, SbrdInt, SbrdReal};
+use crate::{GeneratorType, SchemaBuilder, TableSchemaBuilder, TablesSchemaBuilder};
+use std::collections::HashMap;
+
+/// Count of the generated values sets for each table parsed as the tables by [`SqlParser`]
+///
+/// [`SqlParser`]: ./struct.SqlParser.html
+pub const DEFAULT_SQL_TABLE_COUNT: u64 = 10;
+
+/// Parser for a schema written as `CREATE TABLE` statements of SQL
+///
+/// The schema must have only one table for [`SchemaParser::parse_from_str`],
+/// and each table is generated [`DEFAULT_SQL_TABLE_COUNT`] times for [`SchemaParser::parse_tables_from_str`].
+///
+/// [`SchemaParser::parse_from_str`]: ./trait.SchemaParser.html#tymethod.parse_from_str
+/// [`SchemaParser::parse_tables_from_str`]: ./trait.SchemaParser.html#tymethod.parse_tables_from_str
+/// [`DEFAULT_SQL_TABLE_COUNT`]: ./constant.DEFAULT_SQL_TABLE_COUNT.html
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub struct SqlParser;
+impl SqlParser {
+    /// Parse the tables created in the statements as the pairs of the table's name and the schema
+    fn parse_tables(input: &str) -> SchemaResult<Vec<(String, SchemaBuilder)>> {
+        let tokens = tokenize(input).map_err(|e| {
+            BuildError::FailParseValue("the input".to_string(), "SQL".to_string(), e)
+                .into_sbrd_gen_error(SchemaErrorKind::ParseError)
+        })?;
+
+        let mut enum_types: HashMap<String, Vec<String>> = HashMap::new();
+        let mut tables = Vec::new();
+        for statement in split_by(&tokens, ";") {
+            if statement.is_empty() || !statement[0].is_keyword("CREATE") {
+                continue;
+            }
+
+            let mut index = 1;
+            while statement.get(index).is_some_and(|token| {
+                [
+                    "OR",
+                    "REPLACE",
+                    "TEMP",
+                    "TEMPORARY",
+                    "UNLOGGED",
+                    "GLOBAL",
+                    "LOCAL",
+                ]
+                .iter()
+                .any(|keyword| token.is_keyword(keyword))
+            }) {
+                index += 1;
+            }
+
+            match statement.get(index) {
+                Some(token) if token.is_keyword("TYPE") => {
+                    if let Some((name, values)) = parse_enum_type(&statement[index + 1..]) {
+                        enum_types.insert(name.to_lowercase(), values);
+                    }
+                }
+                Some(token) if token.is_keyword("TABLE") => {
+                    let table = parse_table(&statement[index + 1..], &enum_types)
+                        .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))?;
+                    tables.push(table);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(tables)
+    }
+
+    /// Read all from [`Read`]
+    ///
+    /// [`Read`]: https://doc.rust-lang.org/std/io/trait.Read.html
+    fn read_to_string<R: std::io::Read>(mut rdr: R) -> SchemaResult<String> {
+        let mut input = String::new();
+        rdr.read_to_string(&mut input)
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::ParseError))?;
+
+        Ok(input)
+    }
+}
+
+impl SchemaParser for SqlParser {
+    fn parse_from_str(input: &str) -> SchemaResult<SchemaBuilder>
+    where
+        Self: Sized,
+    {
+        let mut tables = Self::parse_tables(input)?;
+        if tables.len() != 1 {
+            return Err(BuildError::FailParseValue(
+                format!("{} tables", tables.len()),
+                "Schema".to_string(),
+                "The schema must have only one table. Parse as the tables for some tables."
+                    .to_string(),
+            )
+            .into_sbrd_gen_error(SchemaErrorKind::ParseError));
+        }
+
+        Ok(tables.remove(0).1)
+    }
+
+    fn parse_from_reader<R: std::io::Read>(rdr: R) -> SchemaResult<SchemaBuilder> {
+        Self::parse_from_str(&Self::read_to_string(rdr)?)
+    }
+
+    fn parse_tables_from_str(input: &str) -> SchemaResult<TablesSchemaBuilder> {
+        Ok(TablesSchemaBuilder::new(
+            Self::parse_tables(input)?
+                .into_iter()
+                .map(|(name, schema)| {
+                    TableSchemaBuilder::new(name, DEFAULT_SQL_TABLE_COUNT, schema)
+                })
+                .collect(),
+        ))
+    }
+
+    fn parse_tables_from_reader<R: std::io::Read>(rdr: R) -> SchemaResult<TablesSchemaBuilder> {
+        Self::parse_tables_from_str(&Self::read_to_string(rdr)?)
+    }
+}
+
+/// Token of SQL
+#[derive(Debug, PartialEq, Clone)]
+enum Token {
+    /// Keyword or identifier without quotes
+    Word(String),
+    /// Identifier with quotes
+    QuotedWord(String),
+    /// String literal
+    Str(String),
+    /// Number literal
+    Number(String),
+    /// Operator or punctuation
+    Symbol(String),
+}
+
+impl Token {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Token::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    fn is_symbol(&self, symbol: &str) -> bool {
+        matches!(self, Token::Symbol(s) if s == symbol)
+    }
+
+    fn as_identifier(&self) -> Option<&str> {
+        match self {
+            Token::Word(word) | Token::QuotedWord(word) => Some(word),
+            _ => None,
+        }
+    }
+}
+
+/// Split the input into the tokens without the comments
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '-' && next == Some('-') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            let end = find_sequence(&chars, i + 2, &['*', '/'])
+                .ok_or_else(|| "Not closed comment".to_string())?;
+            i = end + 2;
+        } else if c == '\'' || c == '"' || c == '`' {
+            let (text, end) = read_quoted(&chars, i)?;
+            tokens.push(if c == '\'' {
+                Token::Str(text)
+            } else {
+                Token::QuotedWord(text)
+            });
+            i = end;
+        } else if c == '$' && read_dollar_tag(&chars, i).is_some() {
+            // dollar-quoted string such as the body of the function in PostgreSQL
+            let tag = read_dollar_tag(&chars, i).unwrap_or_default();
+            let start = i + tag.len();
+            let end = find_sequence(&chars, start, &tag)
+                .ok_or_else(|| "Not closed dollar-quoted string".to_string())?;
+            tokens.push(Token::Str(chars[start..end].iter().collect()));
+            i = end + tag.len();
+        } else if c.is_ascii_digit() || (c == '.' && next.is_some_and(|n| n.is_ascii_digit())) {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                if (chars[i] == 'e' || chars[i] == 'E')
+                    && matches!(chars.get(i + 1), Some('+') | Some('-'))
+                {
+                    i += 1;
+                }
+                i += 1;
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '$')
+            {
+                i += 1;
+            }
+            tokens.push(Token::Word(chars[start..i].iter().collect()));
+        } else {
+            let pair: String = chars[i..(i + 2).min(chars.len())].iter().collect();
+            if [">=", "<=", "<>", "!=", "::", "||"].contains(&pair.as_str()) {
+                tokens.push(Token::Symbol(pair));
+                i += 2;
+            } else {
+                tokens.push(Token::Symbol(c.to_string()));
+                i += 1;
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Find the index of the sequence from the start
+fn find_sequence(chars: &[char], start: usize, sequence: &[char]) -> Option<usize> {
+    (start..chars.len()).find(|&i| chars[i..].starts_with(sequence))
+}
+
+/// Read the quoted text beginning at the start, and get it and the index after the closing quote.
+/// The doubled quote is an escaped quote.
+fn read_quoted(chars: &[char], start: usize) -> Result<(String, usize), String> {
+    let quote = chars[start];
+    let mut text = String::new();
+    let mut i = start + 1;
+    while i < chars.len() {
+        if chars[i] == quote {
+            if chars.get(i + 1) == Some(&quote) {
+                text.push(quote);
+                i += 2;
+                continue;
+            }
+
+            return Ok((text, i + 1));
+        }
+        text.push(chars[i]);
+        i += 1;
+    }
+
+    Err(format!("Not closed quote {}", quote))
+}
+
+/// Read the tag of the dollar-quoted string such as "$$" and "$body$"
+fn read_dollar_tag(chars: &[char], start: usize) -> Option<Vec<char>> {
+    let mut i = start + 1;
+    while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+        i += 1;
+    }
+
+    if chars.get(i) == Some(&'$') && !chars.get(start + 1).is_some_and(|c| c.is_ascii_digit()) {
+        Some(chars[start..=i].to_vec())
+    } else {
+        None
+    }
+}
+
+/// Split the tokens by the symbol outside the parentheses
+fn split_by<'a>(tokens: &'a [Token], symbol: &str) -> Vec<&'a [Token]> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        if token.is_symbol("(") {
+            depth += 1;
+        } else if token.is_symbol(")") {
+            depth -= 1;
+        } else if depth == 0 && token.is_symbol(symbol) {
+            parts.push(&tokens[start..i]);
+            start = i + 1;
+        }
+    }
+    parts.push(&tokens[start..]);
+
+    parts
+}
+
+/// Get the tokens in the parentheses beginning at the start and the index after the closing parenthesis
+fn take_group(tokens: &[Token], start: usize) -> Option<(&[Token], usize)> {
+    if !tokens.get(start)?.is_symbol("(") {
+        return None;
+    }
+
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        if token.is_symbol("(") {
+            depth += 1;
+        } else if token.is_symbol(")") {
+            depth -= 1;
+            if depth == 0 {
+                return Some((&tokens[start + 1..i], i + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Get the last part of the qualified name such as "public.users" and the index after the name
+fn take_name(tokens: &[Token], start: usize) -> Option<(String, usize)> {
+    let mut name = tokens.get(start)?.as_identifier()?.to_string();
+    let mut i = start + 1;
+    while tokens.get(i).is_some_and(|token| token.is_symbol(".")) {
+        name = tokens.get(i + 1)?.as_identifier()?.to_string();
+        i += 2;
+    }
+
+    Some((name, i))
+}
+
+/// Get the string literals separated by the commas
+fn take_strings(tokens: &[Token]) -> Vec<String> {
+    split_by(tokens, ",")
+        .into_iter()
+        .filter_map(|part| match part {
+            [Token::Str(s), ..] => Some(s.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Parse `name AS ENUM ('a', 'b')` after `CREATE TYPE`
+fn parse_enum_type(tokens: &[Token]) -> Option<(String, Vec<String>)> {
+    let (name, i) = take_name(tokens, 0)?;
+    if !tokens.get(i)?.is_keyword("AS") || !tokens.get(i + 1)?.is_keyword("ENUM") {
+        return None;
+    }
+    let (values, _) = take_group(tokens, i + 2)?;
+
+    Some((name, take_strings(values)))
+}
+
+/// Parse `[IF NOT EXISTS] name (columns and constraints)` after `CREATE TABLE`
+fn parse_table(
+    tokens: &[Token],
+    enum_types: &HashMap<String, Vec<String>>,
+) -> Result<(String, SchemaBuilder), BuildError> {
+    let mut i = 0;
+    if tokens.first().is_some_and(|token| token.is_keyword("IF")) {
+        i = 3;
+    }
+    let (name, i) = take_name(tokens, i).ok_or_else(|| {
+        BuildError::FailParseValue(
+            "CREATE TABLE".to_string(),
+            "SQL".to_string(),
+            "Not found the name of the table".to_string(),
+        )
+    })?;
+    let (definitions, _) = take_group(tokens, i).ok_or_else(|| {
+        BuildError::FailParseValue(
+            name.clone(),
+            "CREATE TABLE".to_string(),
+            "Not found the definitions of the columns".to_string(),
+        )
+    })?;
+
+    let mut columns: Vec<Column> = Vec::new();
+    let mut checks: Vec<&[Token]> = Vec::new();
+    let mut unique_keys: Vec<Vec<String>> = Vec::new();
+    let mut primary_keys: Vec<String> = Vec::new();
+    for definition in split_by(definitions, ",") {
+        let mut definition = definition;
+        if definition
+            .first()
+            .is_some_and(|t| t.is_keyword("CONSTRAINT"))
+        {
+            definition = definition.get(2..).unwrap_or_default();
+        }
+        let first = match definition.first() {
+            None => continue,
+            Some(first) => first,
+        };
+
+        if first.is_keyword("PRIMARY") || first.is_keyword("UNIQUE") {
+            let keys = definition
+                .iter()
+                .position(|t| t.is_symbol("("))
+                .and_then(|start| take_group(definition, start))
+                .map(|(keys, _)| {
+                    split_by(keys, ",")
+                        .into_iter()
+                        .filter_map(|key| key.first().and_then(Token::as_identifier))
+                        .map(|key| key.to_string())
+                        .collect::<Vec<String>>()
+                })
+                .unwrap_or_default();
+            if first.is_keyword("PRIMARY") {
+                primary_keys.extend(keys.iter().cloned());
+            }
+            unique_keys.push(keys);
+        } else if first.is_keyword("CHECK") {
+            if let Some((condition, _)) = take_group(definition, 1) {
+                checks.push(condition);
+            }
+        } else if [
+            "FOREIGN", "KEY", "INDEX", "FULLTEXT", "SPATIAL", "EXCLUDE", "LIKE",
+        ]
+        .iter()
+        .all(|keyword| !first.is_keyword(keyword))
+        {
+            let column = Column::parse(definition, enum_types, &mut checks).ok_or_else(|| {
+                BuildError::FailParseValue(
+                    name.clone(),
+                    "CREATE TABLE".to_string(),
+                    format!("Invalid definition of the column at {:?}", first),
+                )
+            })?;
+            columns.push(column);
+        }
+    }
+
+    for column in columns.iter_mut() {
+        if primary_keys.contains(&column.key) {
+            column.not_null = true;
+        }
+    }
+    for condition in checks.into_iter() {
+        apply_check(condition, &mut columns);
+    }
+
+    let mut keys = Vec::new();
+    let mut builders = Vec::new();
+    let mut composite_unique_keys = Vec::new();
+    for keys in unique_keys.into_iter() {
+        match keys.as_slice() {
+            [] => {}
+            [key] => {
+                if let Some(column) = columns.iter_mut().find(|c| &c.key == key) {
+                    column.unique = true;
+                }
+            }
+            _ => composite_unique_keys.push(keys),
+        }
+    }
+    for column in columns.into_iter() {
+        let key = column.key.clone();
+        builders.push(column.into_builder().into_parent(&key));
+        keys.push(key);
+    }
+
+    let mut schema = SchemaBuilder::new(keys, builders);
+    for keys in composite_unique_keys.into_iter() {
+        schema = schema.unique_keys(keys);
+    }
+
+    Ok((name, schema))
+}
+
+/// Column of the table
+struct Column {
+    key: String,
+    builder: GeneratorBuilder,
+    /// scale of the fixed point type
+    scale: Option<u32>,
+    not_null: bool,
+    unique: bool,
+}
+
+impl Column {
+    /// Parse the definition of the column and collect its `CHECK` constraints
+    fn parse<'a>(
+        tokens: &'a [Token],
+        enum_types: &HashMap<String, Vec<String>>,
+        checks: &mut Vec<&'a [Token]>,
+    ) -> Option<Column> {
+        let key = tokens.first()?.as_identifier()?.to_string();
+        let (type_name, mut i) = take_name(tokens, 1)?;
+        let mut type_name = type_name.to_uppercase();
+        let mut arguments: &[Token] = &[];
+        if let Some((group, end)) = take_group(tokens, i) {
+            arguments = group;
+            i = end;
+        }
+        while let Some(Token::Word(word)) = tokens.get(i) {
+            let word = word.to_uppercase();
+            match word.as_str() {
+                "PRECISION" | "VARYING" | "WITH" | "WITHOUT" | "TIME" | "ZONE" => {
+                    type_name = format!("{} {}", type_name, word);
+                    i += 1;
+                    if let Some((group, end)) = take_group(tokens, i) {
+                        arguments = group;
+                        i = end;
+                    }
+                }
+                "UNSIGNED" | "SIGNED" | "ZEROFILL" => i += 1,
+                _ => break,
+            }
+        }
+
+        let mut not_null = false;
+        let mut unique = false;
+        let mut auto_increment = false;
+        while i < tokens.len() {
+            let token = &tokens[i];
+            if token.is_keyword("NOT") && tokens.get(i + 1).is_some_and(|t| t.is_keyword("NULL")) {
+                not_null = true;
+                i += 2;
+            } else if token.is_keyword("PRIMARY") {
+                not_null = true;
+                unique = true;
+                i += 1;
+            } else if token.is_keyword("UNIQUE") {
+                unique = true;
+                i += 1;
+            } else if token.is_keyword("AUTO_INCREMENT")
+                || token.is_keyword("AUTOINCREMENT")
+                || token.is_keyword("IDENTITY")
+            {
+                auto_increment = true;
+                i += 1;
+            } else if token.is_keyword("CHECK") {
+                match take_group(tokens, i + 1) {
+                    None => i += 1,
+                    Some((condition, end)) => {
+                        checks.push(condition);
+                        i = end;
+                    }
+                }
+            } else if let Some((_, end)) = take_group(tokens, i) {
+                i = end;
+            } else {
+                i += 1;
+            }
+        }
+
+        let builder = if auto_increment || type_name.ends_with("SERIAL") {
+            GeneratorBuilder::new_increment_id(None)
+        } else {
+            type_builder(&type_name, arguments, enum_types)
+        };
+        let scale = precision_and_scale(&type_name, arguments).map(|(_, scale)| scale);
+
+        Some(Column {
+            key,
+            builder,
+            scale,
+            not_null,
+            unique,
+        })
+    }
+
+    /// Convert into the builder with `nullable` and `unique`
+    fn into_builder(self) -> GeneratorBuilder {
+        let Column {
+            mut builder,
+            scale,
+            not_null,
+            unique,
+            ..
+        } = self;
+        if builder.generator_type == GeneratorType::IncrementId {
+            return builder;
+        }
+
+        if let Some(scale) = scale {
+            builder = round_to_scale(builder, scale);
+        }
+
+        if !not_null {
+            builder = builder.nullable();
+        }
+        if unique {
+            builder = builder.unique();
+        }
+
+        builder
+    }
+}
+
+/// Convert the type of the column to the builder
+fn type_builder(
+    type_name: &str,
+    arguments: &[Token],
+    enum_types: &HashMap<String, Vec<String>>,
+) -> GeneratorBuilder {
+    let length = match arguments {
+        [Token::Number(length), ..] => length.parse::<u32>().ok(),
+        _ => None,
+    };
+
+    match type_name {
+        "TINYINT" if length == Some(1) => GeneratorBuilder::new_bool(),
+        "INT" | "INTEGER" | "SMALLINT" | "BIGINT" | "TINYINT" | "MEDIUMINT" | "INT2" | "INT4"
+        | "INT8" => GeneratorBuilder::new_int(None),
+        "DECIMAL" | "NUMERIC" => {
+            let limit = precision_and_scale(type_name, arguments).map(|(precision, scale)| {
+                10f64.powi((precision - scale) as i32) - 10f64.powi(-(scale as i32))
+            });
+            match limit {
+                Some(limit) if limit.is_finite() => GeneratorBuilder::new_real(Some(
+                    ValueBound::new(Some(-limit), Some((true, limit))),
+                )),
+                _ => GeneratorBuilder::new_real(None),
+            }
+        }
+        "REAL" | "FLOAT" | "FLOAT4" | "FLOAT8" | "DOUBLE" | "DOUBLE PRECISION" => {
+            GeneratorBuilder::new_real(None)
+        }
+        "BOOLEAN" | "BOOL" => GeneratorBuilder::new_bool(),
+        "TIMESTAMP"
+        | "TIMESTAMPTZ"
+        | "DATETIME"
+        | "TIMESTAMP WITH TIME ZONE"
+        | "TIMESTAMP WITHOUT TIME ZONE" => GeneratorBuilder::new_date_time(None, None),
+        "DATE" => GeneratorBuilder::new_date(None, None),
+        "TIME" | "TIMETZ" | "TIME WITH TIME ZONE" | "TIME WITHOUT TIME ZONE" => {
+            GeneratorBuilder::new_time(None, None)
+        }
+        "UUID" => GeneratorBuilder::new_uuid_v4(None),
+        "ENUM" => GeneratorBuilder::new_select_string(None, Some(take_strings(arguments)), None),
+        "CHAR" | "CHARACTER" | "NCHAR" => match length {
+            Some(length) => GeneratorBuilder::new_regex(format!("[a-zA-Z0-9]{{{}}}", length), None),
+            None => GeneratorBuilder::new_regex("[a-zA-Z0-9]", None),
+        },
+        _ => match enum_types.get(&type_name.to_lowercase()) {
+            Some(values) => GeneratorBuilder::new_select_string(None, Some(values.clone()), None),
+            None => match length {
+                Some(length) => GeneratorBuilder::new_regex(
+                    format!("[a-zA-Z0-9]{{{},{}}}", length.min(1), length),
+                    None,
+                ),
+                None => GeneratorBuilder::new_lorem(None, None),
+            },
+        },
+    }
+}
+
+/// Get the precision and the scale of the fixed point type such as `NUMERIC(p)` and `NUMERIC(p, s)`
+fn precision_and_scale(type_name: &str, arguments: &[Token]) -> Option<(u32, u32)> {
+    if type_name != "DECIMAL" && type_name != "NUMERIC" {
+        return None;
+    }
+
+    let (precision, scale) = match arguments {
+        [Token::Number(precision)] => (precision.parse::<u32>().ok()?, 0),
+        [Token::Number(precision), comma, Token::Number(scale)] if comma.is_symbol(",") => {
+            (precision.parse::<u32>().ok()?, scale.parse::<u32>().ok()?)
+        }
+        _ => return None,
+    };
+
+    (precision > 0 && scale <= precision).then_some((precision, scale))
+}
+
+/// Convert the builder of the real number with the range into the builder rounding it to the scale
+fn round_to_scale(builder: GeneratorBuilder, scale: u32) -> GeneratorBuilder {
+    if builder.generator_type != GeneratorType::Real {
+        return builder;
+    }
+
+    match builder
+        .range
+        .as_ref()
+        .map(|range| (range.get_start(), range.get_end()))
+    {
+        Some((Some(start), Some(end))) => GeneratorBuilder::new_eval_real(format!(
+            "round(rand_real({}, {}), {})",
+            start.to_parse_string(),
+            end.to_parse_string(),
+            scale
+        )),
+        _ => builder,
+    }
+}
+
+/// Apply the `CHECK` condition joined with `AND` to the columns. The condition which is not supported is ignored.
+fn apply_check(condition: &[Token], columns: &mut [Column]) {
+    let mut i = 0;
+    let mut terms: Vec<(String, Term)> = Vec::new();
+    while i < condition.len() {
+        let key = match condition[i].as_identifier() {
+            None => return,
+            Some(key) => key.to_string(),
+        };
+        i += 1;
+
+        let term = match condition.get(i) {
+            Some(token) if token.is_keyword("BETWEEN") => {
+                let (start, next) = match take_literal(condition, i + 1) {
+                    None => return,
+                    Some(start) => start,
+                };
+                if !condition.get(next).is_some_and(|t| t.is_keyword("AND")) {
+                    return;
+                }
+                let (end, next) = match take_literal(condition, next + 1) {
+                    None => return,
+                    Some(end) => end,
+                };
+                i = next;
+                Term::Between(start, end)
+            }
+            Some(token) if token.is_keyword("IN") => {
+                let (values, next) = match take_group(condition, i + 1) {
+                    None => return,
+                    Some(group) => group,
+                };
+                let mut literals = Vec::new();
+                for value in split_by(values, ",") {
+                    match take_literal(value, 0) {
+                        Some((literal, end)) if end == value.len() => literals.push(literal),
+                        _ => return,
+                    }
+                }
+                i = next;
+                Term::In(literals)
+            }
+            Some(Token::Symbol(operator))
+                if [">=", ">", "<=", "<"].contains(&operator.as_str()) =>
+            {
+                let (value, next) = match take_literal(condition, i + 1) {
+                    None => return,
+                    Some(value) => value,
+                };
+                i = next;
+                Term::Compare(operator.clone(), value)
+            }
+            _ => return,
+        };
+        terms.push((key, term));
+
+        if i < condition.len() {
+            if !condition[i].is_keyword("AND") {
+                return;
+            }
+            i += 1;
+        }
+    }
+
+    for (key, term) in terms.into_iter() {
+        if let Some(column) = columns.iter_mut().find(|c| c.key == key) {
+            term.apply(&mut column.builder);
+        }
+    }
+}
+
+/// Get the literal as the text and the index after it
+fn take_literal(tokens: &[Token], start: usize) -> Option<(String, usize)> {
+    match (tokens.get(start)?, tokens.get(start + 1)) {
+        (Token::Symbol(sign), Some(Token::Number(number))) if sign == "-" || sign == "+" => Some((
+            format!("{}{}", sign.trim_start_matches('+'), number),
+            start + 2,
+        )),
+        (Token::Number(number), _) | (Token::Str(number), _) => Some((number.clone(), start + 1)),
+        _ => None,
+    }
+}
+
+/// Supported term of `CHECK` condition
+enum Term {
+    /// `x BETWEEN a AND b`
+    Between(String, String),
+    /// `x IN (a, b, ...)`
+    In(Vec<String>),
+    /// `x >= a`, `x > a`, `x <= a` or `x < a`
+    Compare(String, String),
+}
+
+impl Term {
+    /// Apply the term as the range or the selectable values of the builder
+    fn apply(self, builder: &mut GeneratorBuilder) {
+        let is_int = builder.generator_type == GeneratorType::Int;
+        let is_real = builder.generator_type == GeneratorType::Real;
+        let is_date_time = [
+            GeneratorType::DateTime,
+            GeneratorType::Date,
+            GeneratorType::Time,
+        ]
+        .contains(&builder.generator_type);
+        let to_value = |literal: &str| -> Option<DataValue> {
+            if is_int {
+                literal.parse::<SbrdInt>().ok().map(DataValue::Int)
+            } else if is_real {
+                literal.parse::<SbrdReal>().ok().map(DataValue::Real)
+            } else if is_date_time {
+                Some(DataValue::String(literal.to_string()))
+            } else {
+                None
+            }
+        };
+
+        match self {
+            Term::In(literals) => {
+                let nullable = builder.nullable;
+                let unique = builder.unique;
+                let selectable = if is_int {
+                    literals
+                        .iter()
+                        .map(|l| l.parse::<SbrdInt>().ok())
+                        .collect::<Option<Vec<SbrdInt>>>()
+                        .map(|values| GeneratorBuilder::new_select_int(None, Some(values), None))
+                } else if is_real {
+                    literals
+                        .iter()
+                        .map(|l| l.parse::<SbrdReal>().ok())
+                        .collect::<Option<Vec<SbrdReal>>>()
+                        .map(|values| GeneratorBuilder::new_select_real(None, Some(values), None))
+                } else {
+                    Some(GeneratorBuilder::new_select_string(
+                        None,
+                        Some(literals),
+                        None,
+                    ))
+                };
+                if let Some(mut selectable) = selectable {
+                    selectable.nullable = nullable;
+                    selectable.unique = unique;
+                    *builder = selectable;
+                }
+            }
+            Term::Between(start, end) => {
+                if let (Some(start), Some(end)) = (to_value(&start), to_value(&end)) {
+                    builder.range = Some(ValueBound::new(Some(start), Some((true, end))));
+                }
+            }
+            Term::Compare(operator, literal) => {
+                let value = match to_value(&literal) {
+                    None => return,
+                    Some(value) => value,
+                };
+                let range = builder.range.take().unwrap_or_else(ValueBound::new_full);
+                let start = range.get_start().clone();
+                let end = range
+                    .get_end()
+                    .clone()
+                    .map(|end| (range.is_include_end(), end));
+                builder.range = Some(match operator.as_str() {
+                    ">=" => ValueBound::new(Some(value), end),
+                    ">" => match value {
+                        DataValue::Int(v) => ValueBound::new(Some(DataValue::Int(v + 1)), end),
+                        value => ValueBound::new(Some(value), end),
+                    },
+                    "<=" => ValueBound::new(start, Some((true, value))),
+                    _ => ValueBound::new(start, Some((false, value))),
+                });
+            }
+        }
+    }
+}
@@ -2,11 +2,23 @@
 //! Module for schema
 
 use crate::builder::ParentGeneratorBuilder;
+use crate::check::{check_schema, collect_referenced_keys, CheckFinding};
 use crate::error::{BuildError, GenerateError, IntoSbrdError, SchemaErrorKind, SchemaResult};
-use crate::generator::{GeneratorBase, Randomizer};
-use crate::value::{DataValue, DataValueMap};
+use crate::generator::{
+    build_indexed_randomizer, generate_with_index, GeneratorBase, Randomizer, RandomizerSeed,
+    SeededRandomizer,
+};
+use crate::json_schema::{with_dialect, Describer};
+use crate::value::{DataValue, DataValueMap, DateTimeRepresentation, ValueMap};
 use serde::ser::Error;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, PoisonError};
+use std::thread;
+
+/// Default count of retrying to generate the unique values
+pub const DEFAULT_UNIQUE_RETRY_COUNT: u64 = 1000;
 
 /// Builder for [`Schema`] is consisting of values at `keys` key that need to be output and builders at `generators` key
 ///
@@ -16,22 +28,183 @@ pub struct SchemaBuilder {
     keys: Vec<String>,
     #[serde(rename = "generators")]
     builders: Vec<ParentGeneratorBuilder>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    unique_keys: Vec<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unique_retry: Option<u64>,
 }
 
 impl SchemaBuilder {
     /// Constructor
     pub fn new(keys: Vec<String>, builders: Vec<ParentGeneratorBuilder>) -> SchemaBuilder {
-        SchemaBuilder { keys, builders }
+        SchemaBuilder {
+            keys,
+            builders,
+            unique_keys: Vec::new(),
+            unique_retry: None,
+        }
+    }
+
+    /// Add the keys whose combination of the generated values must be unique
+    pub fn unique_keys(mut self, keys: Vec<String>) -> Self {
+        self.unique_keys.push(keys);
+        self
+    }
+
+    /// Set count of retrying to generate the unique values.
+    ///
+    /// If not set, use [`DEFAULT_UNIQUE_RETRY_COUNT`].
+    ///
+    /// [`DEFAULT_UNIQUE_RETRY_COUNT`]: ./constant.DEFAULT_UNIQUE_RETRY_COUNT.html
+    pub fn unique_retry(mut self, retry_count: u64) -> Self {
+        self.unique_retry = Some(retry_count);
+        self
+    }
+
+    /// Get specified keys
+    pub(crate) fn get_keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// Get builders for the generators
+    pub(crate) fn get_builders(&self) -> &[ParentGeneratorBuilder] {
+        &self.builders
     }
 
-    /// Build schema structure
+    /// Get names of the tables referenced by the builders
+    pub(crate) fn get_referenced_tables(&self) -> Vec<&str> {
+        self.builders
+            .iter()
+            .flat_map(|parent_builder| parent_builder.builder.get_referenced_tables())
+            .collect()
+    }
+
+    /// Replace `values` option with the records of the referenced table for the builders
+    pub(crate) fn set_referenced_records(
+        &mut self,
+        records_map: &ValueMap<String, Vec<DataValue>>,
+    ) {
+        for parent_builder in self.builders.iter_mut() {
+            parent_builder.builder.set_referenced_records(records_map);
+        }
+    }
+
+    /// Check the generators without building and generating.
+    ///
+    /// Report the references to the keys which are not generated before the generator in the scripts and the formats,
+    /// the syntax errors of the scripts, the unreachable cases, the unused generators and the misuse of `include_end`.
+    /// Even if no error is found, [`SchemaBuilder::build`] may fail with the other error.
+    ///
+    /// # Examples
+    /// ```
+    /// fn main() {
+    ///     use sbrd_gen::builder::GeneratorBuilder;
+    ///     use sbrd_gen::check::CheckKind;
+    ///     use sbrd_gen::SchemaBuilder;
+    ///
+    ///     let schema_builder = SchemaBuilder::new(
+    ///         vec!["name".to_string(), "id".to_string()],
+    ///         vec![
+    ///             GeneratorBuilder::new_format("name-{user_id}").into_parent("name"),
+    ///             GeneratorBuilder::new_increment_id(None).into_parent("id"),
+    ///         ],
+    ///     );
+    ///     let findings = schema_builder.check();
+    ///
+    ///     assert_eq!(1, findings.len());
+    ///     assert_eq!("name", findings[0].get_key());
+    ///     assert_eq!(
+    ///         &CheckKind::UnknownKey("user_id".to_string(), "format".to_string()),
+    ///         findings[0].get_kind()
+    ///     );
+    ///
+    ///     let schema_builder = SchemaBuilder::new(
+    ///         vec!["a".to_string(), "b".to_string()],
+    ///         vec![
+    ///             GeneratorBuilder::new_format("{b}").into_parent("a"),
+    ///             GeneratorBuilder::new_format("{a}").into_parent("b"),
+    ///         ],
+    ///     );
+    ///     let findings = schema_builder.check();
+    ///
+    ///     assert_eq!(1, findings.len());
+    ///     assert_eq!(
+    ///         &CheckKind::CyclicKeyReference(vec!["a".to_string(), "b".to_string(), "a".to_string()]),
+    ///         findings[0].get_kind()
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// [`SchemaBuilder::build`]: ./struct.SchemaBuilder.html#method.build
+    pub fn check(&self) -> Vec<CheckFinding> {
+        check_schema(&self.keys, &self.builders, &self.unique_keys)
+    }
+
+    /// Describe the values set generated by this schema as JSON Schema (draft 2020-12).
+    ///
+    /// Each values set is described as an object consisting of the specified keys.
+    /// The date-time, date and time values are described as they are written with the representation.
+    ///
+    /// # Examples
+    /// ```
+    /// fn main() {
+    ///     use sbrd_gen::builder::{GeneratorBuilder, ValueBound};
+    ///     use sbrd_gen::value::{DataValue, DateTimeRepresentation};
+    ///     use sbrd_gen::SchemaBuilder;
+    ///
+    ///     let schema_builder = SchemaBuilder::new(
+    ///         vec!["age".to_string(), "rank".to_string()],
+    ///         vec![
+    ///             GeneratorBuilder::new_int(Some(ValueBound::new(Some(0), Some((true, 120)))))
+    ///                 .into_parent("age"),
+    ///             GeneratorBuilder::new_select_string(
+    ///                 None,
+    ///                 Some(vec!["A".to_string(), "B".to_string()]),
+    ///                 None,
+    ///             )
+    ///                 .nullable()
+    ///                 .into_parent("rank"),
+    ///         ],
+    ///     );
+    ///     let json_schema = schema_builder
+    ///         .to_json_schema(&DateTimeRepresentation::Format)
+    ///         .unwrap();
+    ///
+    ///     assert_eq!("integer", json_schema["properties"]["age"]["type"]);
+    ///     assert_eq!(120, json_schema["properties"]["age"]["maximum"]);
+    ///     assert_eq!(
+    ///         serde_json::json!(["A", "B", null]),
+    ///         json_schema["properties"]["rank"]["enum"]
+    ///     );
+    /// }
+    /// ```
+    pub fn to_json_schema(
+        &self,
+        representation: &DateTimeRepresentation,
+    ) -> SchemaResult<serde_json::Value> {
+        Describer::new(representation)
+            .describe_values(&self.keys, &self.builders)
+            .map(with_dialect)
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::BuildError))
+    }
+
+    /// Build schema structure.
+    ///
+    /// The generators are sorted so that the generator whose key is referenced by `format`, `script` and `case` of another generator precedes it.
+    /// The specified order is kept as much as possible, and the generators referencing each other cause [`BuildError::CyclicKeyReference`].
+    ///
+    /// [`BuildError::CyclicKeyReference`]: ./error/enum.BuildError.html#variant.CyclicKeyReference
     pub fn build<R: Randomizer + ?Sized>(self) -> SchemaResult<Schema<R>> {
         let SchemaBuilder {
             keys: specified_keys,
             builders,
+            unique_keys: composite_unique_keys,
+            unique_retry,
         } = self;
         let mut generators = Vec::new();
+        let mut sorted_builders = Vec::new();
         let mut checked = Vec::new();
+        let mut unique_keys = Vec::new();
 
         // check specified key's unique
         let mut cloned = specified_keys.clone();
@@ -42,14 +215,24 @@ impl SchemaBuilder {
                 .into_sbrd_gen_error(SchemaErrorKind::BuildError));
         }
 
-        for parent_builder in builders.into_iter() {
-            let (key, builder) = parent_builder.split_key();
-
-            if checked.contains(&key) {
-                return Err(BuildError::AlreadyExistKey(key)
+        // check generator's key unique
+        for (i, parent_builder) in builders.iter().enumerate() {
+            if builders[..i]
+                .iter()
+                .any(|other| other.key == parent_builder.key)
+            {
+                return Err(BuildError::AlreadyExistKey(parent_builder.key.clone())
                     .into_sbrd_gen_error(SchemaErrorKind::BuildError));
             }
+        }
 
+        for parent_builder in sort_by_dependency(builders)?.into_iter() {
+            sorted_builders.push(parent_builder.clone());
+            let (key, builder) = parent_builder.split_key();
+
+            if builder.unique {
+                unique_keys.push(UniqueKeys::new(vec![key.clone()]));
+            }
             let generator = builder.build()?;
             generators.push((key.clone(), generator));
             checked.push(key);
@@ -63,18 +246,225 @@ impl SchemaBuilder {
             }
         }
 
+        for mut keys in composite_unique_keys.into_iter() {
+            keys.sort();
+            keys.dedup();
+            if keys.is_empty() {
+                return Err(
+                    BuildError::EmptyUniqueKeys.into_sbrd_gen_error(SchemaErrorKind::BuildError)
+                );
+            }
+
+            for key in keys.iter() {
+                if !checked.contains(key) {
+                    return Err(BuildError::NotExistSpecifiedKey(key.to_string(), checked)
+                        .into_sbrd_gen_error(SchemaErrorKind::BuildError));
+                }
+            }
+
+            if !unique_keys
+                .iter()
+                .any(|unique_keys| unique_keys.keys == keys)
+            {
+                unique_keys.push(UniqueKeys::new(keys));
+            }
+        }
+
+        // the keys depending on the unique keys are regenerated together when the combination is not unique
+        let defined: HashSet<String> = checked.iter().cloned().collect();
+        let dependencies: Vec<(&str, Vec<String>)> = sorted_builders
+            .iter()
+            .map(|parent_builder| {
+                (
+                    parent_builder.key.as_str(),
+                    collect_referenced_keys(&parent_builder.builder, &defined),
+                )
+            })
+            .collect();
+        for unique_keys in unique_keys.iter_mut() {
+            for (key, referenced_keys) in dependencies.iter() {
+                if unique_keys.keys.iter().any(|unique_key| unique_key == key)
+                    || referenced_keys
+                        .iter()
+                        .any(|referenced_key| unique_keys.regenerated_keys.contains(referenced_key))
+                {
+                    unique_keys.regenerated_keys.push(key.to_string());
+                }
+            }
+        }
+
+        let depends_on_order = sorted_builders
+            .iter()
+            .any(|parent_builder| parent_builder.builder.has_reference_cardinality());
+
         Ok(Schema {
             keys: specified_keys,
             generators,
+            builders: sorted_builders,
+            unique_keys,
+            depends_on_order,
+            unique_retry: unique_retry.unwrap_or(DEFAULT_UNIQUE_RETRY_COUNT),
         })
     }
 }
 
+/// Sort the builders so that the generator referenced by the scripts and the formats precedes the referencing generators.
+/// The specified order is kept as much as possible.
+pub(crate) fn sort_by_dependency(
+    builders: Vec<ParentGeneratorBuilder>,
+) -> Result<Vec<ParentGeneratorBuilder>, BuildError> {
+    let keys: HashSet<String> = builders.iter().map(|b| b.key.clone()).collect();
+    let mut rest_builders: Vec<(ParentGeneratorBuilder, Vec<String>)> = builders
+        .into_iter()
+        .map(|parent_builder| {
+            let dependencies = collect_referenced_keys(&parent_builder.builder, &keys)
+                .into_iter()
+                .filter(|key| keys.contains(key))
+                .collect();
+            (parent_builder, dependencies)
+        })
+        .collect();
+
+    let mut sorted_builders: Vec<ParentGeneratorBuilder> = Vec::new();
+    while !rest_builders.is_empty() {
+        let ready_index = rest_builders.iter().position(|(_, dependencies)| {
+            dependencies.iter().all(|dependency| {
+                sorted_builders
+                    .iter()
+                    .any(|sorted_builder| &sorted_builder.key == dependency)
+            })
+        });
+
+        match ready_index {
+            None => return Err(BuildError::CyclicKeyReference(find_cycle(&rest_builders))),
+            Some(index) => sorted_builders.push(rest_builders.remove(index).0),
+        }
+    }
+
+    Ok(sorted_builders)
+}
+
+/// Find the path of a cycle in the builders which all depend on some of the rest builders.
+/// The path begins and ends with the same key.
+fn find_cycle(rest_builders: &[(ParentGeneratorBuilder, Vec<String>)]) -> Vec<String> {
+    let mut path: Vec<String> = Vec::new();
+    let mut current = &rest_builders[0];
+    loop {
+        let key = &current.0.key;
+        if let Some(start) = path.iter().position(|k| k == key) {
+            let mut cycle = path.split_off(start);
+            cycle.push(key.clone());
+            return cycle;
+        }
+        path.push(key.clone());
+
+        current = current
+            .1
+            .iter()
+            .find_map(|dependency| rest_builders.iter().find(|(b, _)| &b.key == dependency))
+            .expect("Rest builder depends on some of the rest builders");
+    }
+}
+
+/// Keys whose combination of the generated values must be unique and the combinations already generated
+#[derive(Debug)]
+struct UniqueKeys {
+    keys: Vec<String>,
+    /// the keys and the keys depending on them in the order of generation
+    regenerated_keys: Vec<String>,
+    generated: Mutex<HashSet<String>>,
+}
+
+impl UniqueKeys {
+    fn new(keys: Vec<String>) -> UniqueKeys {
+        UniqueKeys {
+            keys,
+            regenerated_keys: Vec::new(),
+            generated: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Forget the combinations already generated
+    fn reset(&self) {
+        self.generated
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Identify the combination of the values at the keys.
+    ///
+    /// If some of the values are null, the combination is not identified because null is not a subject to be unique.
+    fn identify(&self, values: &DataValueMap<&str>) -> Result<Option<String>, GenerateError> {
+        let mut combination = Vec::new();
+        for key in self.keys.iter() {
+            match values.get(key.as_str()) {
+                None => {
+                    return Err(GenerateError::NotExistGeneratedKey(
+                        key.to_string(),
+                        values
+                            .iter()
+                            .map(|(k, v)| (k.to_string(), v.clone()))
+                            .collect::<DataValueMap<String>>(),
+                    ));
+                }
+                Some(value) => combination.push(value),
+            }
+        }
+
+        Self::identify_combination(&combination)
+    }
+
+    /// Identify the combination of the values
+    fn identify_combination(combination: &[&DataValue]) -> Result<Option<String>, GenerateError> {
+        if combination
+            .iter()
+            .any(|value| matches!(value, DataValue::Null))
+        {
+            return Ok(None);
+        }
+
+        // serialize as json for distinguishing the type of the values
+        serde_json::to_string(combination)
+            .map(Some)
+            .map_err(|e| GenerateError::FailGenerate(e.to_string()))
+    }
+
+    /// Check that the combination has not been generated yet
+    fn is_unique(&self, combination: &Option<String>) -> Result<bool, GenerateError> {
+        match combination {
+            None => Ok(true),
+            Some(combination) => Ok(!self
+                .generated
+                .lock()
+                .map_err(|e| GenerateError::FailGenerate(e.to_string()))?
+                .contains(combination)),
+        }
+    }
+
+    /// Record the combination as generated
+    fn record(&self, combination: Option<String>) -> Result<(), GenerateError> {
+        if let Some(combination) = combination {
+            self.generated
+                .lock()
+                .map_err(|e| GenerateError::FailGenerate(e.to_string()))?
+                .insert(combination);
+        }
+
+        Ok(())
+    }
+}
+
 /// Schema consisting of `keys` and` generators`
 #[allow(missing_debug_implementations)]
 pub struct Schema<R: Randomizer + ?Sized> {
     keys: Vec<String>,
     generators: Vec<(String, Box<dyn GeneratorBase<R>>)>,
+    builders: Vec<ParentGeneratorBuilder>,
+    unique_keys: Vec<UniqueKeys>,
+    /// whether some of the generated values depend on the order of generation such as the cardinality of the reference
+    depends_on_order: bool,
+    unique_retry: u64,
 }
 
 impl<R: Randomizer + ?Sized> Schema<R> {
@@ -83,23 +473,323 @@ impl<R: Randomizer + ?Sized> Schema<R> {
         &self.keys
     }
 
-    /// Generate a values set
-    pub fn generate(&self, rng: &mut R) -> SchemaResult<GeneratedValues> {
+    /// Generate a values set.
+    ///
+    /// The values at the unique keys are regenerated until they become unique within the count of retrying.
+    pub fn generate(&self, rng: &mut R) -> SchemaResult<GeneratedValues<'_>> {
+        self.generate_unique_values(rng, None)
+            .map(|generated_values| GeneratedValues {
+                keys: self.get_keys(),
+                generated_values,
+            })
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::GenerateError))
+    }
+
+    /// Describe the values set generated by this schema as JSON Schema (draft 2020-12).
+    ///
+    /// See [`SchemaBuilder::to_json_schema`] for the description.
+    ///
+    /// [`SchemaBuilder::to_json_schema`]: ./struct.SchemaBuilder.html#method.to_json_schema
+    pub fn to_json_schema(
+        &self,
+        representation: &DateTimeRepresentation,
+    ) -> SchemaResult<serde_json::Value> {
+        Describer::new(representation)
+            .describe_values(&self.keys, &self.builders)
+            .map(with_dialect)
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::BuildError))
+    }
+
+    /// Check that the schema has some unique keys
+    pub fn has_unique_keys(&self) -> bool {
+        !self.unique_keys.is_empty()
+    }
+
+    /// Generate the values set which satisfies all unique keys and then record the combinations as generated.
+    /// If the combination at the unique keys is not unique, regenerate only the values at the keys and the keys depending on them.
+    /// If the index is specified, generate as the values set at the index.
+    fn generate_unique_values(
+        &self,
+        rng: &mut R,
+        index: Option<u64>,
+    ) -> Result<DataValueMap<&str>, GenerateError> {
         let mut generated_values = DataValueMap::new();
+        self.generate_values(rng, index, &mut generated_values, None)?;
+
+        let mut retry_count = 0;
+        'retry: loop {
+            let mut combinations = Vec::new();
+            for unique_keys in self.unique_keys.iter() {
+                let combination = unique_keys.identify(&generated_values)?;
+                if !unique_keys.is_unique(&combination)? {
+                    if retry_count >= self.unique_retry {
+                        return Err(GenerateError::NotUniqueValues(
+                            unique_keys.keys.clone(),
+                            self.unique_retry,
+                        ));
+                    }
+                    retry_count += 1;
+
+                    self.generate_values(
+                        rng,
+                        index,
+                        &mut generated_values,
+                        Some(&unique_keys.regenerated_keys),
+                    )?;
+                    continue 'retry;
+                }
+                combinations.push(combination);
+            }
+
+            for (unique_keys, combination) in self.unique_keys.iter().zip(combinations) {
+                unique_keys.record(combination)?;
+            }
+
+            return Ok(generated_values);
+        }
+    }
+
+    /// Generate the values at the target keys, or all keys if the target keys are not specified, into the values set
+    /// regenerating the value at the key specified as unique until it becomes unique.
+    /// If the index is specified, generate as the values set at the index.
+    fn generate_values<'a>(
+        &'a self,
+        rng: &mut R,
+        index: Option<u64>,
+        generated_values: &mut DataValueMap<&'a str>,
+        target_keys: Option<&[String]>,
+    ) -> Result<(), GenerateError> {
         for (key, generator) in self.generators.iter() {
-            let generated = generator
-                .generate(rng, &generated_values)
-                .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::GenerateError))?;
+            if let Some(target_keys) = target_keys {
+                if !target_keys.contains(key) {
+                    continue;
+                }
+            }
+
+            let unique_keys = self
+                .unique_keys
+                .iter()
+                .find(|unique_keys| unique_keys.keys.len() == 1 && &unique_keys.keys[0] == key);
+
+            let mut retry_count = 0;
+            let generated = loop {
+                let generated =
+                    generate_with_index(generator.as_ref(), rng, generated_values, index)?;
+                let unique_keys = match unique_keys {
+                    None => break generated,
+                    Some(unique_keys) => unique_keys,
+                };
+
+                let combination = UniqueKeys::identify_combination(&[&generated])?;
+                if unique_keys.is_unique(&combination)? {
+                    break generated;
+                }
+
+                if retry_count >= self.unique_retry {
+                    return Err(GenerateError::NotUniqueValues(
+                        vec![key.to_string()],
+                        self.unique_retry,
+                    ));
+                }
+                retry_count += 1;
+            };
             generated_values.insert(key, generated);
         }
 
-        Ok(GeneratedValues {
-            keys: self.get_keys(),
-            generated_values,
+        Ok(())
+    }
+}
+
+impl Schema<SeededRandomizer> {
+    /// Generate values sets as many as the count with the randomizer built from the seed.
+    ///
+    /// Each values set is generated by [`generate_at`] with the index in the order of the values sets,
+    /// so the same schema, seed and count always generate the same values sets on any platform.
+    /// The combinations at the unique keys and the counts of referencing with the cardinality generated before this call are forgotten.
+    ///
+    /// [`generate_at`]: #method.generate_at
+    pub fn generate_from_seed(
+        &self,
+        seed: RandomizerSeed,
+        count: u64,
+    ) -> SchemaResult<Vec<GeneratedValues<'_>>> {
+        self.generate_iter(seed, count, 1).collect()
+    }
+
+    /// Generate the values set at the index with the randomizer for the index built from the seed.
+    ///
+    /// The values set at the index does not depend on the other values sets unless the schema has some unique keys.
+    pub fn generate_at(
+        &self,
+        seed: RandomizerSeed,
+        index: u64,
+    ) -> SchemaResult<GeneratedValues<'_>> {
+        let mut rng = build_indexed_randomizer(seed, index);
+        self.generate_unique_values(&mut rng, Some(index))
+            .map(|generated_values| GeneratedValues {
+                keys: self.get_keys(),
+                generated_values,
+            })
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::GenerateError))
+    }
+
+    /// Generate values sets as many as the count in parallel on the threads as many as the jobs.
+    ///
+    /// The values sets are the same as the values sets generated by [`generate_from_seed`] regardless of the jobs.
+    /// If the jobs is 0, use the available parallelism.
+    /// If the schema has some unique keys or the cardinality of the reference, the values sets are generated in order on a thread for keeping the same result.
+    ///
+    /// [`generate_from_seed`]: #method.generate_from_seed
+    pub fn generate_in_parallel(
+        &self,
+        seed: RandomizerSeed,
+        count: u64,
+        jobs: usize,
+    ) -> SchemaResult<Vec<GeneratedValues<'_>>> {
+        self.generate_iter(seed, count, jobs).collect()
+    }
+
+    /// Get the iterator generating values sets as many as the count in parallel on the threads as many as the jobs.
+    ///
+    /// The iterator generates the values sets in bounded chunks in order while it is consumed,
+    /// so the values sets can be written without holding all of them.
+    /// The combinations at the unique keys and the counts of referencing with the cardinality generated before this call are forgotten.
+    /// The values sets are the same as the values sets generated by [`generate_from_seed`] regardless of the jobs.
+    /// If the jobs is 0, use the available parallelism.
+    /// If the schema has some unique keys or the cardinality of the reference, the values sets are generated in order on a thread.
+    ///
+    /// [`generate_from_seed`]: #method.generate_from_seed
+    pub fn generate_iter(
+        &self,
+        seed: RandomizerSeed,
+        count: u64,
+        jobs: usize,
+    ) -> GeneratedValuesIter<'_> {
+        let jobs = if self.has_unique_keys() || self.depends_on_order {
+            1
+        } else if jobs == 0 {
+            thread::available_parallelism()
+                .map(NonZeroUsize::get)
+                .unwrap_or(1)
+        } else {
+            jobs
+        };
+        for unique_keys in self.unique_keys.iter() {
+            unique_keys.reset();
+        }
+        for (_, generator) in self.generators.iter() {
+            generator.reset();
+        }
+
+        GeneratedValuesIter {
+            schema: self,
+            seed,
+            count,
+            jobs,
+            next_index: 0,
+            chunk: Vec::new().into_iter(),
+        }
+    }
+
+    /// Generate values sets from the start index to before the end index in parallel on the threads as many as the jobs.
+    fn generate_range(
+        &self,
+        seed: RandomizerSeed,
+        start: u64,
+        end: u64,
+        jobs: usize,
+    ) -> SchemaResult<Vec<GeneratedValues<'_>>> {
+        let chunk_size = (end - start).div_ceil(jobs as u64);
+        if jobs == 1 || chunk_size == 0 {
+            return (start..end)
+                .map(|index| self.generate_at(seed, index))
+                .collect();
+        }
+
+        thread::scope(|scope| {
+            let workers: Vec<_> = (start..end)
+                .step_by(chunk_size as usize)
+                .map(|start| {
+                    let end = end.min(start + chunk_size);
+                    scope.spawn(move || {
+                        (start..end)
+                            .map(|index| self.generate_at(seed, index))
+                            .collect::<SchemaResult<Vec<GeneratedValues<'_>>>>()
+                    })
+                })
+                .collect();
+
+            let mut result = Vec::new();
+            for worker in workers.into_iter() {
+                let values_sets = worker
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))?;
+                result.extend(values_sets);
+            }
+
+            Ok(result)
         })
     }
 }
 
+/// Count of the values sets generated on a thread at once by [`GeneratedValuesIter`]
+///
+/// [`GeneratedValuesIter`]: ./struct.GeneratedValuesIter.html
+const VALUES_SETS_PER_JOB: u64 = 256;
+
+/// Iterator generating values sets in bounded chunks in order.
+///
+/// This iterator is built by [`Schema::generate_iter`].
+/// Once an error is returned, this iterator generates no more values sets.
+///
+/// [`Schema::generate_iter`]: ./struct.Schema.html#method.generate_iter
+#[allow(missing_debug_implementations)]
+pub struct GeneratedValuesIter<'a> {
+    schema: &'a Schema<SeededRandomizer>,
+    seed: RandomizerSeed,
+    count: u64,
+    jobs: usize,
+    next_index: u64,
+    chunk: std::vec::IntoIter<GeneratedValues<'a>>,
+}
+
+impl<'a> Iterator for GeneratedValuesIter<'a> {
+    type Item = SchemaResult<GeneratedValues<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(values_set) = self.chunk.next() {
+            return Some(Ok(values_set));
+        }
+        if self.next_index >= self.count {
+            return None;
+        }
+
+        let start = self.next_index;
+        let end = self
+            .count
+            .min(start.saturating_add((self.jobs as u64).saturating_mul(VALUES_SETS_PER_JOB)));
+        match self.schema.generate_range(self.seed, start, end, self.jobs) {
+            Ok(values_sets) => {
+                self.next_index = end;
+                self.chunk = values_sets.into_iter();
+                self.chunk.next().map(Ok)
+            }
+            Err(e) => {
+                self.next_index = self.count;
+                Some(Err(e))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rest = (self.count - self.next_index)
+            .try_into()
+            .ok()
+            .and_then(|rest: usize| rest.checked_add(self.chunk.len()));
+        (rest.unwrap_or(usize::MAX), rest)
+    }
+}
+
 /// Structure for generated values set
 pub struct GeneratedValues<'a> {
     keys: &'a [String],
@@ -131,6 +821,11 @@ impl<'a> std::fmt::Display for GeneratedValues<'a> {
                 DataValue::Bool(v) => write!(f, "{}: {:?}", k, v)?,
                 DataValue::String(v) => write!(f, "{}: {:?}", k, v)?,
                 DataValue::Null => write!(f, "{}: null", k)?,
+                DataValue::Array(_) | DataValue::Object(_) => write!(f, "{}: {}", k, v)?,
+                DataValue::DateTime(..)
+                | DataValue::ZonedDateTime(..)
+                | DataValue::Date(..)
+                | DataValue::Time(..) => write!(f, "{}: {:?}", k, v.to_permutation_string())?,
             };
         }
         write!(f, "}}")
@@ -0,0 +1,272 @@
+#![deny(missing_debug_implementations)]
+//! Module for schema consisting of tables
+
+use crate::check::CheckFinding;
+use crate::error::{BuildError, IntoSbrdError, SchemaErrorKind, SchemaResult};
+use crate::generator::{build_seeded_randomizer, Randomizer, RandomizerSeed, SeededRandomizer};
+use crate::json_schema::{with_dialect, Describer};
+use crate::value::{DataValue, DateTimeRepresentation, ValueMap};
+use crate::SchemaBuilder;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+/// Builder for a table in [`TablesSchema`] is consisting of the table's `name`, `count` of the generated values sets and the schema for the table.
+///
+/// [`TablesSchema`]: ./struct.TablesSchema.html
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct TableSchemaBuilder {
+    name: String,
+    count: u64,
+    #[serde(flatten)]
+    schema: SchemaBuilder,
+}
+
+impl TableSchemaBuilder {
+    /// Constructor
+    pub fn new<S>(name: S, count: u64, schema: SchemaBuilder) -> TableSchemaBuilder
+    where
+        S: Into<String>,
+    {
+        TableSchemaBuilder {
+            name: name.into(),
+            count,
+            schema,
+        }
+    }
+}
+
+/// Builder for [`TablesSchema`] is consisting of builders for the tables at `tables` key
+///
+/// [`TablesSchema`]: ./struct.TablesSchema.html
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+pub struct TablesSchemaBuilder {
+    tables: Vec<TableSchemaBuilder>,
+}
+
+impl TablesSchemaBuilder {
+    /// Constructor
+    pub fn new(tables: Vec<TableSchemaBuilder>) -> TablesSchemaBuilder {
+        TablesSchemaBuilder { tables }
+    }
+
+    /// Check the generators of each table without building and generating.
+    ///
+    /// See [`SchemaBuilder::check`] for the checked problems.
+    ///
+    /// [`SchemaBuilder::check`]: ./struct.SchemaBuilder.html#method.check
+    pub fn check(&self) -> Vec<CheckFinding> {
+        self.tables
+            .iter()
+            .flat_map(|table| {
+                table
+                    .schema
+                    .check()
+                    .into_iter()
+                    .map(|finding| finding.in_table(&table.name))
+            })
+            .collect()
+    }
+
+    /// Describe the values set generated for each table as JSON Schema (draft 2020-12).
+    ///
+    /// The description of each table is defined under `$defs` with the table's name,
+    /// and the value referenced in the other table is described as the reference to the definition.
+    /// See [`SchemaBuilder::to_json_schema`] for the description of the values set.
+    ///
+    /// [`SchemaBuilder::to_json_schema`]: ./struct.SchemaBuilder.html#method.to_json_schema
+    pub fn to_json_schema(
+        &self,
+        representation: &DateTimeRepresentation,
+    ) -> SchemaResult<serde_json::Value> {
+        let table_keys: HashMap<&str, &[String]> = self
+            .tables
+            .iter()
+            .map(|table| (table.name.as_str(), table.schema.get_keys()))
+            .collect();
+        let describer = Describer::with_tables(representation, table_keys);
+
+        let mut definitions = Map::new();
+        for table in self.tables.iter() {
+            let described = describer
+                .describe_values(table.schema.get_keys(), table.schema.get_builders())
+                .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::BuildError))?;
+            definitions.insert(table.name.clone(), described);
+        }
+
+        Ok(with_dialect(json!({ "$defs": definitions })))
+    }
+
+    /// Build schema structure for the tables ordered so that a referenced table precedes the referencing tables
+    pub fn build<R: Randomizer + ?Sized>(self) -> SchemaResult<TablesSchema<R>> {
+        let TablesSchemaBuilder { tables } = self;
+        let table_names: Vec<String> = tables.iter().map(|table| table.name.clone()).collect();
+
+        // check table's name unique
+        for (i, name) in table_names.iter().enumerate() {
+            if table_names[..i].contains(name) {
+                return Err(BuildError::AlreadyExistTable(name.to_string())
+                    .into_sbrd_gen_error(SchemaErrorKind::BuildError));
+            }
+        }
+
+        let mut referenced_tables = Vec::new();
+        for table in tables.iter() {
+            // check that the schema without the referenced records is valid
+            table.schema.clone().build::<R>()?;
+
+            for referenced_table in table.schema.get_referenced_tables() {
+                if !table_names.iter().any(|name| name == referenced_table) {
+                    return Err(BuildError::NotExistTable(
+                        referenced_table.to_string(),
+                        table_names,
+                    )
+                    .into_sbrd_gen_error(SchemaErrorKind::BuildError));
+                }
+
+                if !referenced_tables.contains(&referenced_table.to_string()) {
+                    referenced_tables.push(referenced_table.to_string());
+                }
+            }
+        }
+
+        // sort in dependency order keeping the specified order as much as possible
+        let mut rest_tables = tables;
+        let mut sorted_tables: Vec<TableSchemaBuilder> = Vec::new();
+        while !rest_tables.is_empty() {
+            let ready_index = rest_tables.iter().position(|table| {
+                table
+                    .schema
+                    .get_referenced_tables()
+                    .iter()
+                    .all(|referenced_table| {
+                        sorted_tables
+                            .iter()
+                            .any(|sorted_table| &sorted_table.name == referenced_table)
+                    })
+            });
+
+            match ready_index {
+                None => {
+                    return Err(BuildError::CyclicTableReference(
+                        rest_tables.into_iter().map(|table| table.name).collect(),
+                    )
+                    .into_sbrd_gen_error(SchemaErrorKind::BuildError));
+                }
+                Some(index) => sorted_tables.push(rest_tables.remove(index)),
+            }
+        }
+
+        Ok(TablesSchema {
+            tables: sorted_tables,
+            referenced_tables,
+            randomizer: PhantomData,
+        })
+    }
+}
+
+/// Schema consisting of the tables ordered so that a referenced table precedes the referencing tables
+#[allow(missing_debug_implementations)]
+pub struct TablesSchema<R: Randomizer + ?Sized> {
+    tables: Vec<TableSchemaBuilder>,
+    referenced_tables: Vec<String>,
+    randomizer: PhantomData<R>,
+}
+
+impl<R: Randomizer + ?Sized> TablesSchema<R> {
+    /// Get names of the tables in the order of generation
+    pub fn get_table_names(&self) -> Vec<&str> {
+        self.tables
+            .iter()
+            .map(|table| table.name.as_str())
+            .collect()
+    }
+
+    /// Generate values sets for each table in the order of generation
+    pub fn generate(&self, rng: &mut R) -> SchemaResult<Vec<GeneratedTable>> {
+        let mut records_map: ValueMap<String, Vec<DataValue>> = ValueMap::new();
+        let mut result = Vec::new();
+        for table in self.tables.iter() {
+            let mut schema_builder = table.schema.clone();
+            schema_builder.set_referenced_records(&records_map);
+            let schema = schema_builder.build::<R>()?;
+
+            let is_referenced = self.referenced_tables.contains(&table.name);
+            let mut records = Vec::new();
+            let mut values_list = Vec::new();
+            for _ in 1..=table.count {
+                let generated = schema.generate(rng)?;
+                if is_referenced {
+                    records.push(DataValue::Object(
+                        generated
+                            .get_all_values()
+                            .iter()
+                            .map(|(k, v)| (k.to_string(), v.clone()))
+                            .collect(),
+                    ));
+                }
+
+                values_list.push(generated.into_values()?);
+            }
+
+            if is_referenced {
+                records_map.insert(table.name.clone(), records);
+            }
+            result.push(GeneratedTable {
+                name: table.name.clone(),
+                keys: schema.get_keys().to_vec(),
+                values_list,
+            });
+        }
+
+        Ok(result)
+    }
+}
+
+impl TablesSchema<SeededRandomizer> {
+    /// Generate values sets for each table with the randomizer built from the seed.
+    ///
+    /// The same schema and seed always generate the same tables on any platform.
+    pub fn generate_from_seed(&self, seed: RandomizerSeed) -> SchemaResult<Vec<GeneratedTable>> {
+        let mut rng = build_seeded_randomizer(seed);
+        self.generate(&mut rng)
+    }
+}
+
+/// Structure for generated values sets of a table
+#[derive(Debug, PartialEq, Clone)]
+pub struct GeneratedTable {
+    name: String,
+    keys: Vec<String>,
+    values_list: Vec<Vec<DataValue>>,
+}
+
+impl GeneratedTable {
+    /// Constructor
+    pub fn new<S>(name: S, keys: Vec<String>, values_list: Vec<Vec<DataValue>>) -> GeneratedTable
+    where
+        S: Into<String>,
+    {
+        GeneratedTable {
+            name: name.into(),
+            keys,
+            values_list,
+        }
+    }
+
+    /// Get the table's name
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get specified keys
+    pub fn get_keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// Get all values sets for which the key is specified as the value for which output is required
+    pub fn get_values_list(&self) -> &[Vec<DataValue>] {
+        &self.values_list
+    }
+}
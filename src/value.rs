@@ -1,13 +1,14 @@
 #![deny(missing_debug_implementations)]
 //! Module for value
 
-use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 use rt_format::{Format, FormatArgument, NoNamedArguments, ParsedFormat, Specifier};
 use std::collections::BTreeMap;
 use std::fmt;
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Formatter, Write};
 
-use serde::de::{Error, Unexpected, Visitor};
+use serde::de::{Error, MapAccess, SeqAccess, Unexpected, Visitor};
+use serde::ser::{SerializeMap, SerializeSeq};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// KVS for this crate
@@ -18,15 +19,17 @@ pub type ValueMap<K, S> = BTreeMap<K, S>;
 pub type DataValueMap<K> = ValueMap<K, DataValue>;
 
 /// Integer type for this crate
-pub type SbrdInt = i32;
+pub type SbrdInt = i64;
 /// Real type for this crate
-pub type SbrdReal = f32;
+pub type SbrdReal = f64;
 /// Boolean type for this crate
 pub type SbrdBool = bool;
 /// String type for this crate
 pub type SbrdString = String;
 /// DateTime type for this crate
 pub type SbrdDateTime = NaiveDateTime;
+/// DateTime type with the offset of the time zone for this crate
+pub type SbrdZonedDateTime = DateTime<FixedOffset>;
 /// Date type for this crate
 pub type SbrdDate = NaiveDate;
 /// Time type for this crate
@@ -36,6 +39,10 @@ pub type SbrdTime = NaiveTime;
 ///
 /// [`SbrdDateTime`]: ./type.SbrdDateTime.html
 pub const DATE_TIME_DEFAULT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+/// Default format string for [`SbrdZonedDateTime`]
+///
+/// [`SbrdZonedDateTime`]: ./type.SbrdZonedDateTime.html
+pub const ZONED_DATE_TIME_DEFAULT_FORMAT: &str = "%Y-%m-%d %H:%M:%S%:z";
 /// Default format string for [`SbrdDate`]
 ///
 /// [`SbrdDate`]: ./type.SbrdDate.html
@@ -45,6 +52,41 @@ pub const DATE_DEFAULT_FORMAT: &str = "%Y-%m-%d";
 /// [`SbrdTime`]: ./type.SbrdTime.html
 pub const TIME_DEFAULT_FORMAT: &str = "%H:%M:%S";
 
+/// Representation of the date-time, date and time values when writing
+///
+/// The time is treated as the time on the Unix epoch day, so the epoch representations of the time are the elapsed time since midnight.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub enum DateTimeRepresentation {
+    /// Formatted with the `format` option of the generator, or the default format if not specified
+    #[default]
+    Format,
+    /// ISO-8601 such as "2022-01-02T03:04:05", "2022-01-02T03:04:05+09:00", "2022-01-02" and "03:04:05"
+    Iso8601,
+    /// Integer of the seconds since the Unix epoch
+    EpochSeconds,
+    /// Integer of the milliseconds since the Unix epoch
+    EpochMillis,
+    /// Formatted with the custom format of [`format::strftime` module]
+    ///
+    /// [`format::strftime` module]: https://docs.rs/chrono/0.4.19/chrono/format/strftime/index.html#specifiers
+    Custom(String),
+}
+
+impl std::str::FromStr for DateTimeRepresentation {
+    type Err = std::convert::Infallible;
+
+    /// Parse "format", "iso8601", "epoch-seconds" and "epoch-millis", otherwise parse as the custom format
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "format" => DateTimeRepresentation::Format,
+            "iso8601" => DateTimeRepresentation::Iso8601,
+            "epoch-seconds" => DateTimeRepresentation::EpochSeconds,
+            "epoch-millis" => DateTimeRepresentation::EpochMillis,
+            _ => DateTimeRepresentation::Custom(s.to_string()),
+        })
+    }
+}
+
 /// Value for [`Schema`]
 ///
 /// [`Schema`]: ../schema/struct.Schema.html
@@ -60,6 +102,30 @@ pub enum DataValue {
     String(String),
     /// Null
     Null,
+    /// Array of values
+    Array(Vec<DataValue>),
+    /// Object consisting of keys and values
+    Object(DataValueMap<String>),
+    /// Date-time with the format applied when writing.
+    /// If the format is `None`, then use [`DATE_TIME_DEFAULT_FORMAT`].
+    ///
+    /// [`DATE_TIME_DEFAULT_FORMAT`]: ./constant.DATE_TIME_DEFAULT_FORMAT.html
+    DateTime(SbrdDateTime, Option<String>),
+    /// Date-time in a time zone with the format applied when writing.
+    /// If the format is `None`, then use [`ZONED_DATE_TIME_DEFAULT_FORMAT`].
+    ///
+    /// [`ZONED_DATE_TIME_DEFAULT_FORMAT`]: ./constant.ZONED_DATE_TIME_DEFAULT_FORMAT.html
+    ZonedDateTime(SbrdZonedDateTime, Option<String>),
+    /// Date with the format applied when writing.
+    /// If the format is `None`, then use [`DATE_DEFAULT_FORMAT`].
+    ///
+    /// [`DATE_DEFAULT_FORMAT`]: ./constant.DATE_DEFAULT_FORMAT.html
+    Date(SbrdDate, Option<String>),
+    /// Time with the format applied when writing.
+    /// If the format is `None`, then use [`TIME_DEFAULT_FORMAT`].
+    ///
+    /// [`TIME_DEFAULT_FORMAT`]: ./constant.TIME_DEFAULT_FORMAT.html
+    Time(SbrdTime, Option<String>),
 }
 
 impl From<SbrdInt> for DataValue {
@@ -86,21 +152,39 @@ impl From<String> for DataValue {
     }
 }
 
+impl From<Vec<DataValue>> for DataValue {
+    fn from(v: Vec<DataValue>) -> Self {
+        Self::Array(v)
+    }
+}
+
+impl From<DataValueMap<String>> for DataValue {
+    fn from(v: DataValueMap<String>) -> Self {
+        Self::Object(v)
+    }
+}
+
 impl From<SbrdDateTime> for DataValue {
     fn from(v: SbrdDateTime) -> Self {
-        Self::String(v.format(DATE_TIME_DEFAULT_FORMAT).to_string())
+        Self::DateTime(v, None)
+    }
+}
+
+impl From<SbrdZonedDateTime> for DataValue {
+    fn from(v: SbrdZonedDateTime) -> Self {
+        Self::ZonedDateTime(v, None)
     }
 }
 
 impl From<SbrdDate> for DataValue {
     fn from(v: SbrdDate) -> Self {
-        Self::String(v.format(DATE_DEFAULT_FORMAT).to_string())
+        Self::Date(v, None)
     }
 }
 
 impl From<SbrdTime> for DataValue {
     fn from(v: SbrdTime) -> Self {
-        Self::String(v.format(TIME_DEFAULT_FORMAT).to_string())
+        Self::Time(v, None)
     }
 }
 
@@ -109,18 +193,14 @@ impl<'de> Visitor<'de> for DataValueVisitor {
     type Value = DataValue;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("null or string for value parameter.")
+        formatter.write_str("null, string, sequence or map for value parameter.")
     }
 
     fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
     where
         E: Error,
     {
-        let i = SbrdInt::try_from(v);
-        match i {
-            Err(_) => Err(Error::invalid_value(Unexpected::Signed(v), &self)),
-            Ok(parsed) => Ok(DataValue::Int(parsed)),
-        }
+        Ok(DataValue::Int(v))
     }
 
     fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
@@ -129,7 +209,7 @@ impl<'de> Visitor<'de> for DataValueVisitor {
     {
         let i = SbrdInt::try_from(v);
         match i {
-            Err(_) => Err(Error::invalid_value(Unexpected::Unsigned(v as u64), &self)),
+            Err(_) => Err(Error::invalid_value(Unexpected::Unsigned(v), &self)),
             Ok(parsed) => Ok(DataValue::Int(parsed)),
         }
     }
@@ -138,7 +218,7 @@ impl<'de> Visitor<'de> for DataValueVisitor {
     where
         E: Error,
     {
-        Ok(DataValue::Real(v as SbrdReal))
+        Ok(DataValue::Real(v))
     }
 
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -168,6 +248,30 @@ impl<'de> Visitor<'de> for DataValueVisitor {
     {
         deserializer.deserialize_any(Self)
     }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element::<DataValue>()? {
+            values.push(value);
+        }
+
+        Ok(DataValue::Array(values))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut values = DataValueMap::new();
+        while let Some((key, value)) = map.next_entry::<String, DataValue>()? {
+            values.insert(key, value);
+        }
+
+        Ok(DataValue::Object(values))
+    }
 }
 
 impl<'de> Deserialize<'de> for DataValue {
@@ -185,11 +289,29 @@ impl Serialize for DataValue {
         S: Serializer,
     {
         match &self {
-            DataValue::Int(v) => serializer.serialize_i32(*v),
-            DataValue::Real(v) => serializer.serialize_f32(*v),
+            DataValue::Int(v) => serializer.serialize_i64(*v),
+            DataValue::Real(v) => serializer.serialize_f64(*v),
+            DataValue::DateTime(..)
+            | DataValue::ZonedDateTime(..)
+            | DataValue::Date(..)
+            | DataValue::Time(..) => serializer.serialize_str(&self.to_permutation_string()),
             DataValue::Bool(v) => serializer.serialize_bool(*v),
             DataValue::String(v) => serializer.serialize_str(v),
             DataValue::Null => serializer.serialize_unit(),
+            DataValue::Array(values) => {
+                let mut seq_state = serializer.serialize_seq(Some(values.len()))?;
+                for value in values.iter() {
+                    seq_state.serialize_element(value)?;
+                }
+                seq_state.end()
+            }
+            DataValue::Object(values) => {
+                let mut map_state = serializer.serialize_map(Some(values.len()))?;
+                for (key, value) in values.iter() {
+                    map_state.serialize_entry(key, value)?;
+                }
+                map_state.end()
+            }
         }
     }
 }
@@ -202,6 +324,24 @@ impl std::fmt::Display for DataValue {
             DataValue::Bool(v) => fmt::Display::fmt(v, f),
             DataValue::String(v) => fmt::Display::fmt(v, f),
             DataValue::Null => write!(f, "null"),
+            // display with the default format to be able to compare chronologically as a string
+            DataValue::DateTime(v, _) => {
+                fmt::Display::fmt(&v.format(DATE_TIME_DEFAULT_FORMAT).to_string(), f)
+            }
+            DataValue::ZonedDateTime(v, _) => {
+                fmt::Display::fmt(&v.format(ZONED_DATE_TIME_DEFAULT_FORMAT).to_string(), f)
+            }
+            DataValue::Date(v, _) => {
+                fmt::Display::fmt(&v.format(DATE_DEFAULT_FORMAT).to_string(), f)
+            }
+            DataValue::Time(v, _) => {
+                fmt::Display::fmt(&v.format(TIME_DEFAULT_FORMAT).to_string(), f)
+            }
+            DataValue::Array(_) | DataValue::Object(_) => {
+                // display as json
+                let json = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+                fmt::Display::fmt(&json, f)
+            }
         }
     }
 }
@@ -248,7 +388,7 @@ impl FormatArgument for DataValue {
     }
 }
 
-impl<'a> FormatArgument for &'a DataValue {
+impl FormatArgument for &DataValue {
     fn supports_format(&self, specifier: &Specifier) -> bool {
         // Not support debug format in release build.
         if !cfg!(debug_assertions) && specifier.format == Format::Debug {
@@ -261,7 +401,14 @@ impl<'a> FormatArgument for &'a DataValue {
                 specifier.format,
                 Format::Display | Format::Debug | Format::LowerExp | Format::UpperExp
             ),
-            DataValue::Bool(_) | DataValue::String(_) => {
+            DataValue::Bool(_)
+            | DataValue::String(_)
+            | DataValue::Array(_)
+            | DataValue::Object(_)
+            | DataValue::DateTime(..)
+            | DataValue::ZonedDateTime(..)
+            | DataValue::Date(..)
+            | DataValue::Time(..) => {
                 matches!(specifier.format, Format::Display | Format::Debug)
             }
         }
@@ -351,6 +498,8 @@ impl<'a> FormatArgument for &'a DataValue {
 
 impl DataValue {
     /// Convert to String to use when permute with other Strings
+    ///
+    /// The date-time, date and time are formatted with their format.
     pub fn to_permutation_string(&self) -> String {
         match self {
             DataValue::Int(v) => v.to_string(),
@@ -358,10 +507,25 @@ impl DataValue {
             DataValue::Bool(v) => v.to_string(),
             DataValue::String(v) => v.to_string(),
             DataValue::Null => "".to_string(),
+            DataValue::Array(_) | DataValue::Object(_) => self.to_string(),
+            DataValue::DateTime(v, format) => v
+                .format(format.as_deref().unwrap_or(DATE_TIME_DEFAULT_FORMAT))
+                .to_string(),
+            DataValue::ZonedDateTime(v, format) => v
+                .format(format.as_deref().unwrap_or(ZONED_DATE_TIME_DEFAULT_FORMAT))
+                .to_string(),
+            DataValue::Date(v, format) => v
+                .format(format.as_deref().unwrap_or(DATE_DEFAULT_FORMAT))
+                .to_string(),
+            DataValue::Time(v, format) => v
+                .format(format.as_deref().unwrap_or(TIME_DEFAULT_FORMAT))
+                .to_string(),
         }
     }
 
     /// Convert to String to use parse
+    ///
+    /// The date-time, date and time are formatted with their default format.
     pub fn to_parse_string(&self) -> String {
         match self {
             DataValue::Int(v) => v.to_string(),
@@ -369,9 +533,102 @@ impl DataValue {
             DataValue::Bool(v) => v.to_string(),
             DataValue::String(v) => v.to_string(),
             DataValue::Null => "".to_string(),
+            DataValue::Array(_)
+            | DataValue::Object(_)
+            | DataValue::DateTime(..)
+            | DataValue::ZonedDateTime(..)
+            | DataValue::Date(..)
+            | DataValue::Time(..) => self.to_string(),
         }
     }
 
+    /// Convert the date-time, date and time in this value into the representation for writing.
+    /// The values in the array and the object are also converted.
+    ///
+    /// The date is treated as the date-time at midnight, and the time is treated as the date-time on 1970-01-01.
+    ///
+    /// # Examples
+    /// ```
+    /// fn main(){
+    ///     use sbrd_gen::value::{DataValue, DateTimeRepresentation, SbrdDate};
+    ///
+    ///     let date_time = DataValue::DateTime(SbrdDate::from_ymd(2022, 1, 2).and_hms(3, 4, 5), Some("%Y/%m/%d".to_string()));
+    ///     assert_eq!(Ok(DataValue::String("2022/01/02".to_string())), date_time.represent_date_time(&DateTimeRepresentation::Format));
+    ///     assert_eq!(Ok(DataValue::String("2022-01-02T03:04:05".to_string())), date_time.represent_date_time(&DateTimeRepresentation::Iso8601));
+    ///     assert_eq!(Ok(DataValue::Int(1641092645)), date_time.represent_date_time(&DateTimeRepresentation::EpochSeconds));
+    ///     assert_eq!(Ok(DataValue::Int(1641092645000)), date_time.represent_date_time(&DateTimeRepresentation::EpochMillis));
+    ///     assert_eq!(Ok(DataValue::String("03h04m".to_string())), date_time.represent_date_time(&DateTimeRepresentation::Custom("%Hh%Mm".to_string())));
+    /// }
+    /// ```
+    pub fn represent_date_time(
+        &self,
+        representation: &DateTimeRepresentation,
+    ) -> Result<DataValue, String> {
+        let midnight = SbrdTime::from_hms(0, 0, 0);
+        let represented = match (self, representation) {
+            (DataValue::Array(values), _) => DataValue::Array(
+                values
+                    .iter()
+                    .map(|v| v.represent_date_time(representation))
+                    .collect::<Result<Vec<DataValue>, String>>()?,
+            ),
+            (DataValue::Object(values), _) => DataValue::Object(
+                values
+                    .iter()
+                    .map(|(k, v)| Ok((k.clone(), v.represent_date_time(representation)?)))
+                    .collect::<Result<DataValueMap<String>, String>>()?,
+            ),
+            (DataValue::ZonedDateTime(v, _), _) => match representation {
+                DateTimeRepresentation::Format => DataValue::String(self.to_permutation_string()),
+                DateTimeRepresentation::Iso8601 => {
+                    DataValue::String(v.format("%Y-%m-%dT%H:%M:%S%:z").to_string())
+                }
+                DateTimeRepresentation::EpochSeconds => DataValue::Int(v.timestamp()),
+                DateTimeRepresentation::EpochMillis => DataValue::Int(v.timestamp_millis()),
+                DateTimeRepresentation::Custom(format) => {
+                    let mut formatted = String::new();
+                    write!(formatted, "{}", v.format(format))
+                        .map_err(|_| format!("Fail format {} with format \"{}\"", self, format))?;
+
+                    DataValue::String(formatted)
+                }
+            },
+            (DataValue::DateTime(..) | DataValue::Date(..) | DataValue::Time(..), _) => {
+                // the date is treated as the midnight and the time is treated as the time on the Unix epoch day
+                let date_time = match self {
+                    DataValue::DateTime(v, _) => *v,
+                    DataValue::Date(v, _) => v.and_time(midnight),
+                    DataValue::Time(v, _) => SbrdDate::from_ymd(1970, 1, 1).and_time(*v),
+                    _ => unreachable!(),
+                };
+                match representation {
+                    DateTimeRepresentation::Format => {
+                        DataValue::String(self.to_permutation_string())
+                    }
+                    DateTimeRepresentation::Iso8601 => DataValue::String(match self {
+                        DataValue::DateTime(v, _) => v.format("%Y-%m-%dT%H:%M:%S").to_string(),
+                        _ => self.to_string(),
+                    }),
+                    DateTimeRepresentation::EpochSeconds => DataValue::Int(date_time.timestamp()),
+                    DateTimeRepresentation::EpochMillis => {
+                        DataValue::Int(date_time.timestamp_millis())
+                    }
+                    DateTimeRepresentation::Custom(format) => {
+                        let mut formatted = String::new();
+                        write!(formatted, "{}", date_time.format(format)).map_err(|_| {
+                            format!("Fail format {} with format \"{}\"", self, format)
+                        })?;
+
+                        DataValue::String(formatted)
+                    }
+                }
+            }
+            _ => self.clone(),
+        };
+
+        Ok(represented)
+    }
+
     /// Format this value
     ///
     /// Support [`Rust-format syntax`]. But not support position, variable, padding with character and [`Pointer`] format (`{:p}`).
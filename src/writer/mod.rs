@@ -1,11 +1,13 @@
 //! Module for writer
 
 pub use json::*;
+pub use sql::*;
 pub use writer_base::*;
 pub use xsv::*;
 pub use yaml::*;
 
 mod json;
+mod sql;
 mod writer_base;
 mod xsv;
 mod yaml;
@@ -0,0 +1,334 @@
+use crate::error::{IntoSbrdError, SchemaErrorKind, SchemaResult};
+use crate::generator::Randomizer;
+use crate::value::{DataValue, DateTimeRepresentation};
+use crate::writer::writer_base::{represent_table, represent_values};
+use crate::writer::GeneratedValueWriterBase;
+use crate::{GeneratedTable, GeneratedValues, Schema};
+use std::io;
+
+/// Default name of the table to insert the generated values
+pub const DEFAULT_SQL_TABLE_NAME: &str = "dummy_data";
+/// Default count of the values sets inserted in an INSERT statement
+pub const DEFAULT_SQL_BATCH_SIZE: usize = 100;
+
+/// Dialect of SQL for [`SqlWriter`]
+///
+/// [`SqlWriter`]: ./struct.SqlWriter.html
+#[derive(Debug, Default, Eq, PartialEq, Copy, Clone)]
+pub enum SqlDialect {
+    /// Dialect for PostgreSQL
+    #[default]
+    PostgreSql,
+    /// Dialect for MySQL
+    MySql,
+    /// Dialect for SQLite
+    Sqlite,
+}
+
+impl SqlDialect {
+    /// Quote the whole identifier such as the column's name
+    pub fn quote_identifier(&self, identifier: &str) -> String {
+        let quote = match self {
+            SqlDialect::PostgreSql | SqlDialect::Sqlite => '"',
+            SqlDialect::MySql => '`',
+        };
+
+        let escaped = identifier.replace(quote, &format!("{}{}", quote, quote));
+        format!("{}{}{}", quote, escaped, quote)
+    }
+
+    /// Quote the table's name.
+    ///
+    /// The name qualified with `.` such as `schema.table` is quoted for each part.
+    pub fn quote_table_name(&self, table: &str) -> String {
+        table
+            .split('.')
+            .map(|part| self.quote_identifier(part))
+            .collect::<Vec<String>>()
+            .join(".")
+    }
+
+    /// Quote the string as a literal
+    pub fn quote_string(&self, s: &str) -> String {
+        let escaped = match self {
+            SqlDialect::PostgreSql | SqlDialect::Sqlite => s.replace('\'', "''"),
+            // MySQL treats a backslash in a literal as an escape character by default
+            SqlDialect::MySql => s.replace('\\', "\\\\").replace('\'', "''"),
+        };
+
+        format!("'{}'", escaped)
+    }
+
+    /// Format the value as a literal
+    pub fn format_value(&self, value: &DataValue) -> String {
+        match value {
+            DataValue::Int(v) => v.to_string(),
+            DataValue::Real(v) if v.is_finite() => v.to_string(),
+            // only PostgreSQL has the literals for the real number which is not finite
+            DataValue::Real(v) => match self {
+                SqlDialect::PostgreSql if v.is_nan() => "'NaN'::float8".to_string(),
+                SqlDialect::PostgreSql if v.is_sign_positive() => "'Infinity'::float8".to_string(),
+                SqlDialect::PostgreSql => "'-Infinity'::float8".to_string(),
+                SqlDialect::MySql | SqlDialect::Sqlite => "NULL".to_string(),
+            },
+            DataValue::Bool(v) => match (self, v) {
+                (SqlDialect::PostgreSql | SqlDialect::MySql, true) => "TRUE".to_string(),
+                (SqlDialect::PostgreSql | SqlDialect::MySql, false) => "FALSE".to_string(),
+                (SqlDialect::Sqlite, true) => "1".to_string(),
+                (SqlDialect::Sqlite, false) => "0".to_string(),
+            },
+            DataValue::String(v) => self.quote_string(v),
+            DataValue::DateTime(..)
+            | DataValue::ZonedDateTime(..)
+            | DataValue::Date(..)
+            | DataValue::Time(..) => self.quote_string(&value.to_permutation_string()),
+            DataValue::Null => "NULL".to_string(),
+            // nested value is written as a json string
+            DataValue::Array(_) | DataValue::Object(_) => self.quote_string(&value.to_string()),
+        }
+    }
+}
+
+/// A writer that outputs as INSERT statements of SQL for the key and the generated value
+///
+/// The keys are written as the column's names when using the key header.
+pub struct SqlWriter<W: io::Write> {
+    writer: W,
+    table: String,
+    batch_size: usize,
+    dialect: SqlDialect,
+    date_time_representation: DateTimeRepresentation,
+}
+
+impl<W: io::Write> SqlWriter<W> {
+    /// Set name of the table to insert the generated values
+    pub fn table<S>(mut self, table: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.table = table.into();
+        self
+    }
+
+    /// Set count of the values sets inserted in an INSERT statement.
+    ///
+    /// If the count is 0, then insert all values sets in an INSERT statement.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set dialect of SQL
+    pub fn dialect(mut self, dialect: SqlDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Set representation of the date-time, date and time values
+    pub fn date_time_representation(mut self, representation: DateTimeRepresentation) -> Self {
+        self.date_time_representation = representation;
+        self
+    }
+
+    fn write_str(&mut self, s: &str) -> SchemaResult<()> {
+        self.writer
+            .write_all(s.as_bytes())
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::OutputError))
+    }
+
+    fn is_full_batch(&self, count: usize) -> bool {
+        self.batch_size != 0 && count >= self.batch_size
+    }
+
+    /// Write an INSERT statement for the values sets
+    fn write_insert(
+        &mut self,
+        table: &str,
+        keys: Option<&[String]>,
+        values_list: &[Vec<DataValue>],
+    ) -> SchemaResult<()> {
+        if values_list.is_empty() {
+            return Ok(());
+        }
+
+        let mut statement = format!("INSERT INTO {}", self.dialect.quote_table_name(table));
+        if let Some(keys) = keys {
+            let columns = keys
+                .iter()
+                .map(|key| self.dialect.quote_identifier(key))
+                .collect::<Vec<String>>()
+                .join(", ");
+            statement.push_str(&format!(" ({})", columns));
+        }
+        statement.push_str(" VALUES\n");
+
+        let rows = values_list
+            .iter()
+            .map(|values| {
+                let row = values
+                    .iter()
+                    .map(|value| self.dialect.format_value(value))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!("({})", row)
+            })
+            .collect::<Vec<String>>()
+            .join(",\n");
+        statement.push_str(&rows);
+        statement.push_str(";\n");
+
+        self.write_str(&statement)
+    }
+
+    /// Write INSERT statements for the values sets divided into the batches
+    fn write_inserts(
+        &mut self,
+        table: &str,
+        keys: Option<&[String]>,
+        values_list: &[Vec<DataValue>],
+    ) -> SchemaResult<()> {
+        let chunk_size = if self.batch_size == 0 {
+            values_list.len().max(1)
+        } else {
+            self.batch_size
+        };
+        for chunk in values_list.chunks(chunk_size) {
+            self.write_insert(table, keys, chunk)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_table_statements(
+        &mut self,
+        use_key_header: bool,
+        table: &GeneratedTable,
+    ) -> SchemaResult<()> {
+        let table = represent_table(table, &self.date_time_representation)?;
+        let keys = if use_key_header {
+            Some(table.get_keys())
+        } else {
+            None
+        };
+
+        self.write_inserts(table.get_name(), keys, table.get_values_list())
+    }
+}
+
+impl<W: io::Write> GeneratedValueWriterBase<W> for SqlWriter<W> {
+    fn from_writer(writer: W) -> Self {
+        Self {
+            writer,
+            table: DEFAULT_SQL_TABLE_NAME.to_string(),
+            batch_size: DEFAULT_SQL_BATCH_SIZE,
+            dialect: SqlDialect::default(),
+            date_time_representation: DateTimeRepresentation::default(),
+        }
+    }
+
+    fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn flush(&mut self) -> SchemaResult<()> {
+        self.writer
+            .flush()
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::OutputError))
+    }
+
+    fn write_after_all_generated<R: Randomizer + ?Sized>(
+        &mut self,
+        use_key_header: bool,
+        schema: &Schema<R>,
+        rng: &mut R,
+        count: u64,
+    ) -> SchemaResult<()> {
+        let mut values_list: Vec<Vec<DataValue>> = Vec::new();
+        for _ in 1..=count {
+            let generated = schema.generate(rng)?;
+            values_list.push(represent_values(
+                generated.into_values()?,
+                &self.date_time_representation,
+            )?);
+        }
+
+        let keys = if use_key_header {
+            Some(schema.get_keys())
+        } else {
+            None
+        };
+        let table = self.table.clone();
+        self.write_inserts(&table, keys, &values_list)?;
+
+        self.flush()?;
+        Ok(())
+    }
+
+    fn write_with_generate<R: Randomizer + ?Sized>(
+        &mut self,
+        use_key_header: bool,
+        schema: &Schema<R>,
+        rng: &mut R,
+        count: u64,
+    ) -> SchemaResult<()> {
+        self.write_values_sets(
+            use_key_header,
+            schema.get_keys(),
+            (0..count).map(|_| schema.generate(&mut *rng)),
+        )
+    }
+
+    fn write_values_sets<'a, I>(
+        &mut self,
+        use_key_header: bool,
+        keys: &[String],
+        values_sets: I,
+    ) -> SchemaResult<()>
+    where
+        I: Iterator<Item = SchemaResult<GeneratedValues<'a>>>,
+    {
+        let keys = if use_key_header { Some(keys) } else { None };
+        let table = self.table.clone();
+
+        let mut values_list: Vec<Vec<DataValue>> = Vec::new();
+        for generated in values_sets {
+            values_list.push(represent_values(
+                generated?.into_values()?,
+                &self.date_time_representation,
+            )?);
+
+            if self.is_full_batch(values_list.len()) {
+                self.write_insert(&table, keys, &values_list)?;
+                values_list.clear();
+            }
+        }
+        self.write_insert(&table, keys, &values_list)?;
+
+        self.flush()?;
+        Ok(())
+    }
+
+    fn write_table(&mut self, use_key_header: bool, table: &GeneratedTable) -> SchemaResult<()> {
+        self.write_table_statements(use_key_header, table)?;
+
+        self.flush()?;
+        Ok(())
+    }
+
+    fn write_tables(
+        &mut self,
+        use_key_header: bool,
+        tables: &[GeneratedTable],
+    ) -> SchemaResult<()> {
+        for (i, table) in tables.iter().enumerate() {
+            // separate each section with an empty line
+            if i != 0 {
+                self.write_str("\n")?;
+            }
+            self.write_table_statements(use_key_header, table)?;
+        }
+
+        self.flush()?;
+        Ok(())
+    }
+}
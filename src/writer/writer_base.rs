@@ -1,8 +1,10 @@
-use crate::error::SchemaResult;
+use crate::error::{IntoSbrdError, OutputError, SchemaErrorKind, SchemaResult};
 use crate::generator::Randomizer;
-use crate::Schema;
+use crate::value::{DataValue, DateTimeRepresentation};
+use crate::{GeneratedTable, GeneratedValues, Schema};
 use serde::ser::{Error, SerializeMap, SerializeSeq};
 use serde::{Serialize, Serializer};
+use std::borrow::Cow;
 use std::sync::Mutex;
 
 /// Trait of Writer for keys and generated values
@@ -33,6 +35,23 @@ pub trait GeneratedValueWriterBase<W: std::io::Write> {
         rng: &mut R,
         count: u64,
     ) -> SchemaResult<()>;
+
+    /// Write keys and the values sets while taking them from the iterator
+    fn write_values_sets<'a, I>(
+        &mut self,
+        use_key_header: bool,
+        keys: &[String],
+        values_sets: I,
+    ) -> SchemaResult<()>
+    where
+        I: Iterator<Item = SchemaResult<GeneratedValues<'a>>>;
+
+    /// Write keys and values of the generated table
+    fn write_table(&mut self, use_key_header: bool, table: &GeneratedTable) -> SchemaResult<()>;
+
+    /// Write keys and values of each generated table as a section for the table
+    fn write_tables(&mut self, use_key_header: bool, tables: &[GeneratedTable])
+        -> SchemaResult<()>;
 }
 
 /// dummy key of `keys` for a KVS
@@ -40,6 +59,81 @@ pub const DUMMY_KEYS_NAME: &str = "keys";
 /// dummy key of `values` for a KVS
 pub const DUMMY_VALUES_NAME: &str = "values";
 
+/// Convert the date-time, date and time in the value into the representation
+pub(crate) fn represent_value(
+    value: DataValue,
+    representation: &DateTimeRepresentation,
+) -> SchemaResult<DataValue> {
+    if representation == &DateTimeRepresentation::Format {
+        // the value is formatted with its format when serializing
+        return Ok(value);
+    }
+
+    value.represent_date_time(representation).map_err(|e| {
+        OutputError::FailRepresentValue(value, e).into_sbrd_gen_error(SchemaErrorKind::OutputError)
+    })
+}
+
+/// Convert the date-time, date and time in the values into the representation
+pub(crate) fn represent_values(
+    values: Vec<DataValue>,
+    representation: &DateTimeRepresentation,
+) -> SchemaResult<Vec<DataValue>> {
+    values
+        .into_iter()
+        .map(|value| represent_value(value, representation))
+        .collect()
+}
+
+/// Convert the date-time, date and time in the values with the key into the representation
+pub(crate) fn represent_values_with_key(
+    values: Vec<(String, DataValue)>,
+    representation: &DateTimeRepresentation,
+) -> SchemaResult<Vec<(String, DataValue)>> {
+    values
+        .into_iter()
+        .map(|(key, value)| Ok((key, represent_value(value, representation)?)))
+        .collect()
+}
+
+/// Convert the date-time, date and time in the table into the representation
+pub(crate) fn represent_table<'a>(
+    table: &'a GeneratedTable,
+    representation: &DateTimeRepresentation,
+) -> SchemaResult<Cow<'a, GeneratedTable>> {
+    if representation == &DateTimeRepresentation::Format {
+        return Ok(Cow::Borrowed(table));
+    }
+
+    let values_list = table
+        .get_values_list()
+        .iter()
+        .map(|values| represent_values(values.clone(), representation))
+        .collect::<SchemaResult<Vec<Vec<DataValue>>>>()?;
+
+    Ok(Cow::Owned(GeneratedTable::new(
+        table.get_name(),
+        table.get_keys().to_vec(),
+        values_list,
+    )))
+}
+
+/// Convert the date-time, date and time in the tables into the representation
+pub(crate) fn represent_tables<'a>(
+    tables: &'a [GeneratedTable],
+    representation: &DateTimeRepresentation,
+) -> SchemaResult<Cow<'a, [GeneratedTable]>> {
+    if representation == &DateTimeRepresentation::Format {
+        return Ok(Cow::Borrowed(tables));
+    }
+
+    tables
+        .iter()
+        .map(|table| represent_table(table, representation).map(Cow::into_owned))
+        .collect::<SchemaResult<Vec<GeneratedTable>>>()
+        .map(Cow::Owned)
+}
+
 /// A Sequence of generated values
 pub struct GeneratedDisplayValues<K: Serialize, V: Serialize> {
     key_values: Vec<(K, V)>,
@@ -65,38 +159,49 @@ impl<K: Serialize, V: Serialize> Serialize for GeneratedDisplayValues<K, V> {
     }
 }
 
-/// A Sequence of generated values while generating it
-pub struct SerializeWithGenerate<'a, R: Randomizer + ?Sized> {
-    schema: &'a Schema<R>,
-    rng: Mutex<&'a mut R>,
-    count: &'a u64,
+/// A Sequence of generated values while taking them from the iterator
+pub struct SerializeWithGenerate<I> {
+    values_sets: Mutex<I>,
+    date_time_representation: DateTimeRepresentation,
 }
 
-impl<'a, R: Randomizer + ?Sized> SerializeWithGenerate<'a, R> {
+impl<'a, I> SerializeWithGenerate<I>
+where
+    I: Iterator<Item = SchemaResult<GeneratedValues<'a>>>,
+{
     /// Constructor
-    pub fn new(schema: &'a Schema<R>, rng: &'a mut R, count: &'a u64) -> Self {
+    pub fn new(values_sets: I) -> Self {
         Self {
-            schema,
-            rng: Mutex::new(rng),
-            count,
+            values_sets: Mutex::new(values_sets),
+            date_time_representation: DateTimeRepresentation::default(),
         }
     }
+
+    /// Set representation of the date-time, date and time values
+    pub fn date_time_representation(mut self, representation: DateTimeRepresentation) -> Self {
+        self.date_time_representation = representation;
+        self
+    }
 }
 
-impl<'a, R: Randomizer + ?Sized> Serialize for SerializeWithGenerate<'a, R> {
+impl<'a, I> Serialize for SerializeWithGenerate<I>
+where
+    I: Iterator<Item = SchemaResult<GeneratedValues<'a>>>,
+{
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut seq_state = serializer.serialize_seq(Some(*self.count as usize))?;
+        let mut values_sets = self.values_sets.try_lock().map_err(S::Error::custom)?;
+        let mut seq_state = serializer.serialize_seq(values_sets.size_hint().1)?;
 
-        for _ in 0..*self.count {
-            let generated = {
-                let mut rng = self.rng.try_lock().map_err(S::Error::custom)?;
-                self.schema.generate(*rng).map_err(S::Error::custom)?
-            };
-
-            let values = generated.into_values_with_key().map_err(S::Error::custom)?;
+        for generated in values_sets.by_ref() {
+            let values = generated
+                .and_then(GeneratedValues::into_values_with_key)
+                .and_then(|values| {
+                    represent_values_with_key(values, &self.date_time_representation)
+                })
+                .map_err(S::Error::custom)?;
 
             let json_map = GeneratedDisplayValues::new(values);
             seq_state.serialize_element(&json_map)?;
@@ -105,3 +210,78 @@ impl<'a, R: Randomizer + ?Sized> Serialize for SerializeWithGenerate<'a, R> {
         seq_state.end()
     }
 }
+
+/// A generated table for a KVS
+pub struct SerializeTable<'a> {
+    use_key_header: bool,
+    table: &'a GeneratedTable,
+}
+
+impl<'a> SerializeTable<'a> {
+    /// Constructor
+    pub fn new(use_key_header: bool, table: &'a GeneratedTable) -> Self {
+        Self {
+            use_key_header,
+            table,
+        }
+    }
+}
+
+impl<'a> Serialize for SerializeTable<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let values_list: Vec<GeneratedDisplayValues<&String, &DataValue>> = self
+            .table
+            .get_values_list()
+            .iter()
+            .map(|values| {
+                GeneratedDisplayValues::new(
+                    self.table.get_keys().iter().zip(values.iter()).collect(),
+                )
+            })
+            .collect();
+
+        if self.use_key_header {
+            let mut map_state = serializer.serialize_map(Some(2))?;
+            map_state.serialize_entry(DUMMY_KEYS_NAME, self.table.get_keys())?;
+            map_state.serialize_entry(DUMMY_VALUES_NAME, &values_list)?;
+            map_state.end()
+        } else {
+            values_list.serialize(serializer)
+        }
+    }
+}
+
+/// Generated tables keyed by the table's name for a KVS
+pub struct SerializeTables<'a> {
+    use_key_header: bool,
+    tables: &'a [GeneratedTable],
+}
+
+impl<'a> SerializeTables<'a> {
+    /// Constructor
+    pub fn new(use_key_header: bool, tables: &'a [GeneratedTable]) -> Self {
+        Self {
+            use_key_header,
+            tables,
+        }
+    }
+}
+
+impl<'a> Serialize for SerializeTables<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map_state = serializer.serialize_map(Some(self.tables.len()))?;
+        for table in self.tables.iter() {
+            map_state.serialize_entry(
+                table.get_name(),
+                &SerializeTable::new(self.use_key_header, table),
+            )?;
+        }
+        map_state.end()
+    }
+}
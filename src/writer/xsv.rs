@@ -1,8 +1,9 @@
-use crate::error::{IntoSbrdError, SchemaErrorKind, SchemaResult};
+use crate::error::{IntoSbrdError, OutputError, SchemaErrorKind, SchemaResult};
 use crate::generator::Randomizer;
-use crate::value::DataValue;
+use crate::value::{DataValue, DateTimeRepresentation};
+use crate::writer::writer_base::{represent_table, represent_values};
 use crate::writer::GeneratedValueWriterBase;
-use crate::Schema;
+use crate::{GeneratedTable, GeneratedValues, Schema};
 use csv::QuoteStyle;
 use serde::Serialize;
 use std::io;
@@ -12,6 +13,14 @@ pub struct CsvWriter<W: io::Write> {
     xsv_writer: XsvWriter<W>,
 }
 
+impl<W: io::Write> CsvWriter<W> {
+    /// Set representation of the date-time, date and time values
+    pub fn date_time_representation(mut self, representation: DateTimeRepresentation) -> Self {
+        self.xsv_writer.date_time_representation = representation;
+        self
+    }
+}
+
 impl<W: io::Write> GeneratedValueWriterBase<W> for CsvWriter<W> {
     fn from_writer(writer: W) -> Self {
         let xsv_writer = XsvWriter::from_writer(writer, b',');
@@ -48,6 +57,31 @@ impl<W: io::Write> GeneratedValueWriterBase<W> for CsvWriter<W> {
         self.xsv_writer
             .write_with_generate(use_key_header, schema, rng, count)
     }
+
+    fn write_values_sets<'a, I>(
+        &mut self,
+        use_key_header: bool,
+        keys: &[String],
+        values_sets: I,
+    ) -> SchemaResult<()>
+    where
+        I: Iterator<Item = SchemaResult<GeneratedValues<'a>>>,
+    {
+        self.xsv_writer
+            .write_values_sets(use_key_header, keys, values_sets)
+    }
+
+    fn write_table(&mut self, use_key_header: bool, table: &GeneratedTable) -> SchemaResult<()> {
+        self.xsv_writer.write_table(use_key_header, table)
+    }
+
+    fn write_tables(
+        &mut self,
+        use_key_header: bool,
+        tables: &[GeneratedTable],
+    ) -> SchemaResult<()> {
+        self.xsv_writer.write_tables(use_key_header, tables)
+    }
 }
 
 /// A writer that outputs as Tab-Separated Values for the key and the generated value
@@ -55,6 +89,14 @@ pub struct TsvWriter<W: io::Write> {
     xsv_writer: XsvWriter<W>,
 }
 
+impl<W: io::Write> TsvWriter<W> {
+    /// Set representation of the date-time, date and time values
+    pub fn date_time_representation(mut self, representation: DateTimeRepresentation) -> Self {
+        self.xsv_writer.date_time_representation = representation;
+        self
+    }
+}
+
 impl<W: io::Write> GeneratedValueWriterBase<W> for TsvWriter<W> {
     fn from_writer(writer: W) -> Self {
         let xsv_writer = XsvWriter::from_writer(writer, b'\t');
@@ -91,12 +133,38 @@ impl<W: io::Write> GeneratedValueWriterBase<W> for TsvWriter<W> {
         self.xsv_writer
             .write_with_generate(use_key_header, schema, rng, count)
     }
+
+    fn write_values_sets<'a, I>(
+        &mut self,
+        use_key_header: bool,
+        keys: &[String],
+        values_sets: I,
+    ) -> SchemaResult<()>
+    where
+        I: Iterator<Item = SchemaResult<GeneratedValues<'a>>>,
+    {
+        self.xsv_writer
+            .write_values_sets(use_key_header, keys, values_sets)
+    }
+
+    fn write_table(&mut self, use_key_header: bool, table: &GeneratedTable) -> SchemaResult<()> {
+        self.xsv_writer.write_table(use_key_header, table)
+    }
+
+    fn write_tables(
+        &mut self,
+        use_key_header: bool,
+        tables: &[GeneratedTable],
+    ) -> SchemaResult<()> {
+        self.xsv_writer.write_tables(use_key_header, tables)
+    }
 }
 
 /// A writer that outputs as XSV for the key and the generated value
 struct XsvWriter<W: io::Write> {
     writer: W,
     delimiter: u8,
+    date_time_representation: DateTimeRepresentation,
 }
 
 impl<W: io::Write> XsvWriter<W> {
@@ -110,7 +178,11 @@ impl<W: io::Write> XsvWriter<W> {
     }
 
     fn from_writer(writer: W, delimiter: u8) -> Self {
-        Self { writer, delimiter }
+        Self {
+            writer,
+            delimiter,
+            date_time_representation: DateTimeRepresentation::default(),
+        }
     }
 
     fn flush(&mut self) -> SchemaResult<()> {
@@ -140,6 +212,28 @@ impl<W: io::Write> XsvWriter<W> {
         self.writer
     }
 
+    /// Check that the values do not have a nested value, because XSV cannot express it
+    fn check_values(&self, keys: &[String], values: &[DataValue]) -> SchemaResult<()> {
+        for (key, value) in keys.iter().zip(values.iter()) {
+            if let DataValue::Array(_) | DataValue::Object(_) = value {
+                let output_name = match self.delimiter {
+                    b',' => "CSV",
+                    b'\t' => "TSV",
+                    _ => "XSV",
+                };
+
+                return Err(OutputError::NotSupportedValue(
+                    output_name.to_string(),
+                    key.to_string(),
+                    value.clone(),
+                )
+                .into_sbrd_gen_error(SchemaErrorKind::OutputError));
+            }
+        }
+
+        Ok(())
+    }
+
     fn write_after_all_generated<R: Randomizer + ?Sized>(
         &mut self,
         use_key_header: bool,
@@ -150,7 +244,9 @@ impl<W: io::Write> XsvWriter<W> {
         let mut values_list: Vec<Vec<DataValue>> = Vec::new();
         for _ in 1..=count {
             let generated = schema.generate(rng)?;
-            let values = generated.into_values()?;
+            let values =
+                represent_values(generated.into_values()?, &self.date_time_representation)?;
+            self.check_values(schema.get_keys(), &values)?;
 
             values_list.push(values);
         }
@@ -173,13 +269,30 @@ impl<W: io::Write> XsvWriter<W> {
         rng: &mut R,
         count: u64,
     ) -> SchemaResult<()> {
+        self.write_values_sets(
+            use_key_header,
+            schema.get_keys(),
+            (0..count).map(|_| schema.generate(&mut *rng)),
+        )
+    }
+
+    fn write_values_sets<'a, I>(
+        &mut self,
+        use_key_header: bool,
+        keys: &[String],
+        values_sets: I,
+    ) -> SchemaResult<()>
+    where
+        I: Iterator<Item = SchemaResult<GeneratedValues<'a>>>,
+    {
         if use_key_header {
-            self.serialize(schema.get_keys())?;
+            self.serialize(keys)?;
         }
 
-        for _ in 1..=count {
-            let generated = schema.generate(rng)?;
-            let values = generated.into_values()?;
+        for generated in values_sets {
+            let values =
+                represent_values(generated?.into_values()?, &self.date_time_representation)?;
+            self.check_values(keys, &values)?;
 
             self.serialize(values)?;
         }
@@ -187,4 +300,51 @@ impl<W: io::Write> XsvWriter<W> {
         self.flush()?;
         Ok(())
     }
+
+    fn serialize_table(
+        &mut self,
+        use_key_header: bool,
+        table: &GeneratedTable,
+    ) -> SchemaResult<()> {
+        let table = represent_table(table, &self.date_time_representation)?;
+        for values in table.get_values_list().iter() {
+            self.check_values(table.get_keys(), values)?;
+        }
+
+        if use_key_header {
+            self.serialize(table.get_keys())?;
+        }
+        for values in table.get_values_list().iter() {
+            self.serialize(values)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_table(&mut self, use_key_header: bool, table: &GeneratedTable) -> SchemaResult<()> {
+        self.serialize_table(use_key_header, table)?;
+
+        self.flush()?;
+        Ok(())
+    }
+
+    fn write_tables(
+        &mut self,
+        use_key_header: bool,
+        tables: &[GeneratedTable],
+    ) -> SchemaResult<()> {
+        for (i, table) in tables.iter().enumerate() {
+            // separate each section with an empty line and start it with the table's name
+            if i != 0 {
+                self.writer
+                    .write_all(b"\n")
+                    .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::OutputError))?;
+            }
+            self.serialize([table.get_name()])?;
+            self.serialize_table(use_key_header, table)?;
+        }
+
+        self.flush()?;
+        Ok(())
+    }
 }
@@ -1,13 +1,14 @@
 use crate::error::{IntoSbrdError, SchemaErrorKind, SchemaResult};
 use crate::generator::Randomizer;
-use crate::value::DataValue;
+use crate::value::{DataValue, DateTimeRepresentation};
 use crate::writer::writer_base::{
-    GeneratedDisplayValues, SerializeWithGenerate, DUMMY_KEYS_NAME, DUMMY_VALUES_NAME,
+    represent_table, represent_tables, represent_values_with_key, GeneratedDisplayValues,
+    SerializeTable, SerializeTables, SerializeWithGenerate, DUMMY_KEYS_NAME, DUMMY_VALUES_NAME,
 };
 use crate::writer::GeneratedValueWriterBase;
-use crate::Schema;
+use crate::{GeneratedTable, GeneratedValues, Schema};
 use serde::ser::{SerializeMap, SerializeSeq};
-use serde::Serializer;
+use serde::{Serialize, Serializer};
 use serde_yaml::Serializer as YamlSerializer;
 use std::borrow::BorrowMut;
 use std::io;
@@ -15,9 +16,16 @@ use std::io;
 /// A writer that outputs as Yaml for the key and the generated value
 pub struct YamlWriter<W: io::Write> {
     writer: W,
+    date_time_representation: DateTimeRepresentation,
 }
 
 impl<W: io::Write> YamlWriter<W> {
+    /// Set representation of the date-time, date and time values
+    pub fn date_time_representation(mut self, representation: DateTimeRepresentation) -> Self {
+        self.date_time_representation = representation;
+        self
+    }
+
     fn build_serializer(&mut self) -> YamlSerializer<&mut W> {
         YamlSerializer::new(&mut self.writer)
     }
@@ -25,7 +33,10 @@ impl<W: io::Write> YamlWriter<W> {
 
 impl<W: io::Write> GeneratedValueWriterBase<W> for YamlWriter<W> {
     fn from_writer(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            date_time_representation: DateTimeRepresentation::default(),
+        }
     }
 
     fn into_inner(self) -> W {
@@ -48,7 +59,10 @@ impl<W: io::Write> GeneratedValueWriterBase<W> for YamlWriter<W> {
         let mut values_list: Vec<GeneratedDisplayValues<String, DataValue>> = Vec::new();
         for _ in 1..=count {
             let generated = schema.generate(rng)?;
-            let values = generated.into_values_with_key()?;
+            let values = represent_values_with_key(
+                generated.into_values_with_key()?,
+                &self.date_time_representation,
+            )?;
 
             let value_map = GeneratedDisplayValues::new(values);
             values_list.push(value_map);
@@ -94,18 +108,36 @@ impl<W: io::Write> GeneratedValueWriterBase<W> for YamlWriter<W> {
         rng: &mut R,
         count: u64,
     ) -> SchemaResult<()> {
+        self.write_values_sets(
+            use_key_header,
+            schema.get_keys(),
+            (0..count).map(|_| schema.generate(&mut *rng)),
+        )
+    }
+
+    fn write_values_sets<'a, I>(
+        &mut self,
+        use_key_header: bool,
+        keys: &[String],
+        values_sets: I,
+    ) -> SchemaResult<()>
+    where
+        I: Iterator<Item = SchemaResult<GeneratedValues<'a>>>,
+    {
+        let date_time_representation = self.date_time_representation.clone();
         let mut serializer = self.build_serializer();
         if use_key_header {
             let mut map_state = serializer
                 .borrow_mut()
                 .serialize_map(Some(2))
                 .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::OutputError))?;
-            SerializeMap::serialize_entry(&mut map_state, DUMMY_KEYS_NAME, schema.get_keys())
+            SerializeMap::serialize_entry(&mut map_state, DUMMY_KEYS_NAME, keys)
                 .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::OutputError))?;
             SerializeMap::serialize_entry(
                 &mut map_state,
                 DUMMY_VALUES_NAME,
-                &SerializeWithGenerate::new(schema, rng, &count),
+                &SerializeWithGenerate::new(values_sets)
+                    .date_time_representation(date_time_representation.clone()),
             )
             .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::OutputError))?;
             SerializeMap::end(map_state)
@@ -113,11 +145,13 @@ impl<W: io::Write> GeneratedValueWriterBase<W> for YamlWriter<W> {
         } else {
             let mut seq_state = serializer
                 .borrow_mut()
-                .serialize_seq(Some(count as usize))
+                .serialize_seq(values_sets.size_hint().1)
                 .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::OutputError))?;
-            for _ in 1..=count {
-                let generated = schema.generate(rng)?;
-                let values = generated.into_values_with_key()?;
+            for generated in values_sets {
+                let values = represent_values_with_key(
+                    generated?.into_values_with_key()?,
+                    &date_time_representation,
+                )?;
 
                 let value_map = GeneratedDisplayValues::new(values);
                 SerializeSeq::serialize_element(&mut seq_state, &value_map)
@@ -130,4 +164,30 @@ impl<W: io::Write> GeneratedValueWriterBase<W> for YamlWriter<W> {
         self.flush()?;
         Ok(())
     }
+
+    fn write_table(&mut self, use_key_header: bool, table: &GeneratedTable) -> SchemaResult<()> {
+        let table = represent_table(table, &self.date_time_representation)?;
+        let mut serializer = self.build_serializer();
+        SerializeTable::new(use_key_header, &table)
+            .serialize(serializer.borrow_mut())
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::OutputError))?;
+
+        self.flush()?;
+        Ok(())
+    }
+
+    fn write_tables(
+        &mut self,
+        use_key_header: bool,
+        tables: &[GeneratedTable],
+    ) -> SchemaResult<()> {
+        let tables = represent_tables(tables, &self.date_time_representation)?;
+        let mut serializer = self.build_serializer();
+        SerializeTables::new(use_key_header, &tables)
+            .serialize(serializer.borrow_mut())
+            .map_err(|e| e.into_sbrd_gen_error(SchemaErrorKind::OutputError))?;
+
+        self.flush()?;
+        Ok(())
+    }
 }